@@ -1,10 +1,13 @@
 use keket::{
     database::{AssetDatabase, path::AssetPath},
-    fetch::container::{ContainerAssetFetch, ContainerPartialFetch},
+    fetch::{
+        FetchError,
+        container::{ContainerAssetFetch, ContainerPartialFetch},
+    },
     protocol::text::TextAssetProtocol,
 };
 use std::{error::Error, fs::File, io::Read};
-use zip::ZipArchive;
+use zip::{ZipArchive, result::ZipError};
 
 fn main() -> Result<(), Box<dyn Error>> {
     /* ANCHOR: main */
@@ -38,10 +41,15 @@ impl ZipContainerPartialFetch {
 impl ContainerPartialFetch for ZipContainerPartialFetch {
     // We use input path and try to unpack file under that path from ZIP archive.
     fn load_bytes(&mut self, path: AssetPath) -> Result<Vec<u8>, Box<dyn Error>> {
-        let mut file = self
-            .archive
-            .by_name(path.path())
-            .map_err(|error| format!("Could not read zip file: `{}` - {}", path.path(), error))?;
+        let mut file = self.archive.by_name(path.path()).map_err(|error| {
+            let message = format!("Could not read zip file: `{}` - {}", path.path(), error);
+            // Typed not found error lets database report missing assets.
+            if matches!(error, ZipError::FileNotFound) {
+                FetchError::NotFound(message)
+            } else {
+                FetchError::Other(message)
+            }
+        })?;
         let mut bytes = vec![];
         file.read_to_end(&mut bytes)?;
         Ok(bytes)
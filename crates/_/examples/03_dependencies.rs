@@ -46,6 +46,32 @@ fn main() -> Result<(), Box<dyn Error>> {
             dependency.access::<&AssetPath>(&database)
         );
     }
+
+    // Group members are listed in order they appear in group file.
+    let members = group
+        .group_members(&database)
+        .map(|member| member.access::<&AssetPath>(&database).to_string())
+        .collect::<Vec<_>>();
+    println!("Group members: {members:?}");
+    assert_eq!(
+        members,
+        vec![
+            "text://lorem.txt",
+            "json://person.json",
+            "bytes://trash.bin"
+        ]
+    );
+
+    // Topological order lists dependencies before assets that depend on them.
+    for handle in database.topological_order(group)? {
+        println!(
+            "Processing order: {}",
+            handle.access::<&AssetPath>(&database)
+        );
+    }
+
+    // Dependency graph can be exported to Graphviz DOT for visualization.
+    println!("Graph:\n{}", database.export_dot());
     /* ANCHOR_END: main */
 
     Ok(())
@@ -1,5 +1,8 @@
 use keket::{
-    database::{AssetDatabase, events::AssetEventBindings},
+    database::{
+        AssetDatabase,
+        events::{AssetEventBindings, AssetEventKind},
+    },
     fetch::file::FileAssetFetch,
     protocol::{
         bundle::BundleAssetProtocol, bytes::BytesAssetProtocol, group::GroupAssetProtocol,
@@ -7,7 +10,14 @@ use keket::{
     },
 };
 use serde_json::Value;
-use std::{error::Error, sync::mpsc::channel};
+use std::{
+    error::Error,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::channel,
+    },
+};
 
 fn main() -> Result<(), Box<dyn Error>> {
     let mut database = AssetDatabase::default()
@@ -18,7 +28,10 @@ fn main() -> Result<(), Box<dyn Error>> {
             Ok((asset,).into())
         }))
         .with_protocol(GroupAssetProtocol)
-        .with_fetch(FileAssetFetch::default().with_root("resources"));
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Assets get processed in order of their paths, so events sequence
+        // is the same between runs.
+        .with_deterministic_order();
 
     /* ANCHOR: events */
     // We can bind closures to asset event bindings for any asset progression tracking.
@@ -27,6 +40,30 @@ fn main() -> Result<(), Box<dyn Error>> {
         Ok(())
     });
 
+    // We can listen only to specific kinds of events, like failures.
+    database.events.bind_kinds(
+        &[
+            AssetEventKind::BytesFetchingFailed,
+            AssetEventKind::BytesProcessingFailed,
+        ],
+        |event| {
+            println!("Asset failed: {event:#?}");
+            Ok(())
+        },
+    );
+
+    // We can also listen only to events of specific asset, here called once
+    // when asset finishes loading.
+    let lorem_done = Arc::new(AtomicBool::new(false));
+    {
+        let lorem_done = lorem_done.clone();
+        database.on_path_once("text://lorem.txt", move |event| {
+            println!("Lorem Ipsum finished loading: {:?}", event.kind);
+            lorem_done.store(true, Ordering::SeqCst);
+            Ok(())
+        });
+    }
+
     // Create channel for asset events communication.
     let (tx, rx) = channel();
 
@@ -39,6 +76,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         database.maintain()?;
     }
 
+    assert!(lorem_done.load(Ordering::SeqCst));
+
     // Read sent events from receiver.
     while let Ok(event) = rx.try_recv() {
         println!("Group channel event: {event:#?}");
@@ -31,5 +31,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     );
     /* ANCHOR_END: main */
 
+    // Assets still being fetched can be cancelled, which also cancels their
+    // fetching jobs.
+    let trash = database.ensure("bytes://trash.bin")?;
+    if database.cancel("bytes://trash.bin")? {
+        println!("Trash fetching cancelled");
+    }
+    database.maintain()?;
+    assert!(!trash.does_exists(&database));
+
     Ok(())
 }
@@ -1,5 +1,10 @@
 use keket::{
-    database::{AssetDatabase, handle::AssetHandle, path::AssetPath},
+    database::{
+        AssetDatabase,
+        events::{AssetEvent, AssetEventKind},
+        handle::AssetHandle,
+        path::AssetPath,
+    },
     fetch::{file::FileAssetFetch, hotreload::HotReloadFileAssetFetch},
     protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
 };
@@ -11,11 +16,24 @@ fn main() -> Result<(), Box<dyn Error>> {
         .with_protocol(TextAssetProtocol)
         .with_protocol(BytesAssetProtocol)
         // Hot reload wrapper watches for changes in file fetch root path.
-        .with_fetch(HotReloadFileAssetFetch::new(
-            FileAssetFetch::default().with_root("resources"),
-            // File system watcher polling interval.
-            Duration::from_secs(5),
-        )?);
+        // Use `HotReloadFileAssetFetch::new_native` for event-driven watcher
+        // on platforms that support native file system events.
+        .with_fetch(
+            HotReloadFileAssetFetch::new(
+                FileAssetFetch::default().with_root("resources"),
+                // File system watcher polling interval.
+                Duration::from_secs(5),
+            )?
+            // Bursts of file writes within debounce window trigger single reload.
+            .with_debounce(Duration::from_millis(500)),
+        )
+        // Hot reloading reports assets which source files have changed.
+        .with_event(|event: AssetEvent| {
+            if event.kind == AssetEventKind::SourceChanged {
+                println!("Asset source changed: `{}`", event.path);
+            }
+            Ok(())
+        });
 
     // First we fill database with some assets, hot reload only
     // cares about changes in files present in database.
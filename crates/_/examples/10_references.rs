@@ -6,7 +6,10 @@ use keket::{
     },
 };
 use serde::Deserialize;
-use std::error::Error;
+use std::{
+    error::Error,
+    sync::{Arc, RwLock},
+};
 
 fn main() -> Result<(), Box<dyn Error>> {
     /* ANCHOR: main */
@@ -24,11 +27,28 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("Custom chain contents: {contents:?}");
     /* ANCHOR_END: main */
 
+    // References can reload their asset, which replaces cached handle with
+    // the one of newly scheduled asset entity.
+    let reference = AssetRef::new("custom://part1.json");
+    reference.resolve(&database)?;
+    let old_handle = reference.handle()?;
+    reference.reload(&mut database)?;
+    database.maintain_until_idle()?;
+    let new_handle = reference.handle()?;
+    println!("Reloaded reference handle: {old_handle:?} -> {new_handle:?}");
+    assert!(new_handle.is_ready_to_use(&database));
+
+    // References can be resolved against database shared between threads,
+    // giving owned copy of asset component.
+    let database = Arc::new(RwLock::new(database));
+    let asset = reference.resolve_shared::<CustomAsset>(&database)?;
+    println!("Shared reference content: {}", asset.content);
+
     Ok(())
 }
 
 /* ANCHOR: custom_asset */
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize)]
 struct CustomAsset {
     content: String,
     // Asset references are used to store path and cached handle. They serialize as asset paths.
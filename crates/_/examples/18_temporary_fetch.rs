@@ -16,8 +16,13 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Temporarily use different asset fetch to load asset from file.
     let lorem = database.using_fetch(
         FileAssetFetch::default().with_root("resources"),
-        |database| database.ensure("text://lorem.txt"),
+        |database| {
+            // Fetch stack can be inspected for diagnostics.
+            println!("Fetch stack: {:?}", database.fetch_stack_names());
+            database.ensure("text://lorem.txt")
+        },
     )?;
+    println!("Fetch stack: {:?}", database.fetch_stack_names());
 
     println!("Lorem Ipsum: {}", lorem.access::<&String>(&database));
     /* ANCHOR_END: main */
@@ -49,5 +49,19 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
     /* ANCHOR_END: main */
 
+    // Instead of polling, progress of all assets can be pushed to listener
+    // whenever it changes during maintenance.
+    let mut database = AssetDatabase::default()
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(DeferredAssetFetch::new(
+            FileAssetFetch::default().with_root("resources"),
+        ))
+        .with_progress_listener(|progress| {
+            println!("Progress changed: {}%", progress.factor() * 100.0);
+        });
+    database.schedule("bytes://dlc.zip")?;
+    database.schedule("bytes://main.zip")?;
+    database.maintain_until_idle()?;
+
     Ok(())
 }
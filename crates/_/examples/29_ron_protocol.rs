@@ -0,0 +1,75 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{bundle::BundleAssetProtocol, ron::RonAssetProcessor},
+    store::file::FileAssetStore,
+    third_party::ron::{self, ser::PrettyConfig},
+};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Enemy {
+    kind: String,
+    position: Position,
+    health: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Level {
+    name: String,
+    spawn: Position,
+    enemies: Vec<Enemy>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // RON processor decodes asset into typed component and encodes it
+        // back (here with pretty output) when asset gets stored.
+        .with_protocol(BundleAssetProtocol::new(
+            "ron",
+            RonAssetProcessor::<Level>::default().pretty(PrettyConfig::default()),
+        ))
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_store(FileAssetStore::default().with_root("resources"));
+
+    let _ = std::fs::remove_file("./resources/saved.ron");
+
+    let level = database.ensure("ron://level.ron")?;
+    let mut level = level.access::<&Level>(&database).clone();
+    println!("Loaded: {level:#?}");
+    assert_eq!(level.name, "Frozen Lake");
+    assert_eq!((level.spawn.x, level.spawn.y), (4.0, 2.5));
+    assert_eq!(level.enemies.len(), 2);
+    assert_eq!(level.enemies[0].kind, "yeti");
+    assert_eq!(level.enemies[0].health, 120);
+    assert_eq!(
+        (level.enemies[1].position.x, level.enemies[1].position.y),
+        (-2.0, 7.5)
+    );
+
+    level.name = "Frozen Lake (modified)".to_owned();
+    let saved = database.spawn("ron://saved.ron", (level.clone(),))?;
+    saved.store(&mut database)?;
+
+    while database.is_busy() {
+        database.maintain()?;
+    }
+
+    // Pretty output spans multiple lines and decodes back to the same level.
+    let content = std::fs::read_to_string("./resources/saved.ron")?;
+    println!("Saved: {content}");
+    assert!(content.lines().count() > 1);
+    assert_eq!(ron::from_str::<Level>(&content)?, level);
+    std::fs::remove_file("./resources/saved.ron")?;
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
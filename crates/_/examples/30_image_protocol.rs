@@ -0,0 +1,43 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::image::{DecodedImage, DecodedImageFormat, ImageAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Image protocol decodes PNG, JPEG and other formats into pixel data.
+        .with_protocol(ImageAssetProtocol::default().with_format(DecodedImageFormat::Rgba8))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let ferris = database.ensure("image://ferris.png")?;
+    let image = ferris.access::<&DecodedImage>(&database);
+    println!(
+        "Ferris: {}x{} in {:?} format ({} bytes)",
+        image.width,
+        image.height,
+        image.format,
+        image.bytes.len()
+    );
+    assert_eq!((image.width, image.height), (460, 307));
+    assert_eq!(image.format, DecodedImageFormat::Rgba8);
+    assert_eq!(image.bytes.len(), 460 * 307 * 4);
+
+    // Files that are not images fail with clear error.
+    let error = database.ensure("image://lorem.txt").unwrap_err();
+    assert!(error.to_string().contains("Unsupported image format"));
+
+    // Target pixel format decides size of pixel data.
+    let mut database = AssetDatabase::default()
+        .with_protocol(ImageAssetProtocol::default().with_format(DecodedImageFormat::Luma8))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+    let ferris = database.ensure("image://ferris.png")?;
+    let image = ferris.access::<&DecodedImage>(&database);
+    assert_eq!((image.width, image.height), (460, 307));
+    assert_eq!(image.bytes.len(), 460 * 307);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
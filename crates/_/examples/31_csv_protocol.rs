@@ -0,0 +1,70 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{file::FileAssetFetch, testing::NullAssetFetch},
+    protocol::{
+        bundle::BundleAssetProtocol,
+        csv::{CsvAssetProtocol, CsvRecordsAssetProcessor, CsvTable},
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Item {
+    name: String,
+    price: usize,
+    count: usize,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Raw CSV tables.
+        .with_protocol(CsvAssetProtocol::default())
+        // CSV records deserialized into typed list.
+        .with_protocol(BundleAssetProtocol::new(
+            "items",
+            CsvRecordsAssetProcessor::<Item>::default(),
+        ))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let table = database.ensure("csv://items.csv")?;
+    let table = table.access::<&CsvTable>(&database);
+    println!("Headers: {:?}", table.headers);
+    for row in &table.rows {
+        println!("Row: {row:?}");
+    }
+    assert_eq!(table.headers, vec!["name", "price", "count"]);
+    assert_eq!(table.rows.len(), 3);
+    assert_eq!(table.rows[1], vec!["shield", "75", "2"]);
+
+    let items = database.ensure("items://items.csv")?;
+    let items = items.access::<&Vec<Item>>(&database);
+    for item in items.iter() {
+        println!("Item: {item:?}");
+    }
+    assert_eq!(
+        items[2],
+        Item {
+            name: "potion".to_owned(),
+            price: 5,
+            count: 10,
+        }
+    );
+
+    // Headerless tables keep first row as data, and delimiter is configurable.
+    let mut database = AssetDatabase::default()
+        .with_protocol(
+            CsvAssetProtocol::default()
+                .has_headers(false)
+                .delimiter(b';'),
+        )
+        .with_fetch(NullAssetFetch::default().with("scores.csv", "alice;10\nbob;7\n"));
+    let scores = database.ensure("csv://scores.csv")?;
+    let scores = scores.access::<&CsvTable>(&database);
+    assert!(scores.headers.is_empty());
+    assert_eq!(scores.rows, vec![vec!["alice", "10"], vec!["bob", "7"]]);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
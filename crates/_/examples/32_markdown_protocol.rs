@@ -0,0 +1,48 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::markdown::{MarkdownAssetProtocol, MarkdownDocument, MarkdownHtml},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Markdown protocol splits front-matter from body and renders HTML.
+        .with_protocol(MarkdownAssetProtocol::default().with_html())
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let article = database.ensure("markdown://article.md")?;
+    let (document, html) = article.access::<(&MarkdownDocument, &MarkdownHtml)>(&database);
+    println!("Title: {}", document.frontmatter["title"]);
+    println!("Tags: {}", document.frontmatter["tags"]);
+    println!("Body: {}", document.body);
+    println!("HTML: {}", html.0);
+    assert_eq!(document.frontmatter["title"], "Hello, Keket!");
+    assert_eq!(document.frontmatter["tags"][1], "markdown");
+    assert_eq!(
+        document.body,
+        "# Hello\n\nThis is **markdown** document with front-matter.\n"
+    );
+    assert!(html.0.starts_with("<h1>Hello</h1>"));
+    assert!(html.0.contains("<strong>markdown</strong>"));
+
+    // Documents without front-matter are body only.
+    assert_eq!(
+        MarkdownAssetProtocol::split_frontmatter("# Title\n---\n"),
+        (None, "# Title\n---\n")
+    );
+    // Front-matter might be followed by empty body.
+    assert_eq!(
+        MarkdownAssetProtocol::split_frontmatter("---\ntitle: Empty\n---\n"),
+        (Some("title: Empty\n"), "")
+    );
+    // Windows line endings are supported too.
+    assert_eq!(
+        MarkdownAssetProtocol::split_frontmatter("---\r\ntitle: CRLF\r\n---\r\nBody\r\n"),
+        (Some("title: CRLF\r\n"), "Body\r\n")
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{file::FileAssetFetch, testing::NullAssetFetch},
+    protocol::lines::LinesAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Lines protocol splits text into lines, skipping comments.
+        .with_protocol(LinesAssetProtocol::default().comment_prefix("#"))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let party = database.ensure("lines://party.txt")?;
+    for name in party.access::<&Vec<String>>(&database).iter() {
+        println!("Member: {name}");
+    }
+    // Comments and trailing empty lines are gone.
+    assert_eq!(
+        *party.access::<&Vec<String>>(&database),
+        vec!["Alice", "Bob", "Charlie"]
+    );
+
+    // Trailing empty lines can be kept.
+    let mut database = AssetDatabase::default()
+        .with_protocol(
+            LinesAssetProtocol::default()
+                .comment_prefix("#")
+                .keep_trailing_empty_lines(),
+        )
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+    let party = database.ensure("lines://party.txt")?;
+    assert_eq!(
+        *party.access::<&Vec<String>>(&database),
+        vec!["Alice", "Bob", "Charlie", ""]
+    );
+
+    // CRLF line endings are handled like LF ones, and indented comments are
+    // skipped too.
+    let fetch = NullAssetFetch::default().with("list.txt", "one\r\n  # note\r\ntwo\r\n");
+    let mut database = AssetDatabase::default()
+        .with_protocol(LinesAssetProtocol::default().comment_prefix("#"))
+        .with_fetch(fetch.clone());
+    let list = database.ensure("lines://list.txt")?;
+    assert_eq!(*list.access::<&Vec<String>>(&database), vec!["one", "two"]);
+
+    // Without comment prefix, no line is a comment.
+    let mut database = AssetDatabase::default()
+        .with_protocol(LinesAssetProtocol::default())
+        .with_fetch(fetch);
+    let list = database.ensure("lines://list.txt")?;
+    assert_eq!(
+        *list.access::<&Vec<String>>(&database),
+        vec!["one", "  # note", "two"]
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{file::FileAssetFetch, testing::NullAssetFetch},
+    protocol::text::TextAssetProtocol,
+    third_party::encoding_rs::{UTF_8, WINDOWS_1252},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Latin-1 bytes get transcoded into UTF-8 string, but byte order mark
+        // (like in UTF-16 file) takes precedence over configured encoding.
+        .with_protocol(TextAssetProtocol::with_encoding(WINDOWS_1252))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let latin1 = database.ensure("text://latin1.txt")?;
+    assert_eq!(latin1.access::<&String>(&database), "Café crème brûlée\n");
+
+    let utf16 = database.ensure("text://utf16.txt")?;
+    assert_eq!(utf16.access::<&String>(&database), "Zażółć gęślą jaźń\n");
+
+    // Invalid sequences produce an error by default...
+    let invalid = NullAssetFetch::default().with("invalid.txt", b"fo\xFFo".to_vec());
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol::with_encoding(UTF_8))
+        .with_fetch(invalid.clone())
+        .with_asset_progression_failures();
+    let strict = database.ensure("text://invalid.txt")?;
+    assert!(!strict.is_ready_to_use(&database));
+    assert!(strict.error(&database).unwrap().contains("invalid UTF-8"));
+
+    // ...or get replaced with replacement characters in lossy mode.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol::with_encoding(UTF_8).lossy())
+        .with_fetch(invalid);
+    let lossy = database.ensure("text://invalid.txt")?;
+    assert_eq!(lossy.access::<&String>(&database), "fo\u{FFFD}o");
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use keket::{
+    database::AssetDatabase, fetch::file::FileAssetFetch, protocol::bytes::BytesAssetProtocol,
+    store::file::FileAssetStore,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_store(FileAssetStore::default().with_root("resources"));
+
+    let _ = std::fs::remove_file("./resources/saved.bin");
+
+    // Load bytes and modify them.
+    let trash = database.ensure("bytes://trash.bin")?;
+    let mut bytes = trash.access::<&Vec<u8>>(&database).to_owned();
+    bytes.reverse();
+    println!("Modified: {bytes:?}");
+
+    // Bytes protocol produces stored bytes straight from `Vec<u8>` component.
+    let saved = database.spawn("bytes://saved.bin", (bytes.clone(),))?;
+    saved.store(&mut database)?;
+
+    database.maintain_until_idle()?;
+
+    let stored = std::fs::read("./resources/saved.bin")?;
+    println!("Stored: {stored:?}");
+    assert_eq!(stored, bytes);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
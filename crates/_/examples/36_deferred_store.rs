@@ -0,0 +1,54 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::text::TextAssetProtocol,
+    store::{AssetAwaitsAsyncStore, deferred::DeferredAssetStore, file::FileAssetStore},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Deferred asset store saves assets on background jobs, so storing
+        // does not block the main thread.
+        .with_store(DeferredAssetStore::new(
+            FileAssetStore::default().with_root("resources"),
+        ));
+
+    let _ = std::fs::remove_file("./resources/saved3.txt");
+
+    let before = database.spawn("text://saved3.txt", ("Abra cadabra!".to_owned(),))?;
+    before.store(&mut database)?;
+
+    // Wait until the asset is stored.
+    while database.is_busy() {
+        println!(
+            "Storing in background: {}",
+            before.has::<AssetAwaitsAsyncStore>(&database)
+        );
+        database.maintain()?;
+    }
+
+    before.delete(&mut database).unwrap();
+
+    let after = database.ensure("text://saved3.txt")?;
+    println!("After: {}", after.access::<&String>(&database));
+
+    // Saving again while previous save is in flight leaves the latest bytes.
+    let _ = std::fs::remove_file("./resources/saved4.txt");
+    let quick = database.spawn("text://saved4.txt", ("First".to_owned(),))?;
+    quick.store(&mut database)?;
+    database.maintain()?;
+    database
+        .storage
+        .insert(quick.entity(), ("Second".to_owned(),))?;
+    quick.store(&mut database)?;
+    database.maintain_until_idle()?;
+    assert_eq!(std::fs::read_to_string("./resources/saved4.txt")?, "Second");
+    std::fs::remove_file("./resources/saved4.txt")?;
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
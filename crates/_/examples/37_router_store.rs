@@ -0,0 +1,46 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+    store::{file::FileAssetStore, router::RouterAssetStore},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Router store dispatches assets to different stores by protocol.
+        .with_store(
+            RouterAssetStore::default()
+                .route_protocol(
+                    "text",
+                    FileAssetStore::default().with_root("resources/text"),
+                )
+                .route_protocol(
+                    "bytes",
+                    FileAssetStore::default().with_root("resources/bytes"),
+                ),
+        );
+
+    let text = database.spawn("text://routed.txt", ("Abra cadabra!".to_owned(),))?;
+    text.store(&mut database)?;
+    let bytes = database.spawn("bytes://routed.bin", (vec![1u8, 2, 3],))?;
+    bytes.store(&mut database)?;
+
+    database.maintain_until_idle()?;
+
+    println!(
+        "Text: {}",
+        std::fs::read_to_string("./resources/text/routed.txt")?
+    );
+    println!(
+        "Bytes: {:?}",
+        std::fs::read("./resources/bytes/routed.bin")?
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
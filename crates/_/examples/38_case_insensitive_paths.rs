@@ -0,0 +1,25 @@
+use keket::{
+    database::AssetDatabase, fetch::file::FileAssetFetch, protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Differently cased paths point to the same asset.
+        .with_case_insensitive_paths();
+
+    let lower = database.ensure("text://lorem.txt")?;
+    let upper = database.ensure("text://LOREM.txt")?;
+    assert_eq!(lower, upper);
+    println!(
+        "Same asset: `{}` and `{}`",
+        lower.path(&database)?.content(),
+        upper.path(&database)?.content()
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
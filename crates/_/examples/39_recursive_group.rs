@@ -0,0 +1,30 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::file::FileAssetFetch,
+    protocol::{group::GroupAssetProtocol, text::TextAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(GroupAssetProtocol)
+        // File fetch lists directories, which group protocol uses to load
+        // every file beneath directory.
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Group contains `text://levels/**` entry, which expands into nested
+    // `group://levels?recursive&protocol=text` group with all level files.
+    database.ensure("group://levels.txt")?;
+
+    database.maintain_until_idle()?;
+
+    // Files are listed in sorted order, so loading is reproducible.
+    for (path, content) in database.storage.query::<true, (&AssetPath, &String)>() {
+        println!("Level `{path}`: {content}");
+    }
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
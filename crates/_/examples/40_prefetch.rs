@@ -0,0 +1,32 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{AssetPrefetched, file::FileAssetFetch},
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Prefetching only fetches asset bytes, without processing them.
+    let prefetched = database.prefetch("text://lorem.txt")?;
+    database.maintain_until_idle()?;
+    assert!(prefetched.access_checked::<&String>(&database).is_none());
+    assert!(
+        database
+            .storage
+            .has_entity_component::<AssetPrefetched>(prefetched.entity())
+    );
+
+    // Ensuring prefetched asset reuses already fetched bytes.
+    let lorem = database.ensure("text://lorem.txt")?;
+    assert_eq!(lorem, prefetched);
+    database.maintain_until_idle()?;
+    println!("Lorem Ipsum: {}", lorem.access::<&String>(&database));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{
+        file::FileAssetFetch,
+        verify::{VerifyingAssetFetch, sha256_hex},
+    },
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        // Verifying fetch checks fetched bytes against `sha256` path meta.
+        .with_fetch(VerifyingAssetFetch::new(
+            FileAssetFetch::default().with_root("resources"),
+        ));
+
+    let expected = sha256_hex(&std::fs::read("resources/lorem.txt")?);
+
+    // Matching hash lets asset be processed.
+    let lorem = database.ensure(format!("text://lorem.txt?sha256={expected}"))?;
+    database.maintain_until_idle()?;
+    println!("Verified: {}", lorem.access::<&String>(&database));
+
+    // Assets without hash meta pass through unchanged.
+    let person = database.ensure("text://person.json")?;
+    database.maintain_until_idle()?;
+    println!("Unverified: {}", person.access::<&String>(&database));
+
+    // Mismatching hash rejects asset bytes.
+    let trash = database.ensure(format!("text://trash.bin?sha256={expected}"))?;
+    if let Err(error) = database.maintain_until_idle() {
+        println!("Rejected: {error}");
+    }
+    assert!(trash.access_checked::<&String>(&database).is_none());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anput::bundle::DynamicBundle;
+use keket::{
+    database::{
+        AssetDatabase,
+        path::{AssetPath, AssetPathStatic},
+    },
+    fetch::{AssetBytesAreReadyToProcess, AssetFetch},
+    protocol::text::TextAssetProtocol,
+};
+use std::{error::Error, path::PathBuf};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(AtlasAssetFetch {
+            root: "resources".into(),
+        });
+
+    // Loading atlas also spawns its named regions as sub-assets.
+    let atlas = database.ensure("text://sprites.atlas")?;
+    database.maintain_until_idle()?;
+
+    let mut regions = Vec::default();
+    for region in atlas.dependencies(&database) {
+        println!(
+            "Region `{}`:\n{}",
+            region.path(&database)?.path(),
+            region.access::<&String>(&database)
+        );
+        regions.push((
+            region.path(&database)?.path().to_owned(),
+            region.access::<&String>(&database).to_owned(),
+        ));
+    }
+    regions.sort();
+    assert_eq!(
+        regions,
+        vec![
+            ("sprites.atlas/coin".to_owned(), "(-)\n| |\n(-)".to_owned()),
+            (
+                "sprites.atlas/heart".to_owned(),
+                "/\\ /\\\n\\   /\n \\ / ".to_owned()
+            ),
+            (
+                "sprites.atlas/hero".to_owned(),
+                " o \n/|\\\n/ \\".to_owned()
+            ),
+        ]
+    );
+    // Atlas itself keeps only its regions list.
+    assert_eq!(atlas.access::<&String>(&database).lines().count(), 3);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+/* ANCHOR: atlas_fetch */
+// Atlas file lists named regions (`name x y width height`), followed by empty
+// line and the sheet itself. Each region becomes `<atlas>/<name>` sub-asset.
+struct AtlasAssetFetch {
+    root: PathBuf,
+}
+
+fn bytes_bundle(bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+    let mut bundle = DynamicBundle::default();
+    bundle
+        .add_component(AssetBytesAreReadyToProcess(bytes))
+        .map_err(|_| "Failed to add bytes to bundle")?;
+    Ok(bundle)
+}
+
+impl AssetFetch for AtlasAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        bytes_bundle(std::fs::read(self.root.join(path.path()))?)
+    }
+
+    fn load_many(
+        &self,
+        path: AssetPath,
+    ) -> Result<Vec<(AssetPathStatic, DynamicBundle)>, Box<dyn Error>> {
+        let content = std::fs::read_to_string(self.root.join(path.path()))?;
+        let (regions, sheet) = content
+            .split_once("\n\n")
+            .ok_or_else(|| format!("Missing sheet in atlas: `{path}`"))?;
+        let sheet = sheet
+            .lines()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut result = vec![(
+            path.clone().into_static(),
+            bytes_bundle(regions.as_bytes().to_vec())?,
+        )];
+        for region in regions.lines() {
+            let mut parts = region.split_whitespace();
+            let name = parts
+                .next()
+                .ok_or_else(|| format!("Missing region name in atlas: `{path}`"))?;
+            let [x, y, width, height] = std::array::from_fn(|_| {
+                parts
+                    .next()
+                    .and_then(|value| value.parse::<usize>().ok())
+                    .unwrap_or_default()
+            });
+            let content = sheet
+                .iter()
+                .skip(y)
+                .take(height)
+                .map(|row| row.iter().skip(x).take(width).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let sub_path = AssetPathStatic::from_parts(
+                path.protocol(),
+                &format!("{}/{}", path.path(), name),
+                "",
+            );
+            result.push((sub_path, bytes_bundle(content.into_bytes())?));
+        }
+        Ok(result)
+    }
+}
+/* ANCHOR_END: atlas_fetch */
@@ -0,0 +1,84 @@
+use anput::world::World;
+use keket::{
+    database::{AssetDatabase, handle::AssetHandle},
+    fetch::stream::{AssetByteStream, StreamingAssetFetch},
+    protocol::AssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(ChecksumAssetProtocol)
+        // Streaming fetch exposes files as streams of chunks, instead of
+        // loading whole files into memory.
+        .with_fetch(
+            StreamingAssetFetch::default()
+                .with_root("resources")
+                .with_chunk_size(256),
+        );
+
+    let package = database.ensure("checksum://package.zip")?;
+    database.maintain_until_idle()?;
+    let checksum = package.access::<&Checksum>(&database);
+    println!("Package: {checksum:?}");
+
+    // 935 bytes split into 256 bytes chunks, last one being partial.
+    let bytes = std::fs::read("resources/package.zip")?;
+    assert_eq!(checksum.size, bytes.len());
+    assert_eq!(checksum.size, 935);
+    assert_eq!(checksum.chunks, bytes.len().div_ceil(256));
+    assert_eq!(checksum.chunks, 4);
+    // Hashing chunks must match hashing whole content at once.
+    assert_eq!(checksum.fnv1a, fnv1a(0xcbf29ce484222325, &bytes));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+/* ANCHOR: checksum_protocol */
+#[derive(Debug)]
+struct Checksum {
+    chunks: usize,
+    size: usize,
+    fnv1a: u64,
+}
+
+// Protocol hashing content chunk by chunk, never buffering whole asset.
+struct ChecksumAssetProtocol;
+
+impl AssetProtocol for ChecksumAssetProtocol {
+    fn name(&self) -> &str {
+        "checksum"
+    }
+
+    fn process_stream(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        stream: AssetByteStream,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut checksum = Checksum {
+            chunks: 0,
+            size: 0,
+            fnv1a: 0xcbf29ce484222325,
+        };
+        for chunk in stream {
+            let chunk = chunk?;
+            checksum.chunks += 1;
+            checksum.size += chunk.len();
+            checksum.fnv1a = fnv1a(checksum.fnv1a, &chunk);
+        }
+        storage.insert(handle.entity(), (checksum,))?;
+        Ok(())
+    }
+}
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+/* ANCHOR_END: checksum_protocol */
@@ -0,0 +1,59 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::{container::ContainerAssetFetch, file::FileAssetFetch},
+    protocol::{bundle::BundleAssetProtocol, text::TextAssetProtocol},
+};
+use serde_json::Value;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BundleAssetProtocol::new("json", |bytes: Vec<u8>| {
+            Ok((serde_json::from_slice::<Value>(&bytes)?,).into())
+        }))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Zero-length assets are fetched as empty bytes, which some protocols
+    // can process just fine.
+    let empty = database.ensure("text://empty.txt")?;
+    println!("Empty text: {:?}", empty.access::<&String>(&database));
+    assert!(empty.access::<&String>(&database).is_empty());
+
+    // Protocols that can not process empty input report empty asset error.
+    let error = database
+        .ensure("json://empty.txt")
+        .err()
+        .ok_or("Empty JSON file should fail to process")?;
+    println!("{error}");
+    assert!(
+        error
+            .to_string()
+            .starts_with("Failed to process empty asset: `json://empty.txt`")
+    );
+
+    // Same applies to other fetch sources.
+    database.push_fetch(ContainerAssetFetch::new(
+        |_: AssetPath| -> Result<Vec<u8>, Box<dyn Error>> { Ok(vec![]) },
+    ));
+    let empty = database.ensure("text://container.txt")?;
+    println!(
+        "Empty container text: {:?}",
+        empty.access::<&String>(&database)
+    );
+    assert!(empty.access::<&String>(&database).is_empty());
+    let error = database
+        .ensure("json://container.json")
+        .err()
+        .ok_or("Empty JSON container asset should fail to process")?;
+    println!("{error}");
+    assert!(
+        error
+            .to_string()
+            .starts_with("Failed to process empty asset: `json://container.json`")
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
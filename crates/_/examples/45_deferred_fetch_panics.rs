@@ -0,0 +1,49 @@
+use anput::bundle::DynamicBundle;
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::{AssetFetch, deferred::DeferredAssetFetch},
+    protocol::bytes::BytesAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(BytesAssetProtocol)
+        // Panics of inner fetch engine are caught and reported as errors
+        // of the offending asset, including panic message.
+        .with_fetch(DeferredAssetFetch::new(PanickingAssetFetch).catch_panics());
+
+    let cursed = database.ensure("bytes://cursed.bin")?;
+    let error = database
+        .maintain_until_idle()
+        .err()
+        .ok_or("Panicking fetch should report an error")?;
+    println!("{error}");
+    assert!(
+        error
+            .to_string()
+            .contains("panicked: Asset `bytes://cursed.bin` is cursed!")
+    );
+
+    // Offending asset gets marked with load error instead of crashing.
+    assert!(cursed.failed(&database));
+    assert!(
+        cursed
+            .error(&database)
+            .ok_or("Cursed asset should have load error")?
+            .contains("panicked: Asset `bytes://cursed.bin` is cursed!")
+    );
+    assert!(!cursed.is_ready_to_use(&database));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+struct PanickingAssetFetch;
+
+impl AssetFetch for PanickingAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        panic!("Asset `{path}` is cursed!");
+    }
+}
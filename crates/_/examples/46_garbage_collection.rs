@@ -0,0 +1,45 @@
+use keket::{
+    database::{AssetDatabase, AssetReferenceCounter},
+    fetch::file::FileAssetFetch,
+    protocol::{
+        bundle::BundleAssetProtocol, bytes::BytesAssetProtocol, group::GroupAssetProtocol,
+        text::TextAssetProtocol,
+    },
+};
+use serde_json::Value;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_protocol(BundleAssetProtocol::new("json", |bytes: Vec<u8>| {
+            Ok((serde_json::from_slice::<Value>(&bytes)?,).into())
+        }))
+        .with_protocol(GroupAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Group and its dependencies are no longer referenced by anyone...
+    let group = database.ensure("group://group.txt")?;
+    database.maintain_until_idle()?;
+    group.give(&mut database, (AssetReferenceCounter::default(),))?;
+
+    // ...except for one of its dependencies, which is still in use.
+    let lorem = database.find("text://lorem.txt").unwrap();
+    lorem
+        .ensure::<AssetReferenceCounter>(&mut database)?
+        .increment();
+    println!("Assets before collection: {}", database.storage.len());
+
+    // Garbage collection unloads unreferenced assets and their orphaned
+    // dependencies, while assets still in use survive.
+    let collected = database.collect_garbage()?;
+    println!("Collected assets: {collected}");
+    assert!(!group.does_exists(&database));
+    assert!(lorem.does_exists(&database));
+    println!("Assets after collection: {}", database.storage.len());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use keket::{
+    database::{AssetDatabase, tracker::AssetsStatus},
+    fetch::file::FileAssetFetch,
+    protocol::text::TextAssetProtocol,
+    store::{AssetStoreTiming, deferred::DeferredAssetStore, file::FileAssetStore},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_store(DeferredAssetStore::new(
+            FileAssetStore::default().with_root("resources"),
+        ));
+
+    let handles = (0..5)
+        .map(|index| {
+            database.spawn(
+                format!("text://saved_progress{index}.txt"),
+                (format!("Saved asset #{index}"),),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for handle in &handles {
+        handle.store(&mut database)?;
+    }
+
+    // Report storing progress until all assets are stored.
+    let mut status = AssetsStatus::amount();
+    loop {
+        database.maintain()?;
+        database.report_storing_status(&mut status);
+        let progress = status.storing_progress();
+        println!("Saving... {:.0}%", progress.factor() * 100.0);
+        if progress.is_complete() {
+            assert_eq!(progress.factor(), 1.0);
+            break;
+        }
+    }
+
+    // Every stored asset knows how long its storing took.
+    for handle in &handles {
+        let timing = handle.access::<&AssetStoreTiming>(&database);
+        println!(
+            "Stored {} in: {:?}",
+            handle.access::<&String>(&database),
+            timing.duration()
+        );
+    }
+
+    for index in 0..5 {
+        let _ = std::fs::remove_file(format!("./resources/saved_progress{index}.txt"));
+    }
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{bundle::BundleAssetProtocol, bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use serde_json::Value;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Text protocol handles `.txt` files out of the box.
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        // Bundle protocols can advertise extensions they handle.
+        .with_protocol(
+            BundleAssetProtocol::new("json", |bytes: Vec<u8>| {
+                let asset = serde_json::from_slice::<Value>(&bytes)?;
+                Ok((asset,).into())
+            })
+            .with_extensions(["json"]),
+        )
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Select protocol from file extension of the asset.
+    for file in ["lorem.txt", "person.json", "trash.bin"] {
+        let extension = file.rsplit('.').next().unwrap_or_default();
+        let protocol = database.protocol_for_extension(extension);
+        println!("File: `{file}` is handled by protocol: {protocol:?}");
+        if let Some(protocol) = protocol {
+            let path = format!("{protocol}://{file}");
+            database.ensure(path)?;
+        }
+    }
+    assert_eq!(database.protocol_for_extension(".TXT"), Some("text"));
+    assert_eq!(database.protocol_for_extension("json"), Some("json"));
+    assert_eq!(database.protocol_for_extension("bin"), None);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anput::world::World;
+use keket::{
+    database::{AssetDatabase, AssetPriority, handle::AssetHandle, path::AssetPathStatic},
+    fetch::file::FileAssetFetch,
+    protocol::AssetProtocol,
+};
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let mut database = AssetDatabase::default()
+        .with_protocol(OrderProtocol {
+            order: order.clone(),
+        })
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Nearby assets get higher priority, so they are resolved and processed
+    // before assets far away from the player.
+    database.schedule_with_priority("order://lorem.txt", None)?;
+    database.schedule_with_priority("order://cat.txt", Some(AssetPriority(10)))?;
+    database.schedule_with_priority("order://logo.txt", Some(AssetPriority(-5)))?;
+
+    while database.is_busy() {
+        database.maintain()?;
+    }
+
+    let order = order.lock().unwrap();
+    println!("Processing order: {order:?}");
+    assert_eq!(
+        *order,
+        vec![
+            "order://cat.txt".to_owned(),
+            "order://lorem.txt".to_owned(),
+            "order://logo.txt".to_owned(),
+        ]
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+// Protocol that records order in which assets got processed.
+struct OrderProtocol {
+    order: Arc<Mutex<Vec<String>>>,
+}
+
+impl AssetProtocol for OrderProtocol {
+    fn name(&self) -> &str {
+        "order"
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let path = storage
+            .component::<true, AssetPathStatic>(handle.entity())?
+            .to_string();
+        self.order
+            .lock()
+            .map_err(|error| format!("{error}"))?
+            .push(path);
+        storage.insert(handle.entity(), (bytes,))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{bundle::BundleAssetProtocol, text::TextAssetProtocol},
+};
+use serde::Deserialize;
+use std::error::Error;
+
+#[derive(Debug, Clone, Deserialize)]
+struct Person {
+    age: u8,
+    friends: Vec<String>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BundleAssetProtocol::new("person", |bytes: Vec<u8>| {
+            let asset = serde_json::from_slice::<Person>(&bytes)?;
+            Ok((asset,).into())
+        }))
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Guard against assets that would never finish loading.
+        .with_max_maintain_iterations(100);
+
+    // Load asset and get its component without manual maintain loops.
+    let lorem = database.load_now::<String>("text://lorem.txt")?;
+    println!("Lorem Ipsum: {lorem}");
+    assert_eq!(lorem, std::fs::read_to_string("resources/lorem.txt")?);
+
+    let person = database.load_now::<Person>("person://person.json")?;
+    println!("Person: {person:#?}");
+    assert_eq!(person.age, 41);
+    assert_eq!(
+        person.friends,
+        vec![
+            "Florrie, Etheline",
+            "Lizbeth, Felicity",
+            "Matilda, Suellen",
+            "Glennie, Fidelity",
+            "Simona, Evy",
+        ]
+    );
+
+    // Asset gets fully loaded, so it can be accessed as usual afterwards.
+    let handle = database
+        .find("person://person.json")
+        .ok_or("Person asset should exist")?;
+    assert!(handle.is_ready_to_use(&database));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
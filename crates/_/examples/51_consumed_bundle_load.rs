@@ -0,0 +1,45 @@
+use keket::{
+    database::{AssetDatabase, tracker::ConsumedAssetLoader},
+    fetch::file::FileAssetFetch,
+    protocol::bundle::BundleAssetProtocol,
+};
+use std::error::Error;
+
+#[derive(Debug, Default)]
+struct LinesCount(usize);
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Protocol producing asset with multiple components.
+        .with_protocol(BundleAssetProtocol::new("counted", |bytes: Vec<u8>| {
+            let text = String::from_utf8(bytes)?;
+            let count = LinesCount(text.lines().count());
+            Ok((text, count).into())
+        }))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Load single asset and consume both of its components when it's done.
+    let mut loader = ConsumedAssetLoader::<(String, LinesCount)>::path("counted://lorem.txt");
+    while loader.is_in_progress() {
+        database.maintain()?;
+        loader.maintain(&mut database);
+    }
+
+    let (text, count) = match loader {
+        ConsumedAssetLoader::Data(data) => data,
+        ConsumedAssetLoader::Error(error) => return Err(error),
+        _ => return Err("Asset loading has not finished".into()),
+    };
+    println!("Lorem Ipsum with {} lines: {text}", count.0);
+
+    // Both components of the asset are returned.
+    assert_eq!(text, std::fs::read_to_string("resources/lorem.txt")?);
+    assert_eq!(count.0, 9);
+
+    // Consumed asset is gone from database.
+    assert!(database.find("counted://lorem.txt").is_none());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
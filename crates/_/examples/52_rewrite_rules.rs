@@ -0,0 +1,49 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::{file::FileAssetFetch, rewrite::RewriteAssetFetch},
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        // Rewrite rules are applied in order before inner fetch loads asset.
+        .with_fetch(
+            RewriteAssetFetch::new_empty(FileAssetFetch::default().with_root("resources"))
+                // Remap virtual `docs` directory to resources root.
+                .with_prefix("text://docs/", "text://")
+                // Normalize file names to lowercase, keeping protocol and meta.
+                .with_fn(|path| {
+                    Ok(AssetPath::from_parts(
+                        path.protocol(),
+                        &path.path().to_lowercase(),
+                        path.meta(),
+                    ))
+                }),
+        );
+
+    // Gets `text://lorem.txt`.
+    let lorem = database.ensure("text://docs/LOREM.txt")?;
+    println!("Lorem Ipsum: {}", lorem.access::<&String>(&database));
+    assert_eq!(
+        lorem.access::<&String>(&database),
+        &std::fs::read_to_string("resources/lorem.txt")?
+    );
+
+    // Paths not matching prefix are only normalized.
+    let cat = database.ensure("text://Cat.txt")?;
+    println!("Cat: {}", cat.access::<&String>(&database));
+    assert_eq!(
+        cat.access::<&String>(&database),
+        &std::fs::read_to_string("resources/cat.txt")?
+    );
+
+    // Prefix rule runs before normalization, so uppercase virtual directory
+    // is not remapped and ends up as missing `docs/lorem.txt` file.
+    assert!(database.ensure("text://DOCS/lorem.txt").is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
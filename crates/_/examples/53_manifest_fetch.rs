@@ -0,0 +1,41 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{file::FileAssetFetch, manifest::ManifestAssetFetch},
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        // Manifest fetch maps logical paths to fingerprinted file names,
+        // generated at build time for cache busting.
+        .with_fetch(ManifestAssetFetch::from_file(
+            FileAssetFetch::default().with_root("resources"),
+            "./resources/assets.manifest.json",
+        )?);
+
+    // Gets `text://credits.5f3a9c.txt`.
+    let credits = database.ensure("text://credits.txt")?;
+    println!("Credits: {}", credits.access::<&String>(&database));
+
+    // Assets missing in manifest fall back to their original paths.
+    let lorem = database.ensure("text://lorem.txt")?;
+    println!("Lorem Ipsum: {}", lorem.access::<&String>(&database));
+
+    // Strict manifest fetch fails for assets missing in manifest.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(
+            ManifestAssetFetch::from_file(
+                FileAssetFetch::default().with_root("resources"),
+                "./resources/assets.manifest.json",
+            )?
+            .strict(),
+        );
+    assert!(database.ensure("text://lorem.txt").is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
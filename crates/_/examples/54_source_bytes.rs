@@ -0,0 +1,26 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{AssetSourceBytes, file::FileAssetFetch},
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Keep copy of fetched bytes of every asset, for debugging purposes.
+        .with_retain_source_bytes();
+
+    let lorem = database.ensure("text://lorem.txt")?;
+    database.maintain_until_idle()?;
+
+    // Original bytes are available next to processed asset data.
+    let source = lorem.access::<&AssetSourceBytes>(&database);
+    println!("Lorem Ipsum source bytes: {}", source.0.len());
+    assert_eq!(source.0, std::fs::read("./resources/lorem.txt")?);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anput::{bundle::DynamicBundle, world::World};
+use keket::{
+    database::path::AssetPath,
+    fetch::{AssetFetch, deferred::DeferredAssetFetch, file::FileAssetFetch},
+};
+use std::{
+    error::Error,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut fetch = DeferredAssetFetch::new(CountingAssetFetch {
+        fetch: FileAssetFetch::default().with_root("resources"),
+        calls: calls.clone(),
+    });
+
+    // Two systems request the same asset before it gets fetched.
+    fetch.load_bytes(AssetPath::new("text://lorem.txt"))?;
+    fetch.load_bytes(AssetPath::new("text://lorem.txt"))?;
+
+    // Both requests share single in-flight job.
+    let mut storage = World::default();
+    while calls.load(Ordering::SeqCst) == 0 {
+        fetch.maintain(&mut storage)?;
+        std::thread::yield_now();
+    }
+    for _ in 0..10 {
+        fetch.maintain(&mut storage)?;
+    }
+    println!("Inner fetch calls: {}", calls.load(Ordering::SeqCst));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+// Fetch counting calls to the inner fetch.
+struct CountingAssetFetch {
+    fetch: FileAssetFetch,
+    calls: Arc<AtomicUsize>,
+}
+
+impl AssetFetch for CountingAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.fetch.load_bytes(path)
+    }
+}
@@ -0,0 +1,39 @@
+use keket::{
+    database::AssetDatabase, fetch::testing::NullAssetFetch, protocol::text::TextAssetProtocol,
+    store::testing::NullAssetStore,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Null store records saved assets in memory, shared between its clones.
+    let store = NullAssetStore::default();
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(NullAssetFetch::default().with("hello.txt", "Hello World!"))
+        .with_store(store.clone());
+
+    // Store asset and check what protocol produced.
+    let hello = database.ensure("text://hello.txt")?;
+    hello.store(&mut database)?;
+    database.maintain_until_idle()?;
+    let saved = store.get("hello.txt").unwrap();
+    assert_eq!(saved, b"Hello World!");
+
+    // Load stored bytes back to check protocol round-trip.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(NullAssetFetch::default().with("hello.txt", saved));
+    let hello = database.ensure("text://hello.txt")?;
+    println!("Round-trip: {}", hello.access::<&String>(&database));
+    assert_eq!(hello.access::<&String>(&database), "Hello World!");
+
+    // Failing fetch allows to test error handling.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(NullAssetFetch::default().failing("Network is down"));
+    assert!(database.ensure("text://hello.txt").is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{AssetByteRange, file::FileAssetFetch},
+    protocol::bytes::BytesAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let content = std::fs::read("./resources/lorem.txt")?;
+
+    // Range meta makes fetch read only requested bytes, with inclusive end.
+    let header = database.ensure("bytes://lorem.txt?range=0-10")?;
+    println!(
+        "Header: {:?} of range: {:?}",
+        String::from_utf8_lossy(header.access::<&Vec<u8>>(&database)),
+        header.access::<&AssetByteRange>(&database)
+    );
+    assert_eq!(header.access::<&Vec<u8>>(&database), &content[0..=10]);
+
+    // Open ranges span until the end of content.
+    let tail = database.ensure("bytes://lorem.txt?range=100-")?;
+    assert_eq!(tail.access::<&Vec<u8>>(&database), &content[100..]);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
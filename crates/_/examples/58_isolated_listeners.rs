@@ -0,0 +1,56 @@
+use keket::{
+    database::AssetDatabase, fetch::file::FileAssetFetch, protocol::text::TextAssetProtocol,
+};
+use std::{
+    error::Error,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Failing listener does not prevent others from getting events.
+        .with_isolated_listeners();
+
+    let first = Arc::new(AtomicUsize::new(0));
+    let third = Arc::new(AtomicUsize::new(0));
+    {
+        let first = first.clone();
+        database.events.bind(move |_| {
+            first.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+    }
+    database
+        .events
+        .bind(|event| Err(format!("Misbehaving listener got: {}", event.path).into()));
+    {
+        let third = third.clone();
+        database.events.bind(move |_| {
+            third.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+    }
+
+    database.ensure("text://lorem.txt")?;
+
+    // Errors of failed listeners are reported together after dispatching.
+    if let Err(error) = database.maintain() {
+        println!("Maintenance error: {error}");
+    }
+    println!(
+        "Events received by first: {} and third: {}",
+        first.load(Ordering::SeqCst),
+        third.load(Ordering::SeqCst)
+    );
+    assert!(first.load(Ordering::SeqCst) > 0);
+    assert_eq!(first.load(Ordering::SeqCst), third.load(Ordering::SeqCst));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
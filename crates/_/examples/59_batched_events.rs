@@ -0,0 +1,57 @@
+use keket::{
+    database::{AssetDatabase, events::AssetEventBindings},
+    fetch::file::FileAssetFetch,
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Batched listener gets all events of single maintenance at once, which
+    // is great for listeners doing expensive work, like re-rendering UI.
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    {
+        let batches = batches.clone();
+        database.events.bind_batched(move |events| {
+            println!("Batch of {} events:", events.len());
+            for event in events {
+                println!("- {:?}: {}", event.kind, event.path);
+            }
+            batches.lock().unwrap().push(events.len());
+        });
+    }
+
+    // Batched listeners can be bound to single asset as well.
+    let asset_batches = Arc::new(Mutex::new(Vec::new()));
+    let cat = database.schedule("text://cat.txt")?;
+    {
+        let asset_batches = asset_batches.clone();
+        cat.ensure::<AssetEventBindings>(&mut database)?
+            .bind_batched(move |events| {
+                asset_batches.lock().unwrap().push(events.len());
+            });
+    }
+
+    database.ensure("text://lorem.txt")?;
+    database.ensure("text://cat.txt")?;
+    database.ensure("bytes://trash.bin")?;
+    database.maintain()?;
+
+    let batches = batches.lock().unwrap();
+    assert_eq!(batches.len(), 1);
+    assert!(batches[0] >= 3);
+    let asset_batches = asset_batches.lock().unwrap();
+    assert_eq!(asset_batches.len(), 1);
+    assert!(asset_batches[0] >= 1);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::testing::NullAssetFetch,
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use moirai::jobs::Jobs;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Many byte-heavy assets, each with distinct content.
+    let mut fetch = NullAssetFetch::default();
+    for index in 0..64 {
+        let content = format!("{index}:").repeat(16 * 1024);
+        fetch = fetch
+            .with(format!("{index}.txt"), content.clone())
+            .with(format!("{index}.bin"), content);
+    }
+    let paths = (0..64)
+        .flat_map(|index| {
+            [
+                format!("text://{index}.txt"),
+                format!("bytes://{index}.bin"),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut sequential = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(fetch.clone());
+    let mut parallel = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(fetch);
+    for path in &paths {
+        sequential.schedule(path.clone())?;
+        parallel.schedule(path.clone())?;
+    }
+
+    // Protocols providing bytes decoders get their assets decoded on jobs.
+    let jobs = Jobs::default();
+    while parallel.is_busy() {
+        parallel.maintain_parallel(&jobs)?;
+    }
+    sequential.maintain_until_idle()?;
+
+    for path in &paths {
+        let a = sequential.find(path.clone()).unwrap();
+        let b = parallel.find(path.clone()).unwrap();
+        if path.starts_with("text://") {
+            assert_eq!(
+                a.access::<&String>(&sequential),
+                b.access::<&String>(&parallel)
+            );
+        } else {
+            assert_eq!(
+                a.access::<&Vec<u8>>(&sequential),
+                b.access::<&Vec<u8>>(&parallel)
+            );
+        }
+    }
+    println!(
+        "Parallel maintain matches sequential for {} assets",
+        paths.len()
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
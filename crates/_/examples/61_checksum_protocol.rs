@@ -0,0 +1,34 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{
+        checksum::{AssetChecksum, ChecksumAssetProtocol},
+        text::TextAssetProtocol,
+    },
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Checksum protocol hashes source bytes before inner protocol processes them.
+        .with_protocol(ChecksumAssetProtocol::new(TextAssetProtocol))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let lorem = database.ensure("text://lorem.txt")?;
+    let cat = database.ensure("text://cat.txt")?;
+    let lorem_checksum = *lorem.access::<&AssetChecksum>(&database);
+    let cat_checksum = *cat.access::<&AssetChecksum>(&database);
+    println!("Lorem checksum: {}", lorem_checksum.to_hex());
+    println!("Cat checksum: {}", cat_checksum.to_hex());
+
+    // Identical content yields identical checksum.
+    let content = std::fs::read("resources/lorem.txt")?;
+    assert_eq!(lorem_checksum, AssetChecksum::from_bytes(&content));
+
+    // Different content yields different checksum.
+    assert_ne!(lorem_checksum, cat_checksum);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
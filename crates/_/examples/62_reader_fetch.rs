@@ -0,0 +1,36 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::reader::ReaderAssetFetch,
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::{
+    error::Error,
+    io::{Cursor, ErrorKind, Read},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        // Reader fetch turns any `Read` source into asset bytes.
+        .with_fetch(ReaderAssetFetch::new(|path| match path.path() {
+            "hello.txt" => Ok(Box::new(Cursor::new(b"Hello World!".to_vec())) as Box<dyn Read>),
+            "numbers.bin" => Ok(Box::new(Cursor::new((0..10).collect::<Vec<u8>>()))),
+            _ => Err(ErrorKind::NotFound.into()),
+        }));
+
+    let hello = database.ensure("text://hello.txt")?;
+    println!("Hello: {}", hello.access::<&String>(&database));
+    assert_eq!(hello.access::<&String>(&database), "Hello World!");
+
+    // Byte ranges are read by skipping and limiting reader content.
+    let numbers = database.ensure("bytes://numbers.bin?range=2-4")?;
+    assert_eq!(numbers.access::<&Vec<u8>>(&database), &[2, 3, 4]);
+
+    // Reader factory errors are reported as fetch errors.
+    assert!(database.ensure("text://missing.txt").is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{file::FileAssetFetch, reader::ReaderAssetFetch},
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::{error::Error, io::Cursor};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Single call wires `text` scheme to its own fetch and protocol.
+        .with_source(
+            "text",
+            ReaderAssetFetch::new(|path| {
+                Ok(Box::new(Cursor::new(format!(
+                    "Hello from {}!",
+                    path.path()
+                ))))
+            }),
+            TextAssetProtocol,
+        );
+
+    let hello = database.ensure("text://world")?;
+    println!("Text: {}", hello.access::<&String>(&database));
+    assert_eq!(hello.access::<&String>(&database), "Hello from world!");
+
+    // Other schemes still go to previously set fetch.
+    let trash = database.ensure("bytes://trash.bin")?;
+    assert_eq!(
+        trash.access::<&Vec<u8>>(&database),
+        &std::fs::read("resources/trash.bin")?
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use keket::{
+    database::{
+        AssetDatabase, AssetFirstAccessed,
+        events::AssetEventKind,
+        reference::AssetRef,
+        tracker::{AssetsStatus, AssetsTracker},
+    },
+    fetch::file::FileAssetFetch,
+    protocol::{
+        bundle::BundleAssetProtocol, bytes::BytesAssetProtocol, group::GroupAssetProtocol,
+        text::TextAssetProtocol,
+    },
+};
+use serde_json::Value;
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_protocol(BundleAssetProtocol::new("json", |bytes: Vec<u8>| {
+            Ok((serde_json::from_slice::<Value>(&bytes)?,).into())
+        }))
+        .with_protocol(GroupAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Access tracking records first access to assets data.
+        .with_access_tracking();
+
+    let accessed = Arc::new(Mutex::new(Vec::new()));
+    {
+        let accessed = accessed.clone();
+        database.events.bind(move |event| {
+            if event.kind == AssetEventKind::Accessed {
+                accessed.lock().unwrap().push(event.path.to_string());
+            }
+            Ok(())
+        });
+    }
+
+    let group = database.ensure("group://group.txt")?;
+    database.maintain_until_idle()?;
+    let lorem = database.ensure("text://lorem.txt")?;
+    let trash = database.ensure("bytes://trash.bin")?;
+
+    // Reporting status, sorting and traversing dependencies are not uses of
+    // assets data, so none of them get recorded.
+    let tracker = AssetsTracker::default().with_many([group, lorem, trash]);
+    let mut status = AssetsStatus::default();
+    tracker.report(&database, &mut status);
+    assert_eq!(status.ready_to_use.len(), 3);
+    assert_eq!(tracker.iter_sorted(&database).count(), 3);
+    let dependencies = AssetRef::new("group://group.txt")
+        .resolve(&database)?
+        .traverse_dependencies()
+        .count();
+    assert_eq!(dependencies, 3);
+    database.maintain()?;
+    for handle in [group, lorem, trash] {
+        assert!(!handle.has::<AssetFirstAccessed>(&database));
+    }
+    assert!(accessed.lock().unwrap().is_empty());
+
+    // First access gets recorded on next maintenance.
+    assert!(lorem.access::<&String>(&database).starts_with("Lorem"));
+    assert!(!lorem.has::<AssetFirstAccessed>(&database));
+    database.maintain()?;
+    assert!(lorem.has::<AssetFirstAccessed>(&database));
+    let first = *lorem.access::<&AssetFirstAccessed>(&database);
+
+    // Later accesses do not change time of first access.
+    database.maintain()?;
+    assert_eq!(*lorem.access::<&AssetFirstAccessed>(&database), first);
+
+    // Assets loaded but never used can be found.
+    assert!(!group.has::<AssetFirstAccessed>(&database));
+    assert!(!trash.has::<AssetFirstAccessed>(&database));
+    assert_eq!(
+        *accessed.lock().unwrap(),
+        vec!["text://lorem.txt".to_owned()]
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use keket::{
+    database::{
+        AssetDatabase, AssetDependencyDepthExceeded, events::AssetEventKind,
+        handle::AssetDependency,
+    },
+    protocol::text::TextAssetProtocol,
+};
+use std::{
+    error::Error,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        // Dependency traversals do not go deeper than this.
+        .with_max_dependency_depth(4);
+
+    let exceeded = Arc::new(AtomicUsize::new(0));
+    {
+        let exceeded = exceeded.clone();
+        database.events.bind(move |event| {
+            if event.kind == AssetEventKind::DepthExceeded {
+                exceeded.fetch_add(1, Ordering::SeqCst);
+            }
+            Ok(())
+        });
+    }
+
+    // Build chain of dependencies deeper than the limit.
+    let chain = (0..10)
+        .map(|index| database.spawn(format!("text://{index}.txt"), (index.to_string(),)))
+        .collect::<Result<Vec<_>, _>>()?;
+    for pair in chain.windows(2) {
+        database
+            .storage
+            .relate::<true, _>(AssetDependency, pair[0].entity(), pair[1].entity())?;
+    }
+    let root = chain[0];
+
+    // Traversal gets truncated at the limit.
+    let reachable = root.traverse_dependencies(&database).count();
+    println!("Reachable assets: {reachable}");
+    assert_eq!(reachable, 5);
+
+    // Root gets marked and event gets dispatched on next maintenance.
+    database.maintain()?;
+    assert!(root.has::<AssetDependencyDepthExceeded>(&database));
+    assert_eq!(exceeded.load(Ordering::SeqCst), 1);
+
+    // Shallow graphs are not affected.
+    assert_eq!(chain[6].traverse_dependencies(&database).count(), 4);
+    database.maintain()?;
+    assert!(!chain[6].has::<AssetDependencyDepthExceeded>(&database));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
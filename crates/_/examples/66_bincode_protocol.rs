@@ -0,0 +1,65 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::bincode::{BincodeAssetProcessor, BincodeAssetProtocol, BincodeIntEncoding},
+    store::file::FileAssetStore,
+};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SaveGame {
+    level: u32,
+    score: u64,
+    player: String,
+}
+
+// Newer version of save game with changed layout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SaveGameV2 {
+    level: u32,
+    score: u64,
+    player: String,
+    inventory: Vec<String>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(BincodeAssetProtocol::new(
+            "bincode",
+            // Fixed size integers are faster to decode, varint ones are smaller.
+            BincodeAssetProcessor::<SaveGame>::default().int_encoding(BincodeIntEncoding::Fixint),
+        ))
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_store(FileAssetStore::default().with_root("resources"));
+
+    let save = SaveGame {
+        level: 3,
+        score: 42000,
+        player: "Ferris".to_owned(),
+    };
+
+    // Store asset and load it back in fresh database to check round-trip.
+    let handle = database.spawn("bincode://save.bin", (save.clone(),))?;
+    handle.store(&mut database)?;
+    database.maintain_until_idle()?;
+    handle.delete(&mut database)?;
+    let loaded = database.ensure("bincode://save.bin")?;
+    println!("Loaded: {:?}", loaded.access::<&SaveGame>(&database));
+    assert_eq!(loaded.access::<&SaveGame>(&database), &save);
+
+    // Decoding asset stored by other version of the type fails clearly.
+    let mut database = AssetDatabase::default()
+        .with_protocol(BincodeAssetProtocol::new(
+            "bincode",
+            BincodeAssetProcessor::<SaveGameV2>::default().int_encoding(BincodeIntEncoding::Fixint),
+        ))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+    let error = database.ensure("bincode://save.bin").unwrap_err();
+    println!("Version mismatch: {error}");
+    assert!(error.to_string().contains("SaveGameV2"));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
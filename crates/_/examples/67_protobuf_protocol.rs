@@ -0,0 +1,65 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPathStatic},
+    fetch::{file::FileAssetFetch, reader::ReaderAssetFetch},
+    protocol::{
+        protobuf::{ProtobufAssetProcessor, ProtobufAssetProtocol},
+        text::TextAssetProtocol,
+    },
+    store::file::FileAssetStore,
+    third_party::prost::Message,
+};
+use std::{error::Error, io::Cursor};
+
+#[derive(Clone, PartialEq, Message)]
+struct Material {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(string, repeated, tag = "2")]
+    textures: Vec<String>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let material = Material {
+        name: "Cat".to_owned(),
+        textures: vec!["cat.txt".to_owned(), "lorem.txt".to_owned()],
+    };
+    let bytes = material.encode_to_vec();
+
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_store(FileAssetStore::default().with_root("resources"))
+        .with_source(
+            "protobuf",
+            // Messages usually come from backend, here we serve encoded bytes.
+            ReaderAssetFetch::new(move |_| Ok(Box::new(Cursor::new(bytes.clone())))),
+            ProtobufAssetProtocol::new(
+                "protobuf",
+                // Messages can reference other assets by their IDs.
+                ProtobufAssetProcessor::<Material>::default().dependencies(|material| {
+                    material
+                        .textures
+                        .iter()
+                        .map(|id| AssetPathStatic::new(format!("text://{id}")))
+                        .collect()
+                }),
+            ),
+        );
+
+    let handle = database.ensure("protobuf://material.bin")?;
+    database.maintain_until_idle()?;
+    println!("Material: {:?}", handle.access::<&Material>(&database));
+    assert_eq!(handle.access::<&Material>(&database), &material);
+    assert_eq!(handle.dependencies(&database).count(), 2);
+    assert!(handle.is_ready_to_use(&database));
+
+    // Stored message is encoded back to protobuf.
+    handle.store(&mut database)?;
+    database.maintain_until_idle()?;
+    let stored = std::fs::read("resources/material.bin")?;
+    assert_eq!(Material::decode(stored.as_slice())?, material);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
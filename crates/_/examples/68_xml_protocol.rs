@@ -0,0 +1,99 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{bundle::BundleAssetProtocol, xml::XmlAssetProcessor},
+    store::file::FileAssetStore,
+};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+// Attribute-heavy document, with namespaced attribute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Sprite {
+    #[serde(rename = "@id")]
+    id: String,
+    #[serde(rename = "@width")]
+    width: u32,
+    #[serde(rename = "@height")]
+    height: u32,
+    #[serde(rename = "@pivot-x")]
+    pivot_x: f32,
+    #[serde(rename = "@pivot-y")]
+    pivot_y: f32,
+    #[serde(rename = "@ui:layer")]
+    layer: String,
+}
+
+// Nested-element document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Entity {
+    name: String,
+    position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Scene {
+    name: String,
+    #[serde(rename = "entity")]
+    entities: Vec<Entity>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(BundleAssetProtocol::new(
+            "sprite",
+            XmlAssetProcessor::<Sprite>::default(),
+        ))
+        // Stored scenes get indented and named root element.
+        .with_protocol(BundleAssetProtocol::new(
+            "scene",
+            XmlAssetProcessor::<Scene>::default()
+                .root("scene")
+                .indent(' ', 4),
+        ))
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_store(FileAssetStore::default().with_root("resources"));
+
+    let sprite = database.ensure("sprite://sprite.xml")?;
+    println!("Sprite: {:#?}", sprite.access::<&Sprite>(&database));
+    assert_eq!(
+        sprite.access::<&Sprite>(&database),
+        &Sprite {
+            id: "player".to_owned(),
+            width: 32,
+            height: 48,
+            pivot_x: 0.5,
+            pivot_y: 1.0,
+            layer: "characters".to_owned(),
+        }
+    );
+
+    let scene = database.ensure("scene://scene.xml")?;
+    let mut scene = scene.access::<&Scene>(&database).clone();
+    println!("Scene: {scene:#?}");
+    assert_eq!(scene.entities.len(), 2);
+    assert_eq!(scene.entities[1].position, Position { x: -5, y: 3 });
+
+    // Stored scene loads back the same.
+    scene.name = "Forest (modified)".to_owned();
+    let saved = database.spawn("scene://saved_scene.xml", (scene.clone(),))?;
+    saved.store(&mut database)?;
+    database.maintain_until_idle()?;
+    saved.delete(&mut database)?;
+    let loaded = database.ensure("scene://saved_scene.xml")?;
+    println!(
+        "Saved: {}",
+        std::fs::read_to_string("./resources/saved_scene.xml")?
+    );
+    assert_eq!(loaded.access::<&Scene>(&database), &scene);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use keket::{
+    database::{AssetDatabase, tags::AssetTags},
+    fetch::file::FileAssetFetch,
+    protocol::bundle::{BundleAssetProtocol, BundleWithDependencies},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(
+            BundleAssetProtocol::new("text", |bytes: Vec<u8>| {
+                Ok(BundleWithDependencies::new((String::from_utf8(bytes)?,)))
+            })
+            // Post-process steps run after bundle gets inserted into asset.
+            .with_post_process(|handle, storage| {
+                storage.insert(handle.entity(), (AssetTags::new("text"),))?;
+                Ok(())
+            })
+            .with_post_process(|handle, storage| {
+                let text = storage.component::<true, String>(handle.entity())?;
+                if text.is_empty() {
+                    return Err("Text asset cannot be empty".into());
+                }
+                Ok(())
+            }),
+        )
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let lorem = database.ensure("text://lorem.txt")?;
+    let tags = lorem.access::<&AssetTags>(&database);
+    println!("Tags: {:?}", tags.iter().collect::<Vec<_>>());
+    assert!(tags.iter().any(|tag| tag == "text"));
+
+    // Failing post-process reports asset processing error.
+    assert!(database.ensure("text://empty.txt").is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use keket::{
+    database::{
+        AssetDatabase,
+        events::{AssetEventBindings, AssetEventKind},
+    },
+    fetch::{FetchError, deferred::DeferredAssetFetch, file::FileAssetFetch},
+    protocol::text::TextAssetProtocol,
+};
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Fetch errors can be downcasted to tell failure reasons apart.
+    let error = database.ensure("text://missing.txt").unwrap_err();
+    println!("Missing: {error}");
+    assert!(matches!(
+        error.downcast_ref::<FetchError>(),
+        Some(FetchError::NotFound(_))
+    ));
+
+    // File used as directory cannot be read, but it is not missing.
+    let error = database.ensure("text://lorem.txt/inner.txt").unwrap_err();
+    println!("Unreadable: {error}");
+    let error = error.downcast_ref::<FetchError>().unwrap();
+    assert!(!error.is_not_found());
+
+    // Missing assets get distinct event, reported with path user asked for.
+    let mut database = database
+        .with_asset_progression_failures()
+        .with_alias("text://ghost", "text://another_missing.txt");
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let handle = database.schedule("text://ghost")?;
+    {
+        let events = events.clone();
+        handle
+            .ensure::<AssetEventBindings>(&mut database)?
+            .bind(move |event| {
+                events
+                    .lock()
+                    .unwrap()
+                    .push((event.kind, event.path.to_string()));
+                Ok(())
+            });
+    }
+    database.maintain()?;
+    println!("Events: {:?}", events.lock().unwrap());
+    assert!(
+        events
+            .lock()
+            .unwrap()
+            .contains(&(AssetEventKind::BytesNotFound, "text://ghost".to_owned()))
+    );
+
+    // Asynchronous fetches keep errors typed too.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(DeferredAssetFetch::new(
+            FileAssetFetch::default().with_root("resources"),
+        ));
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let handle = database.schedule("text://deferred_missing.txt")?;
+    {
+        let events = events.clone();
+        handle
+            .ensure::<AssetEventBindings>(&mut database)?
+            .bind(move |event| {
+                events.lock().unwrap().push(event.kind);
+                Ok(())
+            });
+    }
+    let error = database.maintain_until_idle().unwrap_err();
+    println!("Deferred missing: {error}");
+    assert!(matches!(
+        error.downcast_ref::<FetchError>(),
+        Some(FetchError::NotFound(_))
+    ));
+    assert!(
+        events
+            .lock()
+            .unwrap()
+            .contains(&AssetEventKind::BytesNotFound)
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
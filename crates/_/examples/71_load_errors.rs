@@ -0,0 +1,39 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::bundle::{BundleAssetProtocol, BundleWithDependencies},
+};
+use serde_json::Value;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(BundleAssetProtocol::new("json", |bytes: Vec<u8>| {
+            Ok(BundleWithDependencies::new((serde_json::from_slice::<
+                Value,
+            >(&bytes)?,)))
+        }))
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_asset_progression_failures();
+
+    // Plain text is not valid JSON, so processing fails.
+    let broken = database.ensure("json://lorem.txt")?;
+    let missing = database.ensure("json://missing.json")?;
+    let person = database.ensure("json://person.json")?;
+
+    // Polling code can check for failures without event listeners.
+    for handle in [broken, missing, person] {
+        match handle.error(&database) {
+            Some(error) => println!("Failed: {error}"),
+            None => println!("Loaded: {}", handle.access::<&Value>(&database)),
+        }
+    }
+    assert!(broken.failed(&database));
+    assert!(broken.error(&database).unwrap().contains("expected value"));
+    assert!(missing.failed(&database));
+    assert!(!person.failed(&database));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use keket::{
+    database::{AssetDatabase, tracker::AssetsStatus},
+    fetch::file::FileAssetFetch,
+    protocol::{
+        bundle::{BundleAssetProtocol, BundleWithDependencies},
+        text::TextAssetProtocol,
+    },
+};
+use serde_json::Value;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BundleAssetProtocol::new("json", |bytes: Vec<u8>| {
+            Ok(BundleWithDependencies::new((serde_json::from_slice::<
+                Value,
+            >(&bytes)?,)))
+        }))
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_asset_progression_failures()
+        .with_retain_source_bytes();
+
+    database.ensure("text://lorem.txt")?;
+    database.ensure("json://person.json")?;
+    // Plain text is not valid JSON, so this one fails.
+    let broken = database.ensure("json://lorem.txt")?;
+
+    let mut status = AssetsStatus::list();
+    database.report_loading_status(&mut status);
+    let progress = status.progress();
+    println!(
+        "Loading {}% ({} ready, {} failed, {} total)",
+        progress.factor() * 100.0,
+        progress.ready_to_use,
+        progress.failed,
+        progress.total()
+    );
+    for handle in status.failed.iter() {
+        println!("Failed: {}", handle.path(&database)?.content());
+    }
+
+    // Failed assets are finished too, so loading does not get stuck.
+    assert!(progress.is_complete());
+    assert_eq!(progress.factor(), 1.0);
+    assert_eq!(progress.ready_to_use, 2);
+    assert_eq!(progress.failed, 1);
+
+    // Assets loaded again are reported by their loading stage, not by error
+    // of their previous load.
+    broken.reprocess(&mut database)?;
+    database.report_loading_status(&mut status);
+    let progress = status.progress();
+    assert_eq!(progress.failed, 0);
+    assert_eq!(progress.with_bytes_ready_to_process, 1);
+    assert!(progress.is_in_progress());
+
+    database.maintain_until_idle()?;
+    database.report_loading_status(&mut status);
+    assert_eq!(status.progress().failed, 1);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use keket::{
+    database::{AssetDatabase, tracker::AssetsStatus},
+    fetch::{deferred::DeferredAssetFetch, file::FileAssetFetch},
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(DeferredAssetFetch::new(
+            FileAssetFetch::default().with_root("resources"),
+        ));
+
+    // Ensure all assets of a loading screen and get tracker for them at once.
+    let (handles, tracker) = database.ensure_tracked(["text://lorem.txt", "bytes://ferris.png"])?;
+    assert_eq!(handles.len(), 2);
+    assert_eq!(tracker.len(), 2);
+
+    // Deferred fetch keeps assets awaiting fetch until maintenance.
+    let mut status = AssetsStatus::amount();
+    tracker.report(&database, &mut status);
+    let progress = status.progress();
+    assert_eq!(progress.awaiting_async_fetch, 2);
+    assert_eq!(progress.ready_to_use, 0);
+
+    while database.is_busy() {
+        database.maintain()?;
+    }
+
+    tracker.report(&database, &mut status);
+    let progress = status.progress();
+    println!("Ready: {}/{}", progress.ready_to_use, progress.total());
+    assert!(progress.is_complete());
+    assert_eq!(progress.ready_to_use, 2);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
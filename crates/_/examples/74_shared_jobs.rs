@@ -0,0 +1,68 @@
+use anput::{bundle::DynamicBundle, third_party::intuicio_data::managed::Managed};
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::{AssetFetch, deferred::DeferredAssetFetch, file::FileAssetFetch},
+    protocol::text::TextAssetProtocol,
+    store::{deferred::DeferredAssetStore, file::FileAssetStore},
+};
+use moirai::jobs::Jobs;
+use std::{
+    error::Error,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Single jobs runner bounds concurrency of both fetching and storing.
+    let mut jobs = Managed::new(Jobs::default());
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        // Only one of engines sharing jobs runner drives its local jobs.
+        .with_fetch(
+            DeferredAssetFetch::new(CountingAssetFetch {
+                fetch: FileAssetFetch::default().with_root("resources"),
+                calls: calls.clone(),
+            })
+            .jobs(jobs.lazy())
+            .drive_jobs(),
+        )
+        .with_store(
+            DeferredAssetStore::new(FileAssetStore::default().with_root("resources"))
+                .jobs(jobs.lazy()),
+        );
+
+    let lorem = database.ensure("text://lorem.txt")?;
+    let saved = database.spawn("text://saved4.txt", ("Shared jobs!".to_owned(),))?;
+    saved.store(&mut database)?;
+    database.maintain_until_idle()?;
+
+    // Every job ran exactly once on shared runner.
+    println!("Inner fetch calls: {}", calls.load(Ordering::SeqCst));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert!(lorem.is_ready_to_use(&database));
+    assert_eq!(
+        std::fs::read_to_string("./resources/saved4.txt")?,
+        "Shared jobs!"
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+// Fetch counting calls to the inner fetch.
+struct CountingAssetFetch {
+    fetch: FileAssetFetch,
+    calls: Arc<AtomicUsize>,
+}
+
+impl AssetFetch for CountingAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.fetch.load_bytes(path)
+    }
+}
@@ -0,0 +1,32 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let lorem = database.ensure("text://lorem.txt")?;
+    let cat = database.ensure("text://cat.txt")?;
+    database.ensure("bytes://ferris.png")?;
+
+    // Systems processing all assets of a kind can iterate them directly.
+    let texts = database.assets_with_protocol("text").collect::<Vec<_>>();
+    for handle in &texts {
+        println!("Text asset: {}", handle.path(&database)?.content());
+    }
+    assert_eq!(texts.len(), 2);
+    assert!(texts.contains(&lorem));
+    assert!(texts.contains(&cat));
+    assert_eq!(database.assets_with_protocol("bytes").count(), 1);
+    assert_eq!(database.assets_with_protocol("json").count(), 0);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Assets without registered protocol get routed by their extension.
+        .with_protocol_for_extension("txt", "text");
+
+    // Bare paths get protocol from extension.
+    let bare = database.ensure("lorem.txt")?;
+    println!("Bare path: {}", bare.path(&database)?.content());
+    assert_eq!(bare.path(&database)?.protocol(), "text");
+    assert!(bare.access_checked::<&String>(&database).is_some());
+
+    // Unknown declared protocols get replaced too, so both point to same asset.
+    let any = database.ensure("any://lorem.txt")?;
+    assert_eq!(any, bare);
+
+    // Registered protocols take precedence over extension routing.
+    let bytes = database.ensure("bytes://lorem.txt")?;
+    assert_ne!(bytes, bare);
+    assert!(bytes.access_checked::<&Vec<u8>>(&database).is_some());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
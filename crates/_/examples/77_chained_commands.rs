@@ -0,0 +1,34 @@
+use anput::world::World;
+use keket::database::AssetDatabase;
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    // Command sends another command while being executed.
+    let sender = database.commands_sender();
+    {
+        let log = log.clone();
+        database
+            .commands_sender()
+            .send(Box::new(move |_: &mut World| {
+                log.lock().unwrap().push("first");
+                sender.send(Box::new(move |_: &mut World| {
+                    log.lock().unwrap().push("second");
+                }));
+            }));
+    }
+
+    // Chained commands settle within single maintenance.
+    database.maintain()?;
+    println!("Executed commands: {:?}", log.lock().unwrap());
+    assert_eq!(*log.lock().unwrap(), vec!["first", "second"]);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
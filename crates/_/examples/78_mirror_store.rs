@@ -0,0 +1,91 @@
+use anput::bundle::DynamicBundle;
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    protocol::text::TextAssetProtocol,
+    store::{
+        AssetStore, deferred::DeferredAssetStore, mirror::MirrorAssetStore, testing::NullAssetStore,
+    },
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Mirror store writes the same bytes to every store, like local cache
+    // and remote server.
+    let cache = NullAssetStore::default();
+    let server = NullAssetStore::default();
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_store(
+            MirrorAssetStore::default()
+                .with(cache.clone())
+                .with(server.clone()),
+        );
+
+    let hello = database.spawn("text://hello.txt", ("Hello World!".to_owned(),))?;
+    hello.store(&mut database)?;
+    database.maintain_until_idle()?;
+    assert_eq!(cache.get("hello.txt").unwrap(), b"Hello World!");
+    assert_eq!(server.get("hello.txt").unwrap(), b"Hello World!");
+
+    // Failure of any mirrored store gets reported.
+    let cache = NullAssetStore::default();
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_store(
+            MirrorAssetStore::default()
+                .with(cache.clone())
+                .with(FailingAssetStore),
+        );
+
+    let hello = database.spawn("text://hello.txt", ("Hello World!".to_owned(),))?;
+    hello.store(&mut database)?;
+    let error = database.maintain_until_idle().unwrap_err();
+    println!("Mirror error: {error}");
+    assert!(error.to_string().contains("Server is down"));
+    assert_eq!(cache.get("hello.txt").unwrap(), b"Hello World!");
+
+    // Quorum allows some of stores to fail.
+    let cache = NullAssetStore::default();
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_store(
+            MirrorAssetStore::default()
+                .with(cache.clone())
+                .with(FailingAssetStore)
+                .quorum(1),
+        );
+
+    let hello = database.spawn("text://hello.txt", ("Hello World!".to_owned(),))?;
+    hello.store(&mut database)?;
+    database.maintain_until_idle()?;
+    assert_eq!(cache.get("hello.txt").unwrap(), b"Hello World!");
+
+    // Asynchronous stores cannot be mirrored, since their success is known
+    // only after mirror store counts successful stores.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_store(
+            MirrorAssetStore::default()
+                .with(NullAssetStore::default())
+                .with(DeferredAssetStore::new(NullAssetStore::default())),
+        );
+
+    let hello = database.spawn("text://hello.txt", ("Hello World!".to_owned(),))?;
+    hello.store(&mut database)?;
+    let error = database.maintain_until_idle().unwrap_err();
+    println!("Mirror error: {error}");
+    assert!(error.to_string().contains("not supported"));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+// Store that always fails, like unreachable server.
+struct FailingAssetStore;
+
+impl AssetStore for FailingAssetStore {
+    fn save_bytes(&self, _: AssetPath, _: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+        Err("Server is down".into())
+    }
+}
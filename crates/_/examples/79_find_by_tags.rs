@@ -0,0 +1,47 @@
+use keket::{
+    database::{
+        AssetDatabase,
+        tags::{AssetTags, TagMatch},
+    },
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol);
+
+    let music = database.spawn(
+        "bytes://level1/music.ogg",
+        (AssetTags::new("level1").with("audio"),),
+    )?;
+    let intro = database.spawn(
+        "text://level1/intro.txt",
+        (AssetTags::new("level1").with("text"),),
+    )?;
+    let theme = database.spawn("bytes://menu/theme.ogg", (AssetTags::new("audio"),))?;
+
+    // Find assets tagged with both `level1` and `audio`.
+    let query = AssetTags::new("level1").with("audio");
+    let all = database
+        .find_by_tags(&query, TagMatch::All)
+        .collect::<Vec<_>>();
+    for handle in &all {
+        println!("Level 1 audio: {}", handle.path(&database)?.content());
+    }
+    assert_eq!(all, vec![music]);
+
+    // Find assets tagged with either `level1` or `audio`.
+    let any = database
+        .find_by_tags(&query, TagMatch::Any)
+        .collect::<Vec<_>>();
+    assert_eq!(any.len(), 3);
+    assert!(any.contains(&music));
+    assert!(any.contains(&intro));
+    assert!(any.contains(&theme));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
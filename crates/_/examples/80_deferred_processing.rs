@@ -0,0 +1,95 @@
+use anput::bundle::DynamicBundle;
+use keket::{
+    database::{
+        AssetDatabase,
+        events::{AssetEventBindings, AssetEventKind},
+    },
+    fetch::file::FileAssetFetch,
+    protocol::{AssetBytesDecoder, AssetProtocol, deferred::DeferredAssetProtocol},
+};
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Deferred protocol decodes assets on background jobs.
+        .with_protocol(DeferredAssetProtocol::new(SlowTextAssetProtocol))
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let timer = Instant::now();
+    let lorem = database.ensure("slow://lorem.txt")?;
+    assert!(!lorem.is_ready_to_use(&database));
+
+    // Main thread stays responsive while asset gets decoded.
+    let mut frames = 0;
+    while database.is_busy() {
+        database.maintain()?;
+        frames += 1;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    println!(
+        "Decoded in {:?} while main thread ran {frames} frames",
+        timer.elapsed()
+    );
+    assert!(frames > 1);
+    assert!(lorem.is_ready_to_use(&database));
+    assert!(lorem.access::<&String>(&database).starts_with("Lorem"));
+
+    // Assets failed in background get reported like synchronously processed
+    // ones, without stopping other assets from loading.
+    let mut database = AssetDatabase::default()
+        .with_protocol(DeferredAssetProtocol::new(SlowTextAssetProtocol))
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_asset_progression_failures();
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let trash = database.schedule("slow://trash.bin")?;
+    {
+        let events = events.clone();
+        trash
+            .ensure::<AssetEventBindings>(&mut database)?
+            .bind(move |event| {
+                events.lock().unwrap().push(event.kind);
+                Ok(())
+            });
+    }
+    let cat = database.ensure("slow://cat.txt")?;
+    while database.is_busy() {
+        database.maintain()?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(cat.is_ready_to_use(&database));
+    assert!(trash.failed(&database));
+    assert!(
+        events
+            .lock()
+            .unwrap()
+            .contains(&AssetEventKind::BytesProcessingFailed)
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+// Text protocol simulating heavy decoding.
+struct SlowTextAssetProtocol;
+
+impl AssetProtocol for SlowTextAssetProtocol {
+    fn name(&self) -> &str {
+        "slow"
+    }
+
+    fn bytes_decoder(&self) -> Option<AssetBytesDecoder> {
+        Some(Arc::new(|bytes| {
+            std::thread::sleep(Duration::from_millis(100));
+            let mut bundle = DynamicBundle::default();
+            bundle
+                .add_component(String::from_utf8(bytes)?)
+                .map_err(|_| "Failed to add text to bundle")?;
+            Ok(bundle)
+        }))
+    }
+}
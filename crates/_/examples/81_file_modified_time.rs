@@ -0,0 +1,61 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{
+        file::{FileAssetFetch, FileModifiedTime},
+        hotreload::HotReloadFileAssetFetch,
+    },
+    protocol::text::TextAssetProtocol,
+};
+use std::{
+    error::Error,
+    fs::File,
+    time::{Duration, Instant},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    std::fs::write("./resources/mtime.txt", "Before")?;
+
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(HotReloadFileAssetFetch::new_native(
+            FileAssetFetch::default().with_root("resources"),
+        )?);
+
+    // File assets carry modification time of their files.
+    let asset = database.ensure("text://mtime.txt")?;
+    let modified = *asset.access::<&FileModifiedTime>(&database);
+    println!("Modified at: {:?}", modified.0);
+
+    // Touching file without changing its modification time does not reload it.
+    File::options()
+        .write(true)
+        .open("./resources/mtime.txt")?
+        .set_modified(modified.0)?;
+    let timer = Instant::now();
+    while timer.elapsed() < Duration::from_millis(500) {
+        database.maintain()?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(*asset.access::<&FileModifiedTime>(&database), modified);
+    assert_eq!(asset.access::<&String>(&database), "Before");
+
+    // Actual changes of file do reload it.
+    std::thread::sleep(Duration::from_millis(10));
+    std::fs::write("./resources/mtime.txt", "After")?;
+    let timer = Instant::now();
+    while timer.elapsed() < Duration::from_secs(5)
+        && asset
+            .access_checked::<&String>(&database)
+            .map(|text| text.as_str())
+            != Some("After")
+    {
+        database.maintain()?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    println!("Reloaded: {}", asset.access::<&String>(&database));
+    assert_ne!(*asset.access::<&FileModifiedTime>(&database), modified);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use keket::{
+    database::AssetDatabase, fetch::database::DatabaseAssetFetch, protocol::text::TextAssetProtocol,
+};
+use std::{
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Source database builds assets in memory.
+    let mut source = AssetDatabase::default().with_protocol(TextAssetProtocol);
+    source.spawn("text://greeting.txt", ("Hello World!".to_owned(),))?;
+    let source = Arc::new(RwLock::new(source));
+
+    // Cooked database fetches bytes produced by source database assets.
+    let mut cooked = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(DatabaseAssetFetch::new(source.clone()));
+
+    let greeting = cooked.ensure("text://greeting.txt")?;
+    println!("Cooked: {}", greeting.access::<&String>(&cooked));
+    assert_eq!(greeting.access::<&String>(&cooked), "Hello World!");
+
+    // Assets missing in source database are reported as not found.
+    assert!(cooked.ensure("text://missing.txt").is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
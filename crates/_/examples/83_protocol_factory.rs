@@ -0,0 +1,53 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{
+        AssetProtocol,
+        bundle::{BundleAssetProtocol, BundleWithDependencies},
+    },
+};
+use std::{
+    error::Error,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let constructed = Arc::new(AtomicUsize::new(0));
+    let mut database = AssetDatabase::default()
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Plugins contribute protocols on demand, when first requested.
+        .with_protocol_factory({
+            let constructed = constructed.clone();
+            move |name| {
+                if name != "plugin" {
+                    return None;
+                }
+                constructed.fetch_add(1, Ordering::SeqCst);
+                Some(
+                    Box::new(BundleAssetProtocol::new("plugin", |bytes: Vec<u8>| {
+                        Ok(BundleWithDependencies::new((
+                            String::from_utf8(bytes)?.len(),
+                        )))
+                    })) as Box<dyn AssetProtocol>,
+                )
+            }
+        });
+    assert_eq!(constructed.load(Ordering::SeqCst), 0);
+
+    let lorem = database.ensure("plugin://lorem.txt")?;
+    println!("Lorem length: {}", lorem.access::<&usize>(&database));
+    database.ensure("plugin://cat.txt")?;
+
+    // Constructed protocol gets reused.
+    assert_eq!(constructed.load(Ordering::SeqCst), 1);
+
+    // Protocols that factories cannot construct are still missing.
+    assert!(database.ensure("unknown://lorem.txt").is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anput::bundle::DynamicBundle;
+use keket::{
+    database::{AssetCancellationToken, AssetDatabase, path::AssetPath},
+    fetch::{AssetFetch, deferred::DeferredAssetFetch, file::FileAssetFetch},
+    protocol::text::TextAssetProtocol,
+};
+use std::{error::Error, time::Duration};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let token = AssetCancellationToken::default();
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        // Fetch engines can observe the same token to stop early.
+        .with_fetch(DeferredAssetFetch::new(SlowAssetFetch {
+            fetch: FileAssetFetch::default().with_root("resources"),
+            token: token.clone(),
+        }))
+        .with_cancellation(token.clone());
+
+    let lorem = database.ensure("text://lorem.txt")?;
+    let cat = database.ensure("text://cat.txt")?;
+    database.maintain()?;
+    assert!(database.is_busy());
+
+    // Shutdown requested while assets are still loading.
+    token.cancel();
+    database.maintain()?;
+    println!("Busy after cancellation: {}", database.is_busy());
+    assert!(!database.is_busy());
+    assert!(lorem.access_checked::<&String>(&database).is_none());
+    assert!(cat.access_checked::<&String>(&database).is_none());
+
+    // No new work gets scheduled afterward.
+    assert!(database.ensure("text://lorem.txt").is_err());
+    std::thread::sleep(Duration::from_millis(300));
+    database.maintain()?;
+    assert!(!database.is_busy());
+    assert!(lorem.access_checked::<&String>(&database).is_none());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+// Fetch simulating slow source, giving up when cancelled.
+struct SlowAssetFetch {
+    fetch: FileAssetFetch,
+    token: AssetCancellationToken,
+}
+
+impl AssetFetch for SlowAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        for _ in 0..20 {
+            if self.token.is_cancelled() {
+                return Err(format!("Fetching `{path}` got cancelled").into());
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        self.fetch.load_bytes(path)
+    }
+}
@@ -0,0 +1,25 @@
+use keket::{
+    database::AssetDatabase,
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default().with_protocol(TextAssetProtocol);
+    database.add_protocol(BytesAssetProtocol);
+    println!("Protocols: {:?}", database.protocol_names());
+    assert_eq!(database.protocol_names(), vec!["text", "bytes"]);
+    assert!(database.has_protocol("bytes"));
+
+    // Check protocol before ensuring asset for friendlier error.
+    database.remove_protocol("bytes");
+    assert_eq!(database.protocol_names(), vec!["text"]);
+    if !database.has_protocol("bytes") {
+        println!("Bytes protocol is not available, skipping binary assets.");
+    }
+    assert!(!database.has_protocol("bytes"));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{file::FileAssetFetch, logging::LoggingAssetFetch},
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Logging fetch records every fetch call, for reproducing loads.
+    let fetch = LoggingAssetFetch::new(FileAssetFetch::default().with_root("resources"));
+    let log = fetch.log();
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(fetch)
+        .with_asset_progression_failures();
+
+    database.ensure("text://lorem.txt")?;
+    database.ensure("bytes://ferris.png")?;
+    database.ensure("text://missing.txt")?;
+
+    let log = log.lock().unwrap();
+    for entry in log.iter() {
+        println!(
+            "{} - bytes: {:?}, elapsed: {:?}, result: {:?}",
+            entry.path, entry.bytes_len, entry.elapsed, entry.result
+        );
+    }
+    assert_eq!(log.len(), 3);
+    assert_eq!(log[0].path.content(), "text://lorem.txt");
+    assert_eq!(
+        log[0].bytes_len,
+        Some(std::fs::read("./resources/lorem.txt")?.len())
+    );
+    assert_eq!(log[1].path.content(), "bytes://ferris.png");
+    assert_eq!(
+        log[1].bytes_len,
+        Some(std::fs::read("./resources/ferris.png")?.len())
+    );
+    assert_eq!(log[2].path.content(), "text://missing.txt");
+    assert!(log[2].result.is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
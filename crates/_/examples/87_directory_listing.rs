@@ -0,0 +1,40 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::directory::{DirectoryListing, DirectoryListingProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let root = std::env::temp_dir().join("keket_directory_listing");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("levels/extra"))?;
+    std::fs::write(root.join("levels/b.txt"), "B")?;
+    std::fs::write(root.join("levels/a.txt"), "A")?;
+    std::fs::write(root.join("levels/extra/c.txt"), "C")?;
+
+    let mut database = AssetDatabase::default()
+        .with_protocol(DirectoryListingProtocol::default().entries_protocol("text"))
+        .with_fetch(FileAssetFetch::default().with_root(&root));
+
+    // Directory listing allows discovering available assets at runtime.
+    let levels = database.ensure("dir://levels")?;
+    let listing = levels.access::<&DirectoryListing>(&database);
+    for path in &listing.0 {
+        println!("Found: {path}");
+    }
+    let listed = listing
+        .0
+        .iter()
+        .map(|path| path.content())
+        .collect::<Vec<_>>();
+    assert_eq!(listed, vec!["text://levels/a.txt", "text://levels/b.txt"]);
+
+    // Recursive listing includes assets of subdirectories.
+    let all = database.ensure("dir://levels?recursive")?;
+    assert_eq!(all.access::<&DirectoryListing>(&database).0.len(), 3);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::file::FileAssetFetch,
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Fragment addresses part of multi-resource asset.
+    let path = AssetPath::new("model://scene.gltf#mesh0");
+    assert_eq!(path.protocol(), "model");
+    assert_eq!(path.path(), "scene.gltf");
+    assert_eq!(path.fragment(), "mesh0");
+    assert_eq!(path.try_meta(), None);
+
+    // Fragment goes after meta.
+    let path = AssetPath::new("atlas://sprites.png?filter=nearest#hero").into_static();
+    println!("Path: {path}");
+    assert_eq!(path.path(), "sprites.png");
+    assert_eq!(path.meta(), "filter=nearest");
+    assert_eq!(path.try_fragment(), Some("hero"));
+    assert_eq!(path.to_string(), "atlas://sprites.png?filter=nearest#hero");
+    assert_eq!(
+        path.without_fragment().content(),
+        "atlas://sprites.png?filter=nearest"
+    );
+
+    let path = AssetPath::new("text://lorem.txt");
+    assert_eq!(path.try_fragment(), None);
+
+    // Fetch engines read whole asset file, protocols can pick part of it.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+    let intro = database.ensure("text://lorem.txt#intro")?;
+    assert!(intro.access::<&String>(&database).starts_with("Lorem"));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{deferred::DeferredAssetFetch, file::FileAssetFetch},
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(DeferredAssetFetch::new(
+            FileAssetFetch::default().with_root("resources"),
+        ));
+    assert_eq!(database.in_flight_fetches(), 0);
+
+    // Requested assets are in flight until deferred fetch completes.
+    database.ensure("text://lorem.txt")?;
+    database.ensure("text://cat.txt")?;
+    println!("In-flight fetches: {}", database.in_flight_fetches());
+    assert_eq!(database.in_flight_fetches(), 2);
+
+    // Completed fetches are no longer counted.
+    database.maintain_until_idle()?;
+    println!("In-flight fetches: {}", database.in_flight_fetches());
+    assert_eq!(database.in_flight_fetches(), 0);
+    assert_eq!(database.in_flight_stores(), 0);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
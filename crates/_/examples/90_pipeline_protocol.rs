@@ -0,0 +1,36 @@
+use flate2::read::GzDecoder;
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::{bundle::BundleWithDependencies, pipeline::PipelineProtocol},
+};
+use serde_json::Value;
+use std::{error::Error, io::Read};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        // Pipeline decompresses bytes first, then parses them as JSON.
+        .with_protocol(
+            PipelineProtocol::new("jsongz", |bytes: Vec<u8>| {
+                Ok(BundleWithDependencies::new((serde_json::from_slice::<
+                    Value,
+                >(&bytes)?,)))
+            })
+            .with_stage(|bytes| {
+                let mut result = vec![];
+                GzDecoder::new(bytes.as_slice()).read_to_end(&mut result)?;
+                Ok(result)
+            }),
+        )
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let person = database.ensure("jsongz://person.json.gz")?;
+    println!("Person: {:#}", person.access::<&Value>(&database));
+
+    let expected = serde_json::from_slice::<Value>(&std::fs::read("./resources/person.json")?)?;
+    assert_eq!(person.access::<&Value>(&database), &expected);
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use keket::{
+    database::{AssetDatabase, AssetReferenceCounter, reference::PinnedAssetRef},
+    fetch::file::FileAssetFetch,
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Pinned reference can be created from path alone, then pinned lazily.
+    let lorem = PinnedAssetRef::new("text://lorem.txt");
+    assert!(!lorem.is_pinned());
+    println!(
+        "Lorem Ipsum: {}",
+        lorem.pin(&mut database)?.access::<&String>()
+    );
+    assert!(lorem.is_pinned());
+
+    // Another asset, no longer referenced by anyone.
+    let cat = database.ensure("text://cat.txt")?;
+    cat.give(&mut database, (AssetReferenceCounter::default(),))?;
+
+    // Pinned asset survives garbage collection, unlike unreferenced one.
+    database.collect_garbage()?;
+    assert!(lorem.resolve(&database)?.does_exists());
+    assert!(!cat.does_exists(&database));
+    let handle = lorem.handle()?;
+
+    // Dropping pinned reference releases the asset.
+    drop(lorem);
+    database.maintain()?;
+    assert!(!handle.does_exists(&database));
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
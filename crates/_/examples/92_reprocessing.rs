@@ -0,0 +1,118 @@
+use anput::{entity::Entity, query::Include, world::World};
+use keket::{
+    database::{
+        AssetDatabase,
+        handle::{AssetDependency, AssetHandle},
+        path::AssetPathStatic,
+    },
+    fetch::{AssetAwaitsReprocessing, AssetAwaitsResolution, file::FileAssetFetch},
+    protocol::{AssetProtocol, text::TextAssetProtocol},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(SummaryAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Reprocessing needs source bytes to be retained.
+        .with_retain_source_bytes();
+
+    // First processing finds dependency not loaded yet...
+    let summary = database.ensure("summary://cat.txt")?;
+    println!("Summary: {}", summary.access::<&Summary>(&database).0);
+    assert!(summary.has::<AwaitsDependency>(&database));
+
+    // ...so once it gets loaded, summary gets processed again.
+    database.maintain_until_idle()?;
+    println!("Summary: {}", summary.access::<&Summary>(&database).0);
+    assert!(!summary.has::<AwaitsDependency>(&database));
+    assert!(
+        summary
+            .access::<&Summary>(&database)
+            .0
+            .contains("characters")
+    );
+
+    // Assets can be also reprocessed on demand.
+    summary.reprocess(&mut database)?;
+    assert!(database.is_busy());
+    database.maintain_until_idle()?;
+
+    // Without retained source bytes, requested reprocessing fails only the
+    // asset asking for it.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(SummaryAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+    let summary = database.ensure("summary://cat.txt")?;
+    database.maintain_until_idle()?;
+    assert!(summary.failed(&database));
+    assert!(summary.reprocess(&mut database).is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+const DEPENDENCY: &str = "text://lorem.txt";
+
+// Summary of asset content and its dependency.
+struct Summary(String);
+
+// Marker of summaries processed before their dependency got loaded.
+struct AwaitsDependency;
+
+struct SummaryAssetProtocol;
+
+impl AssetProtocol for SummaryAssetProtocol {
+    fn name(&self) -> &str {
+        "summary"
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let content = String::from_utf8(bytes)?;
+        let path = AssetPathStatic::new(DEPENDENCY);
+        let dependency = match storage.find_by::<true, _>(&path) {
+            Some(entity) => entity,
+            None => {
+                let entity = storage.spawn((path, AssetAwaitsResolution))?;
+                storage.relate::<true, _>(AssetDependency, handle.entity(), entity)?;
+                entity
+            }
+        };
+        let summary = match storage.component::<true, String>(dependency) {
+            Ok(text) => format!("{} - with {} characters", content.trim(), text.len()),
+            Err(_) => {
+                storage.insert(handle.entity(), (AwaitsDependency,))?;
+                format!("{} - pending", content.trim())
+            }
+        };
+        storage.insert(handle.entity(), (Summary(summary),))?;
+        Ok(())
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        // Instead of building summaries here, we ask database to process
+        // assets again once their dependencies are loaded.
+        let ready = storage
+            .query::<true, (Entity, Include<AwaitsDependency>)>()
+            .filter(|(entity, _)| {
+                storage
+                    .relations_outgoing::<true, AssetDependency>(*entity)
+                    .all(|(_, _, dependency)| storage.has_entity_component::<String>(dependency))
+            })
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+        for entity in ready {
+            storage.remove::<(AwaitsDependency,)>(entity)?;
+            storage.insert(entity, (AssetAwaitsReprocessing,))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::{file::FileAssetFetch, pool::AssetBufferPool},
+    protocol::text::TextAssetProtocol,
+};
+use std::{error::Error, time::Instant};
+
+const FILES: usize = 1000;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let root = std::env::temp_dir().join(format!("keket-buffer-pool-{}", std::process::id()));
+    std::fs::create_dir_all(&root)?;
+    for index in 0..FILES {
+        std::fs::write(root.join(format!("{index}.txt")), format!("{index}"))?;
+    }
+
+    /* ANCHOR: main */
+    let pool = AssetBufferPool::default();
+    let mut database = AssetDatabase::default()
+        // Text protocol releases decoded bytes back to the pool they came from.
+        .with_protocol(TextAssetProtocol)
+        // File fetch reads bytes into pooled buffers.
+        .with_fetch(
+            FileAssetFetch::default()
+                .with_root(&root)
+                .with_buffer_pool(pool.clone()),
+        );
+
+    let timer = Instant::now();
+    for index in 0..FILES {
+        let handle = database.ensure(format!("text://{index}.txt"))?;
+        assert_eq!(handle.access::<&String>(&database), &index.to_string());
+    }
+    println!(
+        "Loaded {} files in {:?} with {} buffer allocations and {} reuses",
+        FILES,
+        timer.elapsed(),
+        pool.allocated(),
+        pool.reused()
+    );
+    // Files have 1 to 3 bytes, so one buffer gets allocated per file size.
+    assert_eq!(pool.allocated(), 3);
+    assert_eq!(pool.reused(), FILES - 3);
+    /* ANCHOR_END: main */
+
+    let _ = std::fs::remove_dir_all(root);
+    Ok(())
+}
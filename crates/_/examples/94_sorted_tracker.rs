@@ -0,0 +1,33 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPathStatic, tracker::AssetsTracker},
+    fetch::file::FileAssetFetch,
+    protocol::text::TextAssetProtocol,
+};
+use std::{cmp::Ordering, error::Error};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    let lorem = database.ensure("text://lorem.txt")?;
+    let cat = database.ensure("text://cat.txt")?;
+    let party = database.ensure("text://party.txt")?;
+    assert_eq!(cat.cmp_by_path(lorem, &database), Ordering::Less);
+
+    // Tracked handles can be iterated in order of their paths.
+    let tracker = AssetsTracker::default().with_many([party, lorem, cat]);
+    let paths = tracker
+        .iter_sorted(&database)
+        .map(|handle| handle.access::<&AssetPathStatic>(&database).to_string())
+        .collect::<Vec<_>>();
+    println!("Sorted paths: {paths:?}");
+    assert_eq!(
+        paths,
+        vec!["text://cat.txt", "text://lorem.txt", "text://party.txt"]
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
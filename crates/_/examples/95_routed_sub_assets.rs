@@ -0,0 +1,91 @@
+use anput::bundle::DynamicBundle;
+use keket::{
+    database::{
+        AssetDatabase,
+        path::{AssetPath, AssetPathStatic},
+    },
+    fetch::{
+        AssetBytesAreReadyToProcess, AssetFetch, deferred::DeferredAssetFetch,
+        file::FileAssetFetch, rewrite::RewriteAssetFetch,
+    },
+    protocol::text::TextAssetProtocol,
+};
+use std::{error::Error, path::PathBuf};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Sub-assets survive fetch engines wrapping the one producing them.
+    let mut database = AssetDatabase::default()
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_source(
+            "text",
+            DeferredAssetFetch::new(
+                RewriteAssetFetch::new_empty(LinesAssetFetch {
+                    root: "resources".into(),
+                })
+                .with_prefix("text://sprites", "text://sprites.atlas"),
+            ),
+            TextAssetProtocol,
+        );
+
+    let lines = database.ensure("text://sprites")?;
+    database.maintain_until_idle()?;
+
+    assert!(lines.is_ready_to_use(&database));
+    let mut regions = lines
+        .dependencies(&database)
+        .map(|line| Ok(line.path(&database)?.path().to_owned()))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+    regions.sort();
+    assert_eq!(
+        regions,
+        vec!["sprites.atlas/0", "sprites.atlas/1", "sprites.atlas/2"]
+    );
+    for region in lines.dependencies(&database) {
+        assert!(region.is_ready_to_use(&database));
+        println!("{}", region.access::<&String>(&database));
+    }
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+// Fetch spawning every line of atlas header as `<path>/<index>` sub-asset.
+struct LinesAssetFetch {
+    root: PathBuf,
+}
+
+fn bytes_bundle(bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+    let mut bundle = DynamicBundle::default();
+    bundle
+        .add_component(AssetBytesAreReadyToProcess(bytes))
+        .map_err(|_| "Failed to add bytes to bundle")?;
+    Ok(bundle)
+}
+
+impl AssetFetch for LinesAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        bytes_bundle(std::fs::read(self.root.join(path.path()))?)
+    }
+
+    fn load_many(
+        &self,
+        path: AssetPath,
+    ) -> Result<Vec<(AssetPathStatic, DynamicBundle)>, Box<dyn Error>> {
+        let content = std::fs::read_to_string(self.root.join(path.path()))?;
+        let header = content.split("\n\n").next().unwrap_or_default();
+        let mut result = vec![(
+            path.clone().into_static(),
+            bytes_bundle(header.as_bytes().to_vec())?,
+        )];
+        for (index, line) in header.lines().enumerate() {
+            let sub_path = AssetPathStatic::from_parts(
+                path.protocol(),
+                &format!("{}/{}", path.path(), index),
+                "",
+            );
+            result.push((sub_path, bytes_bundle(line.as_bytes().to_vec())?));
+        }
+        Ok(result)
+    }
+}
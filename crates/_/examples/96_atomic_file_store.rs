@@ -0,0 +1,92 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::file::FileAssetFetch,
+    protocol::text::TextAssetProtocol,
+    store::{AssetStore, file::FileAssetStore},
+};
+use std::{error::Error, path::Path, sync::Arc};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let root = std::env::temp_dir().join(format!("keket_atomic_store_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root(&root))
+        .with_store(FileAssetStore::default().with_root(&root));
+
+    // Missing parent directories get created.
+    let nested = database.spawn("text://levels/forest/intro.txt", ("Hello!".to_owned(),))?;
+    nested.store(&mut database)?;
+    database.maintain_until_idle()?;
+    assert_eq!(
+        std::fs::read_to_string(root.join("levels/forest/intro.txt"))?,
+        "Hello!"
+    );
+    assert_eq!(temp_files(&root.join("levels/forest"))?, 0);
+
+    // Save interrupted by a crash leaves only its temporary file behind, while
+    // destination keeps its previous content.
+    std::fs::write(
+        root.join("levels/forest/.intro.txt.0.0.tmp"),
+        "Partially writ",
+    )?;
+    assert_eq!(
+        std::fs::read_to_string(root.join("levels/forest/intro.txt"))?,
+        "Hello!"
+    );
+
+    // Failed save cleans up its temporary file and keeps destination intact.
+    std::fs::create_dir_all(root.join("levels/forest/occupied.txt/inner"))?;
+    let store = FileAssetStore::default().with_root(&root);
+    assert!(
+        store
+            .save_bytes(
+                AssetPath::new("text://levels/forest/occupied.txt"),
+                b"Nope".to_vec()
+            )
+            .is_err()
+    );
+    assert!(root.join("levels/forest/occupied.txt/inner").is_dir());
+    assert_eq!(temp_files(&root.join("levels/forest"))?, 1);
+
+    // Concurrent saves of the same path do not share temporary files, so
+    // destination always ends up with content of one of them.
+    let store = Arc::new(store);
+    let threads = (0..8)
+        .map(|index| {
+            let store = store.clone();
+            std::thread::spawn(move || {
+                store
+                    .save_bytes(
+                        AssetPath::new("text://shared.txt"),
+                        format!("Writer {index}").repeat(1000).into_bytes(),
+                    )
+                    .map(|_| ())
+                    .map_err(|error| error.to_string())
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap()?;
+    }
+    let shared = std::fs::read_to_string(root.join("shared.txt"))?;
+    assert!((0..8).any(|index| shared == format!("Writer {index}").repeat(1000)));
+    assert_eq!(temp_files(&root)?, 0);
+
+    std::fs::remove_dir_all(&root)?;
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
+
+fn temp_files(directory: &Path) -> Result<usize, Box<dyn Error>> {
+    let mut result = 0;
+    for entry in std::fs::read_dir(directory)? {
+        if entry?.file_name().to_string_lossy().ends_with(".tmp") {
+            result += 1;
+        }
+    }
+    Ok(result)
+}
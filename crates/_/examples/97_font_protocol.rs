@@ -0,0 +1,33 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::file::FileAssetFetch,
+    protocol::font::{FontAssetProtocol, FontFace},
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let mut database = AssetDatabase::default()
+        .with_protocol(FontAssetProtocol::default())
+        .with_fetch(FileAssetFetch::default().with_root("resources"));
+
+    // Font protocol reads font metadata, keeping raw bytes for rasterizers.
+    let font = database.ensure("font://dejavu_sans_mono.ttf")?;
+    let face = font.access::<&FontFace>(&database).to_owned();
+    println!("Font: {face:#?}");
+    assert_eq!(
+        face,
+        FontFace {
+            family: "DejaVu Sans Mono".to_owned(),
+            units_per_em: 2048,
+            glyph_count: 3377,
+        }
+    );
+    assert_eq!(
+        font.access::<&Vec<u8>>(&database).len(),
+        std::fs::metadata("resources/dejavu_sans_mono.ttf")?.len() as usize
+    );
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::file::FileAssetFetch,
+    protocol::text::TextAssetProtocol,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: percent */
+    // Percent encoding touches only path part, keeping meta and fragment.
+    let path = AssetPath::new("file://my assets/ünï.png?size=2#x");
+    let encoded = path.percent_encoded();
+    assert_eq!(
+        encoded.content(),
+        "file://my%20assets/%C3%BC%C3%AF.png?size=2#x"
+    );
+    assert_eq!(encoded.percent_decoded(), path);
+
+    // Already encoded sequences are not encoded twice.
+    assert_eq!(
+        AssetPath::new("file://a%20b").percent_encoded().content(),
+        "file://a%20b"
+    );
+
+    // Lone `%` at the end and `%` followed by invalid hex are kept when
+    // decoding, and get encoded themselves.
+    for (content, encoded) in [
+        ("file://100%", "file://100%25"),
+        ("file://50%2", "file://50%252"),
+        ("file://a%zzb", "file://a%25zzb"),
+    ] {
+        let path = AssetPath::new(content);
+        assert_eq!(path.percent_decoded(), path);
+        assert_eq!(path.percent_encoded().content(), encoded);
+        assert_eq!(path.percent_encoded().percent_decoded(), path);
+    }
+
+    // Encoded `?` and `#` separators stay encoded, so they do not change
+    // meaning of the path.
+    let path = AssetPath::new("file://what%3F%23.txt");
+    assert_eq!(path.percent_decoded(), path);
+    /* ANCHOR_END: percent */
+
+    /* ANCHOR: join */
+    let shader = AssetPath::new("shader://shaders/image.shader?v=1");
+    // Relative paths resolve against directory of the path, dropping its meta.
+    assert_eq!(
+        shader.join("common.glsl").content(),
+        "shader://shaders/common.glsl"
+    );
+    assert_eq!(
+        shader.join("./../lib/noise.glsl").content(),
+        "shader://lib/noise.glsl"
+    );
+    // `..` never goes above the root.
+    assert_eq!(
+        shader.join("../../../escape.glsl").content(),
+        "shader://escape.glsl"
+    );
+    // Absolute paths resolve against the root, keeping their meta and fragment.
+    assert_eq!(
+        shader.join("/root.glsl?x=1#main").content(),
+        "shader://root.glsl?x=1#main"
+    );
+    // Paths with protocol are taken as they are.
+    assert_eq!(
+        shader.join("text://other.txt").content(),
+        "text://other.txt"
+    );
+    /* ANCHOR_END: join */
+
+    /* ANCHOR: meta */
+    let image = AssetPath::new("image://a.png?size=64&scale=1.5&mipmaps&srgb=off&bad=x");
+    assert_eq!(image.meta_get::<u32>("size"), Some(Ok(64)));
+    assert_eq!(image.meta_get::<f32>("scale"), Some(Ok(1.5)));
+    assert_eq!(image.meta_get::<u32>("missing"), None);
+    assert!(matches!(image.meta_get::<u32>("bad"), Some(Err(_))));
+    assert_eq!(image.meta_get_or("size", 7u32), 64);
+    assert_eq!(image.meta_get_or("bad", 7u32), 7);
+    assert_eq!(image.meta_get_or("missing", 7u32), 7);
+    assert!(image.meta_flag("mipmaps"));
+    assert!(!image.meta_flag("srgb"));
+    assert!(!image.meta_flag("missing"));
+    /* ANCHOR_END: meta */
+
+    /* ANCHOR: default_meta */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        .with_default_meta("text", "tag=default&cached");
+
+    // Default meta gets merged into paths not specifying it...
+    let lorem = database.ensure("text://lorem.txt")?;
+    assert_eq!(
+        lorem.path(&database)?.content(),
+        "text://lorem.txt?tag=default&cached"
+    );
+    assert_eq!(database.find("text://lorem.txt"), Some(lorem));
+    // ...while explicit meta items take precedence.
+    let custom = database.ensure("text://lorem.txt?tag=custom")?;
+    assert_eq!(
+        custom.path(&database)?.content(),
+        "text://lorem.txt?tag=custom&cached"
+    );
+    assert_ne!(custom, lorem);
+    /* ANCHOR_END: default_meta */
+
+    /* ANCHOR: aliases */
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(FileAssetFetch::default().with_root("resources"))
+        // Aliases can point to other aliases.
+        .with_alias("text://intro", "text://greeting")
+        .with_alias("text://greeting", "text://lorem.txt")
+        // Cyclic aliases get reported instead of looping forever.
+        .with_alias("text://ping", "text://pong")
+        .with_alias("text://pong", "text://ping");
+
+    assert_eq!(
+        database.resolve_alias("text://intro")?.content(),
+        "text://lorem.txt"
+    );
+    let intro = database.ensure("text://intro")?;
+    assert_eq!(database.ensure("text://lorem.txt")?, intro);
+
+    let error = database.resolve_alias("text://ping").unwrap_err();
+    assert!(error.to_string().contains("Cyclic"));
+    assert!(database.ensure("text://pong").is_err());
+    /* ANCHOR_END: aliases */
+
+    Ok(())
+}
@@ -10,18 +10,23 @@ pub enum AssetEventKind {
     BytesProcessed,
     Unloaded,
     BytesFetchingFailed,
+    BytesNotFound,
     BytesProcessingFailed,
     AwaitsStoring,
     AwaitsAsyncStore,
     BytesReadyToStore,
     BytesStored,
     BytesStoringFailed,
+    Cancelled,
+    SourceChanged,
+    Accessed,
+    DepthExceeded,
 }
 
 impl AssetEventKind {
     /// Checks if the event represents a finished state (either success or failure).
     pub fn is_done(self) -> bool {
-        matches!(self, Self::BytesProcessed) || self.failure()
+        matches!(self, Self::BytesProcessed | Self::Cancelled) || self.failure()
     }
 
     /// Checks if the event is still in progress.
@@ -38,7 +43,7 @@ impl AssetEventKind {
     pub fn failure(self) -> bool {
         matches!(
             self,
-            Self::BytesFetchingFailed | Self::BytesProcessingFailed
+            Self::BytesFetchingFailed | Self::BytesNotFound | Self::BytesProcessingFailed
         )
     }
 }
@@ -84,6 +89,12 @@ where
     }
 }
 
+/// Filter deciding which events get dispatched to a listener.
+pub type AssetEventFilter = Box<dyn Fn(&AssetEvent) -> bool + Send + Sync>;
+
+/// Listener receiving batch of events dispatched since last flush.
+pub type AssetEventBatchListener = Box<dyn FnMut(&[AssetEvent]) + Send + Sync>;
+
 /// A unique identifier for an asset event listener binding.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AssetEventBinding(usize);
@@ -94,11 +105,47 @@ pub struct AssetEventBinding(usize);
 #[derive(Default)]
 pub struct AssetEventBindings {
     id_generator: usize,
-    // [(binding, listener, dispatch once)]
-    bindings: Vec<(AssetEventBinding, Box<dyn AssetEventListener>, bool)>,
+    // [(binding, listener, dispatch once, filter)]
+    #[allow(clippy::type_complexity)]
+    bindings: Vec<(
+        AssetEventBinding,
+        Box<dyn AssetEventListener>,
+        bool,
+        Option<AssetEventFilter>,
+    )>,
+    batched: Vec<(AssetEventBinding, AssetEventBatchListener)>,
+    pending: Vec<AssetEvent>,
+    isolated_listeners: bool,
 }
 
 impl AssetEventBindings {
+    /// Makes listeners isolated from each other's failures.
+    /// See `set_isolated_listeners`.
+    ///
+    /// # Returns
+    /// The updated `AssetEventBindings` with the option enabled.
+    pub fn with_isolated_listeners(mut self) -> Self {
+        self.isolated_listeners = true;
+        self
+    }
+
+    /// Sets whether listeners are isolated from each other's failures.
+    ///
+    /// By default first failing listener stops dispatching event to the rest.
+    /// Isolated listeners always get event dispatched, while errors of failing
+    /// ones get collected and reported together after dispatching.
+    ///
+    /// # Arguments
+    /// - `isolated`: True if listeners should be isolated.
+    pub fn set_isolated_listeners(&mut self, isolated: bool) {
+        self.isolated_listeners = isolated;
+    }
+
+    /// Tells if listeners are isolated from each other's failures.
+    pub fn isolated_listeners(&self) -> bool {
+        self.isolated_listeners
+    }
+
     /// Adds a new listener and returns its binding identifier.
     ///
     /// # Arguments
@@ -107,10 +154,7 @@ impl AssetEventBindings {
     /// # Returns
     /// A unique binding identifier for the listener.
     pub fn bind(&mut self, listener: impl AssetEventListener + 'static) -> AssetEventBinding {
-        let id = AssetEventBinding(self.id_generator);
-        self.id_generator = self.id_generator.overflowing_add(1).0;
-        self.bindings.push((id, Box::new(listener), false));
-        id
+        self.add(Box::new(listener), false, None)
     }
 
     /// Adds a new listener and returns its binding identifier.
@@ -122,9 +166,108 @@ impl AssetEventBindings {
     /// # Returns
     /// A unique binding identifier for the listener.
     pub fn bind_once(&mut self, listener: impl AssetEventListener + 'static) -> AssetEventBinding {
+        self.add(Box::new(listener), true, None)
+    }
+
+    /// Adds a new listener that only receives events accepted by filter and
+    /// returns its binding identifier.
+    ///
+    /// # Arguments
+    /// - `filter`: The filter deciding which events get dispatched to listener.
+    /// - `listener`: The listener to be added.
+    ///
+    /// # Returns
+    /// A unique binding identifier for the listener.
+    pub fn bind_filtered(
+        &mut self,
+        filter: impl Fn(&AssetEvent) -> bool + Send + Sync + 'static,
+        listener: impl AssetEventListener + 'static,
+    ) -> AssetEventBinding {
+        self.add(Box::new(listener), false, Some(Box::new(filter)))
+    }
+
+    /// Adds a new listener that only receives events accepted by filter and
+    /// returns its binding identifier.
+    /// The listener will be automatically removed after being dispatched once.
+    ///
+    /// # Arguments
+    /// - `filter`: The filter deciding which events get dispatched to listener.
+    /// - `listener`: The listener to be added.
+    ///
+    /// # Returns
+    /// A unique binding identifier for the listener.
+    pub fn bind_filtered_once(
+        &mut self,
+        filter: impl Fn(&AssetEvent) -> bool + Send + Sync + 'static,
+        listener: impl AssetEventListener + 'static,
+    ) -> AssetEventBinding {
+        self.add(Box::new(listener), true, Some(Box::new(filter)))
+    }
+
+    /// Adds a new listener that only receives events of given kind and
+    /// returns its binding identifier.
+    ///
+    /// # Arguments
+    /// - `kind`: The kind of events to dispatch to listener.
+    /// - `listener`: The listener to be added.
+    ///
+    /// # Returns
+    /// A unique binding identifier for the listener.
+    pub fn bind_kind(
+        &mut self,
+        kind: AssetEventKind,
+        listener: impl AssetEventListener + 'static,
+    ) -> AssetEventBinding {
+        self.bind_filtered(move |event| event.kind == kind, listener)
+    }
+
+    /// Adds a new listener that only receives events of any of given kinds
+    /// and returns its binding identifier.
+    ///
+    /// # Arguments
+    /// - `kinds`: The kinds of events to dispatch to listener.
+    /// - `listener`: The listener to be added.
+    ///
+    /// # Returns
+    /// A unique binding identifier for the listener.
+    pub fn bind_kinds(
+        &mut self,
+        kinds: &[AssetEventKind],
+        listener: impl AssetEventListener + 'static,
+    ) -> AssetEventBinding {
+        let kinds = kinds.to_vec();
+        self.bind_filtered(move |event| kinds.contains(&event.kind), listener)
+    }
+
+    /// Adds a new listener receiving all events dispatched since last flush
+    /// at once, and returns its binding identifier.
+    /// Database flushes both its global bindings and bindings of every asset
+    /// at the end of every `maintain`.
+    ///
+    /// # Arguments
+    /// - `listener`: The listener to be added.
+    ///
+    /// # Returns
+    /// A unique binding identifier for the listener.
+    pub fn bind_batched(
+        &mut self,
+        listener: impl FnMut(&[AssetEvent]) + Send + Sync + 'static,
+    ) -> AssetEventBinding {
         let id = AssetEventBinding(self.id_generator);
         self.id_generator = self.id_generator.overflowing_add(1).0;
-        self.bindings.push((id, Box::new(listener), true));
+        self.batched.push((id, Box::new(listener)));
+        id
+    }
+
+    fn add(
+        &mut self,
+        listener: Box<dyn AssetEventListener>,
+        dispatch_once: bool,
+        filter: Option<AssetEventFilter>,
+    ) -> AssetEventBinding {
+        let id = AssetEventBinding(self.id_generator);
+        self.id_generator = self.id_generator.overflowing_add(1).0;
+        self.bindings.push((id, listener, dispatch_once, filter));
         id
     }
 
@@ -134,48 +277,98 @@ impl AssetEventBindings {
     /// - `binding`: The identifier of the listener to remove.
     ///
     /// # Returns
-    /// The removed listener, if found.
+    /// The removed listener, if found. Batched listeners get removed too,
+    /// but are not returned.
     pub fn unbind(&mut self, binding: AssetEventBinding) -> Option<Box<dyn AssetEventListener>> {
+        self.batched
+            .retain(|(listener_binding, _)| *listener_binding != binding);
         self.bindings
             .iter()
-            .position(|(listener_binding, _, _)| *listener_binding == binding)
+            .position(|(listener_binding, _, _, _)| *listener_binding == binding)
             .map(|index| self.bindings.swap_remove(index).1)
     }
 
     /// Clears all event listener bindings.
     pub fn clear(&mut self) {
         self.bindings.clear();
+        self.batched.clear();
+        self.pending.clear();
     }
 
     /// Checks if there are no active bindings.
     pub fn is_empty(&self) -> bool {
-        self.bindings.is_empty()
+        self.bindings.is_empty() && self.batched.is_empty()
     }
 
     /// Returns the number of active bindings.
     pub fn len(&self) -> usize {
-        self.bindings.len()
+        self.bindings.len() + self.batched.len()
     }
 
     /// Returns an iterator over all binding identifiers.
     pub fn bindings(&self) -> impl Iterator<Item = AssetEventBinding> + '_ {
-        self.bindings.iter().map(|(binding, _, _)| *binding)
+        self.bindings
+            .iter()
+            .map(|(binding, _, _, _)| *binding)
+            .chain(self.batched.iter().map(|(binding, _)| *binding))
+    }
+
+    /// Dispatches all events accumulated since last flush to batched
+    /// listeners, at once.
+    pub fn flush(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let pending = std::mem::take(&mut self.pending);
+        for (_, listener) in &mut self.batched {
+            listener(&pending);
+        }
     }
 
-    /// Dispatches an asset event to all listeners.
-    /// Listeners that were bound with `bind_once` will be removed after dispatch.
+    /// Dispatches an asset event to all listeners accepting it.
+    /// Listeners that were bound with `bind_once` will be removed after they
+    /// get dispatched.
     ///
     /// # Arguments
     /// - `event`: The event to be dispatched.
     ///
     /// # Returns
-    /// A `Result` indicating success or an error.
+    /// A `Result` indicating success or an error. With isolated listeners,
+    /// error aggregates errors of all failed listeners.
     pub fn dispatch(&mut self, event: AssetEvent) -> Result<(), Box<dyn Error>> {
-        for (_, listener, _) in &mut self.bindings {
-            listener.on_dispatch(event.clone())?;
+        if !self.batched.is_empty() {
+            self.pending.push(event.clone());
+        }
+        let mut dispatched = Vec::default();
+        let mut errors = Vec::default();
+        for (binding, listener, dispatch_once, filter) in &mut self.bindings {
+            if filter.as_ref().map(|filter| filter(&event)).unwrap_or(true) {
+                let status = listener.on_dispatch(event.clone());
+                if *dispatch_once {
+                    dispatched.push(*binding);
+                }
+                if let Err(error) = status {
+                    if !self.isolated_listeners {
+                        return Err(error);
+                    }
+                    errors.push(format!("{binding:?}: {error}"));
+                }
+            }
+        }
+        if !dispatched.is_empty() {
+            self.bindings
+                .retain(|(binding, _, _, _)| !dispatched.contains(binding));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Failed to dispatch `{}` asset event to {} listener(s): {}",
+                event.path,
+                errors.len(),
+                errors.join(", ")
+            )
+            .into())
         }
-        self.bindings
-            .retain(|(_, _, dispatch_once)| !*dispatch_once);
-        Ok(())
     }
 }
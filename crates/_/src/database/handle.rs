@@ -1,11 +1,15 @@
 use crate::{
     database::{
-        AssetDatabase,
+        AssetDatabase, AssetFirstAccessed, AssetLoadError, event_path,
+        events::{AssetEvent, AssetEventBindings, AssetEventKind},
         inspector::AssetInspector,
         path::{AssetPath, AssetPathStatic},
     },
-    fetch::{AssetAwaitsAsyncFetch, AssetAwaitsResolution, AssetBytesAreReadyToProcess},
-    protocol::future::AssetAwaitsAsyncProcessing,
+    fetch::{
+        AssetAwaitsAsyncFetch, AssetAwaitsReprocessing, AssetAwaitsResolution,
+        AssetBytesAreReadyToProcess, AssetSourceBytes,
+    },
+    protocol::{future::AssetAwaitsAsyncProcessing, group::GroupMembers},
     store::AssetAwaitsStoring,
 };
 use anput::{
@@ -15,11 +19,11 @@ use anput::{
     component::{Component, ComponentRef, ComponentRefMut},
     database::WorldDestroyIteratorExt,
     entity::Entity,
-    query::{Exclude, Include, QueryError, TypedLookupFetch, TypedQueryFetch},
+    query::{Exclude, QueryError, TypedLookupFetch, TypedQueryFetch},
     third_party::intuicio_data::type_hash::TypeHash,
     world::World,
 };
-use std::{error::Error, future::pending};
+use std::{error::Error, future::pending, time::Instant};
 
 /// A marker struct to represent an asset dependency relationship.
 pub struct AssetDependency;
@@ -82,6 +86,49 @@ impl AssetHandle {
             .execute(&mut database.storage)
     }
 
+    /// Cancels the asset if it was scheduled but its bytes were not fetched yet.
+    ///
+    /// Only assets awaiting resolution or async fetch, that no other asset
+    /// depends on, can be cancelled. Cancelled asset gets despawned and
+    /// `AssetEventKind::Cancelled` event gets dispatched.
+    ///
+    /// # Arguments
+    /// - `database`: A mutable reference to the asset database.
+    ///
+    /// # Returns
+    /// `true` if asset was cancelled, `false` if it could not be cancelled.
+    pub fn cancel(self, database: &mut AssetDatabase) -> Result<bool, Box<dyn Error>> {
+        let awaits = database
+            .storage
+            .has_entity_component::<AssetAwaitsResolution>(self.entity)
+            || database
+                .storage
+                .has_entity_component::<AssetAwaitsAsyncFetch>(self.entity);
+        if !awaits || self.dependent(database).next().is_some() {
+            return Ok(false);
+        }
+        let path = database
+            .storage
+            .component::<true, AssetPathStatic>(self.entity)?
+            .clone();
+        let event = AssetEvent {
+            handle: self,
+            kind: AssetEventKind::Cancelled,
+            path: event_path(&database.storage, self.entity, &path),
+        };
+        database.events.dispatch(event.clone())?;
+        if let Ok(mut bindings) = database
+            .storage
+            .component_mut::<true, AssetEventBindings>(self.entity)
+        {
+            bindings.dispatch(event)?;
+        }
+        std::iter::once(self.entity)
+            .to_despawn_command()
+            .execute(&mut database.storage)?;
+        Ok(true)
+    }
+
     /// Refreshes the asset, marking it for resolution.
     ///
     /// # Arguments
@@ -118,6 +165,50 @@ impl AssetHandle {
         Ok(())
     }
 
+    /// Schedules already loaded asset to be processed again from its
+    /// retained source bytes, for example when its dependencies changed.
+    ///
+    /// # Arguments
+    /// - `database`: A mutable reference to the asset database.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure, failing when asset has no
+    /// retained `AssetSourceBytes`.
+    pub fn reprocess(self, database: &mut AssetDatabase) -> Result<(), Box<dyn Error>> {
+        if !database
+            .storage
+            .has_entity_component::<AssetSourceBytes>(self.entity)
+        {
+            return Err(format!(
+                "Asset: `{}` cannot be reprocessed without retained source bytes",
+                self.lookup::<&AssetPathStatic>(database)
+                    .map(|path| path.to_string())
+                    .unwrap_or_default()
+            )
+            .into());
+        }
+        database
+            .storage
+            .insert(self.entity, (AssetAwaitsReprocessing,))?;
+        Ok(())
+    }
+
+    /// Compares assets by their paths, for stable ordering of handles in
+    /// logs and tests. Assets without paths are ordered first.
+    ///
+    /// # Arguments
+    /// - `other`: Handle of asset to compare with.
+    /// - `database`: A reference to the asset database.
+    ///
+    /// # Returns
+    /// Ordering of asset paths.
+    pub fn cmp_by_path(self, other: Self, database: &AssetDatabase) -> std::cmp::Ordering {
+        let a = self.lookup::<&AssetPathStatic>(database);
+        let b = other.lookup::<&AssetPathStatic>(database);
+        a.map(|path| path.content())
+            .cmp(&b.map(|path| path.content()))
+    }
+
     /// Returns the entity associated with this handle.
     pub fn entity(self) -> Entity {
         self.entity
@@ -130,8 +221,32 @@ impl AssetHandle {
 
     /// Checks if the asset has given component.
     pub fn has<T: Component>(self, database: &AssetDatabase) -> bool {
-        self.access_checked::<(Entity, Include<T>)>(database)
-            .is_some()
+        database.storage.has_entity_component::<T>(self.entity)
+    }
+
+    /// Returns error message of failed fetching or processing of the asset.
+    ///
+    /// # Arguments
+    /// - `database`: A reference to the asset database.
+    ///
+    /// # Returns
+    /// Error message if asset failed to load, otherwise `None`.
+    pub fn error(self, database: &AssetDatabase) -> Option<String> {
+        database
+            .storage
+            .component::<true, AssetLoadError>(self.entity)
+            .ok()
+            .map(|error| error.0.clone())
+    }
+
+    /// Checks if fetching or processing of the asset failed.
+    ///
+    /// # Arguments
+    /// - `database`: A reference to the asset database.
+    pub fn failed(self, database: &AssetDatabase) -> bool {
+        database
+            .storage
+            .has_entity_component::<AssetLoadError>(self.entity)
     }
 
     /// Checks if the asset is ready for use (all dependencies are resolved).
@@ -146,6 +261,12 @@ impl AssetHandle {
             Exclude<AssetAwaitsAsyncFetch>,
             Exclude<AssetAwaitsAsyncProcessing>,
         )>();
+        if let Some(max_depth) = database.max_dependency_depth {
+            return database
+                .dependencies_within_depth(self.entity, max_depth)
+                .into_iter()
+                .all(|entity| lookup.access(entity).is_some());
+        }
         database
             .storage
             .traverse_outgoing::<true, AssetDependency>([self.entity])
@@ -212,8 +333,9 @@ impl AssetHandle {
         Ok(database.storage.component_mut::<true, T>(self.entity)?)
     }
 
-    /// Tries to access typed data for this asset.
-    pub fn access_checked<'a, Fetch: TypedLookupFetch<'a, true>>(
+    /// Looks up typed data for this asset without recording access, for
+    /// internal bookkeeping that should not count as asset use.
+    pub(crate) fn lookup<'a, Fetch: TypedLookupFetch<'a, true>>(
         self,
         database: &'a AssetDatabase,
     ) -> Option<Fetch::Value> {
@@ -223,6 +345,33 @@ impl AssetHandle {
             .access(self.entity)
     }
 
+    /// Tries to access typed data for this asset.
+    pub fn access_checked<'a, Fetch: TypedLookupFetch<'a, true>>(
+        self,
+        database: &'a AssetDatabase,
+    ) -> Option<Fetch::Value> {
+        let result = self.lookup::<'a, Fetch>(database);
+        if database.access_tracking
+            && result.is_some()
+            && !database
+                .storage
+                .has_entity_component::<AssetFirstAccessed>(self.entity)
+        {
+            // Storage cannot be mutated here, so first access gets recorded
+            // with command executed on next maintenance.
+            let accessed = AssetFirstAccessed(Instant::now());
+            let entity = self.entity;
+            database
+                .commands_sender()
+                .send(Box::new(move |storage: &mut World| {
+                    if !storage.has_entity_component::<AssetFirstAccessed>(entity) {
+                        let _ = storage.insert(entity, (accessed,));
+                    }
+                }));
+        }
+        result
+    }
+
     /// Accesses typed data for this asset or panics if it cannot.
     pub fn access<'a, Fetch: TypedLookupFetch<'a, true>>(
         self,
@@ -245,6 +394,22 @@ impl AssetHandle {
             .map(|(_, _, entity)| Self { entity })
     }
 
+    /// Returns an iterator over member assets of group asset, in order they
+    /// were listed in the group. Members missing in database are skipped.
+    ///
+    /// # Arguments
+    /// - `database`: A reference to the asset database.
+    pub fn group_members(self, database: &AssetDatabase) -> impl Iterator<Item = AssetHandle> + '_ {
+        let members = database
+            .storage
+            .component::<true, GroupMembers>(self.entity)
+            .map(|members| members.0.clone())
+            .unwrap_or_default();
+        members
+            .into_iter()
+            .filter_map(move |path| database.find(path))
+    }
+
     /// Returns an iterator over assets dependent on this one.
     pub fn dependent(self, database: &AssetDatabase) -> impl Iterator<Item = AssetHandle> + '_ {
         database
@@ -254,14 +419,26 @@ impl AssetHandle {
     }
 
     /// Recursively iterates through all dependencies.
+    ///
+    /// Respects dependency depth limit set in asset database.
     pub fn traverse_dependencies(
         self,
         database: &AssetDatabase,
     ) -> impl Iterator<Item = AssetHandle> + '_ {
-        database
-            .storage
-            .traverse_outgoing::<true, AssetDependency>([self.entity])
-            .map(|(_, entity)| Self { entity })
+        let bounded = database
+            .max_dependency_depth
+            .map(|max_depth| database.dependencies_within_depth(self.entity, max_depth));
+        let unbounded = bounded.is_none().then(|| {
+            database
+                .storage
+                .traverse_outgoing::<true, AssetDependency>([self.entity])
+                .map(|(_, entity)| entity)
+        });
+        bounded
+            .into_iter()
+            .flatten()
+            .chain(unbounded.into_iter().flatten())
+            .map(|entity| Self { entity })
     }
 }
 
@@ -8,14 +8,18 @@ pub mod tracker;
 
 use crate::{
     database::{
-        events::{AssetEvent, AssetEventBindings, AssetEventKind, AssetEventListener},
+        events::{
+            AssetEvent, AssetEventBinding, AssetEventBindings, AssetEventKind, AssetEventListener,
+        },
         handle::{AssetDependency, AssetHandle},
-        path::{AssetPath, AssetPathStatic},
-        tracker::AssetsStatus,
+        path::{AssetPath, AssetPathAlias, AssetPathCaseInsensitive, AssetPathStatic},
+        tags::{AssetTags, TagMatch},
+        tracker::{AssetsProgress, AssetsStatus, AssetsTracker},
     },
     fetch::{
-        AssetAwaitsAsyncFetch, AssetAwaitsResolution, AssetBytesAreReadyToProcess, AssetFetch,
-        AssetFetchEngine,
+        AssetAwaitsAsyncFetch, AssetAwaitsReprocessing, AssetAwaitsResolution,
+        AssetBytesAreReadyToProcess, AssetFetch, AssetFetchEngine, AssetPrefetched,
+        AssetSourceBytes, AssetSourceChanged, router::RouterAssetFetch, stream::AssetByteStream,
     },
     protocol::{
         AssetProtocol,
@@ -23,7 +27,7 @@ use crate::{
     },
     store::{
         AssetAwaitsAsyncStore, AssetAwaitsStoring, AssetBytesAreReadyToStore, AssetStore,
-        AssetStoreEngine,
+        AssetStoreEngine, AssetStoreTiming,
     },
 };
 use anput::{
@@ -32,18 +36,57 @@ use anput::{
     component::Component,
     database::WorldDestroyIteratorExt,
     entity::Entity,
-    query::Include,
+    query::{Exclude, Include},
     world::World,
 };
+use moirai::{
+    job::{JobLocation, JobResult},
+    jobs::Jobs,
+};
 use std::{
-    collections::VecDeque,
+    collections::{HashMap, HashSet, VecDeque},
     error::Error,
-    sync::{Arc, Mutex},
+    fmt::Write,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
 };
 
+const MAX_COMMANDS_ROUNDS: usize = 64;
+
+/// Factory constructing protocols on demand, by their name.
+pub type AssetProtocolFactory = Box<dyn Fn(&str) -> Option<Box<dyn AssetProtocol>> + Send + Sync>;
+
 /// Command type for asset database operations.
 pub type AssetDatabaseCommand = Box<dyn FnOnce(&mut World) + Send + Sync>;
 
+/// Token for cancelling all in-flight work of asset database, like on
+/// application shutdown.
+///
+/// Token is cheap to clone and clones share cancellation state, so fetch
+/// engines and protocols can observe the same token as database.
+#[derive(Debug, Default, Clone)]
+pub struct AssetCancellationToken(Arc<AtomicBool>);
+
+impl AssetCancellationToken {
+    /// Requests cancellation of all in-flight work.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Tells if cancellation was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Clears cancellation request, so work can be scheduled again.
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
 /// Sender for asset database commands.
 ///
 /// This is used to send commands to the asset database from external places.
@@ -64,16 +107,194 @@ impl AssetDatabaseCommandsSender {
     }
 }
 
+fn find_asset_entity(
+    storage: &World,
+    path: &AssetPathStatic,
+    case_insensitive_paths: bool,
+) -> Option<Entity> {
+    if case_insensitive_paths {
+        storage.find_by::<true, _>(&AssetPathCaseInsensitive::new(path))
+    } else {
+        storage.find_by::<true, _>(path)
+    }
+}
+
+fn is_same_asset_path(a: &AssetPath, b: &AssetPath, case_insensitive_paths: bool) -> bool {
+    if case_insensitive_paths {
+        a.protocol() == b.protocol()
+            && a.meta() == b.meta()
+            && a.fragment() == b.fragment()
+            && a.path().to_lowercase() == b.path().to_lowercase()
+    } else {
+        a == b
+    }
+}
+
+fn remove_all<T: Component>(storage: &mut World) -> Result<(), Box<dyn Error>> {
+    let entities = storage
+        .query::<true, (Entity, Include<T>)>()
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+    for entity in entities {
+        storage.remove::<(T,)>(entity)?;
+    }
+    Ok(())
+}
+
+fn is_asset_busy(storage: &World, entity: Entity) -> bool {
+    storage.has_entity_component::<AssetAwaitsResolution>(entity)
+        || (storage.has_entity_component::<AssetBytesAreReadyToProcess>(entity)
+            && !storage.has_entity_component::<AssetPrefetched>(entity))
+        || storage.has_entity_component::<AssetAwaitsAsyncFetch>(entity)
+        || storage.has_entity_component::<AssetAwaitsStoring>(entity)
+        || storage.has_entity_component::<AssetBytesAreReadyToStore>(entity)
+        || storage.has_entity_component::<AssetAwaitsAsyncStore>(entity)
+        || storage.has_entity_component::<AssetAwaitsAsyncProcessing>(entity)
+        || storage.has_entity_component::<AssetAwaitsAsyncProducing>(entity)
+        || storage.has_entity_component::<AssetAwaitsReprocessing>(entity)
+}
+
+// Turns retained source bytes of assets awaiting reprocessing back into bytes
+// ready to process, so their protocols process them again. Assets without
+// retained source bytes fail to reprocess, without failing maintenance.
+fn prepare_reprocessing(storage: &mut World) -> Result<(), Box<dyn Error>> {
+    let marked = storage
+        .query::<true, (Entity, &AssetPath, Include<AssetAwaitsReprocessing>)>()
+        .map(|(entity, path, _)| (entity, path.clone().into_static()))
+        .collect::<Vec<_>>();
+    for (entity, path) in marked {
+        let bytes = storage
+            .component::<true, AssetSourceBytes>(entity)
+            .map(|bytes| bytes.0.clone());
+        storage.remove::<(AssetAwaitsReprocessing,)>(entity)?;
+        if let Ok(bytes) = bytes {
+            storage.insert(entity, (AssetBytesAreReadyToProcess(bytes),))?;
+            continue;
+        }
+        storage.insert(
+            entity,
+            (AssetLoadError(format!(
+                "Asset: `{path}` cannot be reprocessed without retained source bytes"
+            )),),
+        )?;
+        let handle = AssetHandle::new(entity);
+        let path = event_path(storage, entity, &path);
+        if let Ok(mut bindings) = storage.component_mut::<true, AssetEventBindings>(entity) {
+            bindings.dispatch(AssetEvent {
+                handle,
+                kind: AssetEventKind::BytesProcessingFailed,
+                path,
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn process_asset_bytes(
+    protocol: &mut dyn AssetProtocol,
+    storage: &mut World,
+    handle: AssetHandle,
+    path: &AssetPathStatic,
+    retain_source_bytes: bool,
+) -> Result<(), Box<dyn Error>> {
+    if retain_source_bytes {
+        let bytes = storage
+            .component::<true, AssetBytesAreReadyToProcess>(handle.entity())
+            .map(|bytes| bytes.0.clone());
+        if let Ok(bytes) = bytes {
+            storage.insert(handle.entity(), (AssetSourceBytes(bytes),))?;
+        }
+    }
+    let status = protocol.process_asset_bytes(handle, storage);
+    match status.as_ref() {
+        Ok(_) => {
+            if storage.has_entity_component::<AssetLoadError>(handle.entity()) {
+                storage.remove::<(AssetLoadError,)>(handle.entity())?;
+            }
+        }
+        Err(error) => {
+            report_processing_failure(storage, handle, path, error.to_string())?;
+        }
+    }
+    status
+}
+
+/// Marks asset as failed to process and notifies its event bindings.
+pub(crate) fn report_processing_failure(
+    storage: &mut World,
+    handle: AssetHandle,
+    path: &AssetPathStatic,
+    error: String,
+) -> Result<(), Box<dyn Error>> {
+    storage.insert(handle.entity(), (AssetLoadError(error),))?;
+    if let Ok(mut bindings) = storage.component_mut::<true, AssetEventBindings>(handle.entity()) {
+        bindings.dispatch(AssetEvent {
+            handle,
+            kind: AssetEventKind::BytesProcessingFailed,
+            path: event_path(storage, handle.entity(), path),
+        })?;
+    }
+    Ok(())
+}
+
+fn sort_by_path(storage: &World, handles: &mut [AssetHandle]) {
+    handles.sort_by_cached_key(|handle| {
+        storage
+            .component::<true, AssetPathStatic>(handle.entity())
+            .map(|path| path.content().to_owned())
+            .unwrap_or_default()
+    });
+}
+
+fn sort_by_priority<T>(storage: &World, items: &mut [T], handle: impl Fn(&T) -> AssetHandle) {
+    if !storage.has_component::<AssetPriority>() {
+        return;
+    }
+    items.sort_by_cached_key(|item| {
+        std::cmp::Reverse(
+            storage
+                .component::<true, AssetPriority>(handle(item).entity())
+                .map(|priority| *priority)
+                .unwrap_or_default(),
+        )
+    });
+}
+
+pub(crate) fn event_path(
+    storage: &World,
+    entity: Entity,
+    path: &AssetPathStatic,
+) -> AssetPathStatic {
+    storage
+        .component::<true, AssetPathAlias>(entity)
+        .map(|alias| alias.0.clone())
+        .unwrap_or_else(|_| path.clone())
+}
+
 /// Asset database for managing assets and their states.
 #[derive(Default)]
 pub struct AssetDatabase {
     pub storage: World,
     pub events: AssetEventBindings,
     pub allow_asset_progression_failures: bool,
+    pub case_insensitive_paths: bool,
+    pub max_maintain_iterations: Option<usize>,
+    pub max_dependency_depth: Option<usize>,
+    pub deterministic_order: bool,
+    pub retain_source_bytes: bool,
+    pub access_tracking: bool,
     fetch_stack: Vec<AssetFetchEngine>,
     store_stack: Vec<AssetStoreEngine>,
     protocols: Vec<Box<dyn AssetProtocol>>,
+    default_meta: HashMap<String, String>,
+    extension_protocols: HashMap<String, String>,
+    protocol_factories: Vec<AssetProtocolFactory>,
+    cancellation: Option<AssetCancellationToken>,
+    aliases: HashMap<AssetPathStatic, AssetPathStatic>,
     commands: Arc<Mutex<VecDeque<AssetDatabaseCommand>>>,
+    #[allow(clippy::type_complexity)]
+    progress_listener: Option<Box<dyn FnMut(AssetsProgress) + Send + Sync>>,
+    last_progress: Option<AssetsProgress>,
 }
 
 impl AssetDatabase {
@@ -89,6 +310,25 @@ impl AssetDatabase {
         self
     }
 
+    /// Wires asset path scheme to fetch engine and protocol in one call.
+    ///
+    /// # Arguments
+    /// - `scheme`: Asset path scheme, for example `http` in `http://x`.
+    /// - `fetch`: Fetch engine that fetches assets with this scheme.
+    /// - `protocol`: Protocol that processes assets with this scheme.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the source added.
+    pub fn with_source(
+        mut self,
+        scheme: impl ToString,
+        fetch: impl AssetFetch + 'static,
+        protocol: impl AssetProtocol + 'static,
+    ) -> Self {
+        self.add_source(scheme, fetch, protocol);
+        self
+    }
+
     /// Adds a store to its store stack.
     ///
     /// # Arguments
@@ -113,6 +353,23 @@ impl AssetDatabase {
         self
     }
 
+    /// Registers a protocol factory, consulted whenever asset path uses
+    /// protocol that is not registered yet. Constructed protocols get
+    /// registered for reuse.
+    ///
+    /// # Arguments
+    /// - `factory`: Function constructing protocol of given name, if it can.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the protocol factory added.
+    pub fn with_protocol_factory(
+        mut self,
+        factory: impl Fn(&str) -> Option<Box<dyn AssetProtocol>> + Send + Sync + 'static,
+    ) -> Self {
+        self.add_protocol_factory(factory);
+        self
+    }
+
     /// Enables allowing asset progression failures.
     ///
     /// # Returns
@@ -122,6 +379,273 @@ impl AssetDatabase {
         self
     }
 
+    /// Enables case-insensitive asset paths.
+    /// Path part of asset paths is compared case-insensitively when finding
+    /// assets, while original path is kept for display and fetching.
+    /// Should be enabled before any asset gets into database.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the option enabled.
+    pub fn with_case_insensitive_paths(mut self) -> Self {
+        self.case_insensitive_paths = true;
+        self
+    }
+
+    /// Sets default meta for assets of given protocol.
+    /// Default meta items get merged into asset paths that do not specify
+    /// them explicitly.
+    ///
+    /// # Arguments
+    /// - `protocol`: The name of the protocol.
+    /// - `meta`: Default meta, for example `filter=linear&mipmaps`.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the default meta set.
+    pub fn with_default_meta(mut self, protocol: impl ToString, meta: impl ToString) -> Self {
+        self.set_default_meta(protocol, meta);
+        self
+    }
+
+    /// Sets default meta for assets of given protocol.
+    /// Default meta items get merged into asset paths that do not specify
+    /// them explicitly.
+    ///
+    /// # Arguments
+    /// - `protocol`: The name of the protocol.
+    /// - `meta`: Default meta, for example `filter=linear&mipmaps`.
+    pub fn set_default_meta(&mut self, protocol: impl ToString, meta: impl ToString) {
+        self.default_meta
+            .insert(protocol.to_string(), meta.to_string());
+    }
+
+    /// Removes default meta of given protocol.
+    ///
+    /// # Arguments
+    /// - `protocol`: The name of the protocol.
+    ///
+    /// # Returns
+    /// The removed default meta if present.
+    pub fn remove_default_meta(&mut self, protocol: &str) -> Option<String> {
+        self.default_meta.remove(protocol)
+    }
+
+    /// Routes assets with given file extension to protocol, whenever their
+    /// path has no protocol or protocol that is not registered.
+    ///
+    /// # Arguments
+    /// - `extension`: File extension, with or without leading dot.
+    /// - `protocol`: The name of the protocol.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the extension routed.
+    pub fn with_protocol_for_extension(
+        mut self,
+        extension: impl ToString,
+        protocol: impl ToString,
+    ) -> Self {
+        self.set_protocol_for_extension(extension, protocol);
+        self
+    }
+
+    /// Routes assets with given file extension to protocol, whenever their
+    /// path has no protocol or protocol that is not registered.
+    ///
+    /// # Arguments
+    /// - `extension`: File extension, with or without leading dot.
+    /// - `protocol`: The name of the protocol.
+    pub fn set_protocol_for_extension(
+        &mut self,
+        extension: impl ToString,
+        protocol: impl ToString,
+    ) {
+        let extension = extension.to_string();
+        let extension = extension.strip_prefix('.').unwrap_or(&extension);
+        self.extension_protocols
+            .insert(extension.to_lowercase(), protocol.to_string());
+    }
+
+    /// Removes routing of given file extension.
+    ///
+    /// # Arguments
+    /// - `extension`: File extension, with or without leading dot.
+    ///
+    /// # Returns
+    /// The removed protocol name if present.
+    pub fn remove_protocol_for_extension(&mut self, extension: &str) -> Option<String> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+        self.extension_protocols.remove(&extension.to_lowercase())
+    }
+
+    /// Adds alias path pointing to another asset path.
+    ///
+    /// # Arguments
+    /// - `from`: The alias path.
+    /// - `to`: The target path, can be an alias too.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the alias added.
+    pub fn with_alias(
+        mut self,
+        from: impl Into<AssetPathStatic>,
+        to: impl Into<AssetPathStatic>,
+    ) -> Self {
+        self.alias(from, to);
+        self
+    }
+
+    /// Adds alias path pointing to another asset path.
+    /// Aliases are resolved when finding, scheduling and ensuring assets,
+    /// and events of assets requested by alias are reported under alias path.
+    ///
+    /// # Arguments
+    /// - `from`: The alias path.
+    /// - `to`: The target path, can be an alias too.
+    pub fn alias(&mut self, from: impl Into<AssetPathStatic>, to: impl Into<AssetPathStatic>) {
+        self.aliases.insert(from.into(), to.into());
+    }
+
+    /// Removes alias path.
+    ///
+    /// # Arguments
+    /// - `from`: The alias path.
+    ///
+    /// # Returns
+    /// The target path of removed alias if present.
+    pub fn unalias(&mut self, from: impl Into<AssetPathStatic>) -> Option<AssetPathStatic> {
+        self.aliases.remove(&from.into())
+    }
+
+    /// Resolves alias path into its final target path.
+    ///
+    /// # Arguments
+    /// - `path`: The path to resolve.
+    ///
+    /// # Returns
+    /// Final target path, or given path if it is not an alias. Error if aliases form a cycle.
+    pub fn resolve_alias(
+        &self,
+        path: impl Into<AssetPathStatic>,
+    ) -> Result<AssetPathStatic, Box<dyn Error>> {
+        let mut path = path.into();
+        let mut visited = HashSet::new();
+        while let Some(target) = self.aliases.get(&path) {
+            if !visited.insert(path.clone()) {
+                return Err(format!("Cyclic asset path alias: `{path}`").into());
+            }
+            path = target.clone();
+        }
+        Ok(path)
+    }
+
+    /// Enables deterministic order of assets processed in `maintain`.
+    /// Assets resolved, processed and stored within single maintenance are
+    /// sorted by their paths, so load order does not vary between runs.
+    /// Disabled by default to avoid sorting cost.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the option enabled.
+    pub fn with_deterministic_order(mut self) -> Self {
+        self.deterministic_order = true;
+        self
+    }
+
+    /// Enables retaining fetched bytes of assets in `AssetSourceBytes`
+    /// component, before protocols process them.
+    /// Useful for debugging and re-exporting assets, but keeps copy of every
+    /// asset source in memory, next to its processed data.
+    /// Disabled by default.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the option enabled.
+    pub fn with_retain_source_bytes(mut self) -> Self {
+        self.retain_source_bytes = true;
+        self
+    }
+
+    /// Sets cancellation token. When token gets cancelled, assets cannot be
+    /// scheduled anymore and maintenance drops all pending work of assets,
+    /// which makes deferred engines cancel their jobs.
+    ///
+    /// # Arguments
+    /// - `token`: Cancellation token, shared with places requesting cancellation.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the cancellation token set.
+    pub fn with_cancellation(mut self, token: AssetCancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Enables tracking of first access to assets data with `AssetHandle`
+    /// access methods. Accessed assets get `AssetFirstAccessed` component
+    /// and dispatch `Accessed` event on next maintenance.
+    /// Useful for finding assets that are loaded but never used.
+    /// Disabled by default.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the option enabled.
+    pub fn with_access_tracking(mut self) -> Self {
+        self.access_tracking = true;
+        self
+    }
+
+    /// Sets limit of maintain iterations `maintain_until_idle` can perform
+    /// before reporting assets stuck in progress.
+    ///
+    /// # Arguments
+    /// - `iterations`: Maximum number of maintain iterations.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the option set.
+    pub fn with_max_maintain_iterations(mut self, iterations: usize) -> Self {
+        self.max_maintain_iterations = Some(iterations);
+        self
+    }
+
+    /// Sets limit of dependency graph depth that recursive dependency
+    /// traversals, like `AssetHandle::traverse_dependencies` and
+    /// `AssetHandle::is_ready_to_use`, go through. Deeper dependencies are
+    /// skipped, and traversed asset gets `AssetDependencyDepthExceeded`
+    /// marker with `DepthExceeded` event dispatched on next maintenance.
+    ///
+    /// # Arguments
+    /// - `depth`: Maximum depth of dependencies, where direct ones have depth 1.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the option set.
+    pub fn with_max_dependency_depth(mut self, depth: usize) -> Self {
+        self.max_dependency_depth = Some(depth);
+        self
+    }
+
+    /// Sets listener notified about loading progress at the end of every
+    /// `maintain` call, whenever progress has changed since last call.
+    ///
+    /// # Arguments
+    /// - `listener`: Function receiving current loading progress.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the listener set.
+    pub fn with_progress_listener(
+        mut self,
+        listener: impl FnMut(AssetsProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress_listener = Some(Box::new(listener));
+        self.last_progress = None;
+        self
+    }
+
+    /// Makes global event listeners isolated from each other's failures, so
+    /// failing listener does not stop event from being dispatched to the rest.
+    /// Errors of failed listeners are reported together after dispatching.
+    ///
+    /// # Returns
+    /// The updated `AssetDatabase` with the option enabled.
+    pub fn with_isolated_listeners(mut self) -> Self {
+        self.events.set_isolated_listeners(true);
+        self
+    }
+
     /// Binds event listener.
     ///
     /// # Returns
@@ -131,6 +655,76 @@ impl AssetDatabase {
         self
     }
 
+    /// Binds event listener that only receives events of asset with given path.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to listen to.
+    /// - `listener`: The listener to be added.
+    ///
+    /// # Returns
+    /// A unique binding identifier for the listener.
+    pub fn on_path(
+        &mut self,
+        path: impl Into<AssetPathStatic>,
+        listener: impl AssetEventListener + 'static,
+    ) -> AssetEventBinding {
+        let (path, prepared) = self.listened_paths(path.into());
+        let case_insensitive_paths = self.case_insensitive_paths;
+        self.events.bind_filtered(
+            move |event| {
+                is_same_asset_path(&event.path, &path, case_insensitive_paths)
+                    || is_same_asset_path(&event.path, &prepared, case_insensitive_paths)
+            },
+            listener,
+        )
+    }
+
+    /// Binds one-shot event listener that gets called when asset with given
+    /// path finishes loading, either successfully or with failure.
+    /// The listener will be automatically removed after being dispatched once.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to listen to.
+    /// - `listener`: The listener to be added.
+    ///
+    /// # Returns
+    /// A unique binding identifier for the listener.
+    pub fn on_path_once(
+        &mut self,
+        path: impl Into<AssetPathStatic>,
+        listener: impl AssetEventListener + 'static,
+    ) -> AssetEventBinding {
+        let (path, prepared) = self.listened_paths(path.into());
+        let case_insensitive_paths = self.case_insensitive_paths;
+        self.events.bind_filtered_once(
+            move |event| {
+                event.kind.is_done()
+                    && (is_same_asset_path(&event.path, &path, case_insensitive_paths)
+                        || is_same_asset_path(&event.path, &prepared, case_insensitive_paths))
+            },
+            listener,
+        )
+    }
+
+    // Events of aliased assets are reported under alias path, while events of
+    // other assets are reported under path prepared like `ensure` does.
+    fn listened_paths(&self, path: AssetPathStatic) -> (AssetPathStatic, AssetPathStatic) {
+        let prepared = self
+            .prepare_path(path.clone())
+            .and_then(|prepared| {
+                match self
+                    .protocols
+                    .iter()
+                    .find(|protocol| protocol.name() == prepared.protocol())
+                {
+                    Some(protocol) => protocol.rewrite_path(prepared),
+                    None => Ok(prepared),
+                }
+            })
+            .unwrap_or_else(|_| path.clone());
+        (path, prepared)
+    }
+
     /// Adds a fetch engine to the stack.
     ///
     /// # Arguments
@@ -139,6 +733,37 @@ impl AssetDatabase {
         self.fetch_stack.push(AssetFetchEngine::new(fetch));
     }
 
+    /// Wires asset path scheme to fetch engine and protocol in one call.
+    ///
+    /// Top fetch engine gets replaced with router sending assets with this
+    /// scheme to given fetch engine, and all other assets to the old one, so
+    /// fetch engines pushed later take over all sources. Assets are processed
+    /// by protocol named like their scheme, so `protocol` should use `scheme`
+    /// as its name.
+    ///
+    /// # Arguments
+    /// - `scheme`: Asset path scheme, for example `http` in `http://x`.
+    /// - `fetch`: Fetch engine that fetches assets with this scheme.
+    /// - `protocol`: Protocol that processes assets with this scheme.
+    pub fn add_source(
+        &mut self,
+        scheme: impl ToString,
+        fetch: impl AssetFetch + 'static,
+        protocol: impl AssetProtocol + 'static,
+    ) {
+        self.add_protocol(protocol);
+        self.push_fetch_source(scheme.to_string(), fetch);
+    }
+
+    fn push_fetch_source(&mut self, scheme: String, fetch: impl AssetFetch + 'static) {
+        let mut router =
+            RouterAssetFetch::default().route(move |path| path.protocol() == scheme, fetch, 1);
+        if let Some(previous) = self.pop_fetch() {
+            router.add(|_| true, previous, 0);
+        }
+        self.push_fetch(router);
+    }
+
     /// Removes and returns the top fetch engine from the stack.
     ///
     /// # Returns
@@ -180,6 +805,14 @@ impl AssetDatabase {
         Ok(result)
     }
 
+    /// Returns names of fetch engines on the stack, from bottom to top.
+    ///
+    /// # Returns
+    /// List of fetch engine names, last one being currently active.
+    pub fn fetch_stack_names(&self) -> Vec<&str> {
+        self.fetch_stack.iter().map(|fetch| fetch.name()).collect()
+    }
+
     /// Adds a store engine to the stack.
     ///
     /// # Arguments
@@ -211,6 +844,14 @@ impl AssetDatabase {
         result
     }
 
+    /// Returns names of store engines on the stack, from bottom to top.
+    ///
+    /// # Returns
+    /// List of store engine names, last one being currently active.
+    pub fn store_stack_names(&self) -> Vec<&str> {
+        self.store_stack.iter().map(|store| store.name()).collect()
+    }
+
     /// Temporarily uses a store engine to perform a closure and removes it afterward.
     ///
     /// # Arguments
@@ -238,6 +879,19 @@ impl AssetDatabase {
         self.protocols.push(Box::new(protocol));
     }
 
+    /// Registers a protocol factory, consulted whenever asset path uses
+    /// protocol that is not registered yet. Constructed protocols get
+    /// registered for reuse.
+    ///
+    /// # Arguments
+    /// - `factory`: Function constructing protocol of given name, if it can.
+    pub fn add_protocol_factory(
+        &mut self,
+        factory: impl Fn(&str) -> Option<Box<dyn AssetProtocol>> + Send + Sync + 'static,
+    ) {
+        self.protocol_factories.push(Box::new(factory));
+    }
+
     /// Removes a protocol by its name.
     ///
     /// # Arguments
@@ -252,42 +906,205 @@ impl AssetDatabase {
             .map(|index| self.protocols.remove(index))
     }
 
-    /// Finds an asset by its path and returns a handle.
+    /// Checks if protocol with given name is registered.
     ///
     /// # Arguments
-    /// - `path`: The path of the asset to find.
+    /// - `name`: The name of the protocol.
     ///
     /// # Returns
-    /// An `AssetHandle` if the asset is found, otherwise `None`.
-    pub fn find(&self, path: impl Into<AssetPathStatic>) -> Option<AssetHandle> {
-        let path = path.into();
-        self.storage.find_by::<true, _>(&path).map(AssetHandle::new)
+    /// `true` if protocol is registered, otherwise `false`.
+    pub fn has_protocol(&self, name: &str) -> bool {
+        self.protocols
+            .iter()
+            .any(|protocol| protocol.name() == name)
     }
 
-    /// Schedules an asset to be resolved later if not already existing.
+    /// Returns names of registered protocols, in order of registration.
+    pub fn protocol_names(&self) -> Vec<&str> {
+        self.protocols
+            .iter()
+            .map(|protocol| protocol.name())
+            .collect()
+    }
+
+    /// Finds name of the first registered protocol that handles assets with
+    /// given file extension.
     ///
     /// # Arguments
-    /// - `path`: The path of the asset to schedule.
+    /// - `extension`: File extension, with or without leading dot.
     ///
     /// # Returns
-    /// An `AssetHandle` for the scheduled asset.
-    pub fn schedule(
-        &mut self,
-        path: impl Into<AssetPathStatic>,
-    ) -> Result<AssetHandle, Box<dyn Error>> {
-        let path = path.into();
-        let Some(protocol) = self
-            .protocols
-            .iter_mut()
-            .find(|protocol| protocol.name() == path.protocol())
-        else {
-            return Err(format!("Missing protocol for asset: `{path}`").into());
-        };
-        let path = protocol.rewrite_path(path)?;
-        if let Some(entity) = self.storage.find_by::<true, _>(&path) {
-            return Ok(AssetHandle::new(entity));
-        }
-        let entity = self.storage.spawn((path.clone(), AssetAwaitsResolution))?;
+    /// Name of the protocol if found, otherwise `None`.
+    pub fn protocol_for_extension(&self, extension: &str) -> Option<&str> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+        self.protocols
+            .iter()
+            .find(|protocol| {
+                protocol
+                    .extensions()
+                    .iter()
+                    .any(|item| item.eq_ignore_ascii_case(extension))
+            })
+            .map(|protocol| protocol.name())
+    }
+
+    /// Finds name of the first registered protocol that recognizes given
+    /// asset content.
+    ///
+    /// # Arguments
+    /// - `bytes`: Beginning of asset content.
+    ///
+    /// # Returns
+    /// Name of the protocol if found, otherwise `None`.
+    pub fn protocol_for_bytes(&self, bytes: &[u8]) -> Option<&str> {
+        self.protocols
+            .iter()
+            .find(|protocol| protocol.sniff(bytes))
+            .map(|protocol| protocol.name())
+    }
+
+    fn prepare_path(&self, path: AssetPathStatic) -> Result<AssetPathStatic, Box<dyn Error>> {
+        let path = self.resolve_alias(path)?;
+        let path = self.route_extension(path);
+        let Some(defaults) = self.default_meta.get(path.protocol()) else {
+            return Ok(path);
+        };
+        let defaults = AssetPath::from_parts("", "", defaults);
+        let mut meta = path.meta().to_owned();
+        let mut changed = false;
+        for (key, value) in defaults.meta_items() {
+            if path.has_meta_key(key) {
+                continue;
+            }
+            if !meta.is_empty() {
+                meta.push('&');
+            }
+            meta.push_str(key);
+            if !value.is_empty() {
+                meta.push('=');
+                meta.push_str(value);
+            }
+            changed = true;
+        }
+        if changed {
+            Ok(AssetPath::from_parts_with_fragment(
+                path.protocol(),
+                path.path(),
+                &meta,
+                path.fragment(),
+            ))
+        } else {
+            Ok(path)
+        }
+    }
+
+    fn construct_protocol(&mut self, name: &str) {
+        if self.has_protocol(name) {
+            return;
+        }
+        if let Some(protocol) = self
+            .protocol_factories
+            .iter()
+            .find_map(|factory| factory(name))
+        {
+            self.protocols.push(protocol);
+        }
+    }
+
+    // Protocols get constructed for both requested and prepared path, so
+    // factories take precedence over extension routing.
+    fn prepare_path_and_protocol(
+        &mut self,
+        path: AssetPathStatic,
+    ) -> Result<AssetPathStatic, Box<dyn Error>> {
+        self.construct_protocol(path.protocol());
+        let path = self.prepare_path(path)?;
+        self.construct_protocol(path.protocol());
+        Ok(path)
+    }
+
+    fn route_extension(&self, path: AssetPathStatic) -> AssetPathStatic {
+        if self.has_protocol(path.protocol()) {
+            return path;
+        }
+        let Some(protocol) = path
+            .path_extension()
+            .and_then(|extension| self.extension_protocols.get(&extension.to_lowercase()))
+        else {
+            return path;
+        };
+        AssetPath::from_parts_with_fragment(protocol, path.path(), path.meta(), path.fragment())
+    }
+
+    /// Finds an asset by its path and returns a handle.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to find.
+    ///
+    /// # Returns
+    /// An `AssetHandle` if the asset is found, otherwise `None`.
+    pub fn find(&self, path: impl Into<AssetPathStatic>) -> Option<AssetHandle> {
+        let path = self.prepare_path(path.into()).ok()?;
+        find_asset_entity(&self.storage, &path, self.case_insensitive_paths).map(AssetHandle::new)
+    }
+
+    /// Schedules an asset to be resolved later if not already existing.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to schedule.
+    ///
+    /// # Returns
+    /// An `AssetHandle` for the scheduled asset.
+    pub fn schedule(
+        &mut self,
+        path: impl Into<AssetPathStatic>,
+    ) -> Result<AssetHandle, Box<dyn Error>> {
+        self.schedule_with_priority(path, None)
+    }
+
+    /// Schedules an asset to be resolved later if not already existing,
+    /// with optional load priority.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to schedule.
+    /// - `priority`: Optional load priority of the asset.
+    ///
+    /// # Returns
+    /// An `AssetHandle` for the scheduled asset.
+    pub fn schedule_with_priority(
+        &mut self,
+        path: impl Into<AssetPathStatic>,
+        priority: Option<AssetPriority>,
+    ) -> Result<AssetHandle, Box<dyn Error>> {
+        let path = path.into();
+        self.ensure_not_cancelled(&path)?;
+        let alias = self.aliases.contains_key(&path).then(|| path.clone());
+        let path = self.prepare_path_and_protocol(path)?;
+        let Some(protocol) = self
+            .protocols
+            .iter_mut()
+            .find(|protocol| protocol.name() == path.protocol())
+        else {
+            return Err(format!("Missing protocol for asset: `{path}`").into());
+        };
+        let path = protocol.rewrite_path(path)?;
+        if let Some(entity) = find_asset_entity(&self.storage, &path, self.case_insensitive_paths) {
+            if let Some(priority) = priority {
+                self.storage.insert(entity, (priority,))?;
+            }
+            return Ok(AssetHandle::new(entity));
+        }
+        let entity = self.storage.spawn((path.clone(), AssetAwaitsResolution))?;
+        if let Some(priority) = priority {
+            self.storage.insert(entity, (priority,))?;
+        }
+        if self.case_insensitive_paths {
+            self.storage
+                .insert(entity, (AssetPathCaseInsensitive::new(&path),))?;
+        }
+        if let Some(alias) = alias {
+            self.storage.insert(entity, (AssetPathAlias(alias),))?;
+        }
         let extracted_bundle = protocol.extract_bundle_from_path(&path)?;
         if !extracted_bundle.is_empty() {
             self.storage.insert(entity, extracted_bundle)?;
@@ -310,6 +1127,8 @@ impl AssetDatabase {
         bundle: impl Bundle,
     ) -> Result<AssetHandle, Box<dyn Error>> {
         let path = path.into();
+        let alias = self.aliases.contains_key(&path).then(|| path.clone());
+        let path = self.prepare_path_and_protocol(path)?;
         let Some(protocol) = self
             .protocols
             .iter_mut()
@@ -323,7 +1142,7 @@ impl AssetDatabase {
             let to_remove = self
                 .storage
                 .query::<true, (Entity, &AssetPath)>()
-                .filter(|(_, p)| *p == &path)
+                .filter(|(_, p)| is_same_asset_path(p, &path, self.case_insensitive_paths))
                 .map(|(entity, _)| entity);
             self.storage
                 .traverse_outgoing::<true, AssetDependency>(to_remove)
@@ -332,6 +1151,13 @@ impl AssetDatabase {
                 .execute(&mut self.storage)?;
         };
         let entity = self.storage.spawn(BundleChain((path.clone(),), bundle))?;
+        if self.case_insensitive_paths {
+            self.storage
+                .insert(entity, (AssetPathCaseInsensitive::new(&path),))?;
+        }
+        if let Some(alias) = alias {
+            self.storage.insert(entity, (AssetPathAlias(alias),))?;
+        }
         let extracted_bundle = protocol.extract_bundle_from_path(&path)?;
         if !extracted_bundle.is_empty() {
             self.storage.insert(entity, extracted_bundle)?;
@@ -349,8 +1175,28 @@ impl AssetDatabase {
     pub fn ensure(
         &mut self,
         path: impl Into<AssetPathStatic>,
+    ) -> Result<AssetHandle, Box<dyn Error>> {
+        self.ensure_with_priority(path, None)
+    }
+
+    /// Ensures an asset exists or is scheduled for resolution, with optional
+    /// load priority.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to ensure.
+    /// - `priority`: Optional load priority of the asset.
+    ///
+    /// # Returns
+    /// An `AssetHandle` for the asset.
+    pub fn ensure_with_priority(
+        &mut self,
+        path: impl Into<AssetPathStatic>,
+        priority: Option<AssetPriority>,
     ) -> Result<AssetHandle, Box<dyn Error>> {
         let path = path.into();
+        self.ensure_not_cancelled(&path)?;
+        let alias = self.aliases.contains_key(&path).then(|| path.clone());
+        let path = self.prepare_path_and_protocol(path)?;
         let Some(protocol) = self
             .protocols
             .iter_mut()
@@ -359,11 +1205,47 @@ impl AssetDatabase {
             return Err(format!("Missing protocol for asset: `{path}`").into());
         };
         let path = protocol.rewrite_path(path)?;
-        if let Some(entity) = self.storage.find_by::<true, _>(&path) {
-            return Ok(AssetHandle::new(entity));
+        if let Some(entity) = find_asset_entity(&self.storage, &path, self.case_insensitive_paths) {
+            let handle = AssetHandle::new(entity);
+            if let Some(priority) = priority {
+                self.storage.insert(entity, (priority,))?;
+            }
+            if self.storage.has_entity_component::<AssetPrefetched>(entity) {
+                // Prefetched asset gets upgraded, reusing already fetched bytes.
+                self.storage.remove::<(AssetPrefetched,)>(entity)?;
+                if self
+                    .storage
+                    .has_entity_component::<AssetBytesAreReadyToProcess>(entity)
+                    && !self
+                        .storage
+                        .has_entity_component::<AssetAwaitsAsyncFetch>(entity)
+                {
+                    let status = process_asset_bytes(
+                        &mut **protocol,
+                        &mut self.storage,
+                        handle,
+                        &path,
+                        self.retain_source_bytes,
+                    );
+                    if !self.allow_asset_progression_failures {
+                        status?;
+                    }
+                }
+            }
+            return Ok(handle);
         }
         if let Some(fetch) = self.fetch_stack.last_mut() {
             let entity = self.storage.spawn((path.clone(),))?;
+            if self.case_insensitive_paths {
+                self.storage
+                    .insert(entity, (AssetPathCaseInsensitive::new(&path),))?;
+            }
+            if let Some(alias) = alias {
+                self.storage.insert(entity, (AssetPathAlias(alias),))?;
+            }
+            if let Some(priority) = priority {
+                self.storage.insert(entity, (priority,))?;
+            }
             let extracted_bundle = protocol.extract_bundle_from_path(&path)?;
             if !extracted_bundle.is_empty() {
                 self.storage.insert(entity, extracted_bundle)?;
@@ -377,19 +1259,17 @@ impl AssetDatabase {
                 .storage
                 .component::<true, AssetBytesAreReadyToProcess>(entity)
                 .is_ok()
+                && !self
+                    .storage
+                    .has_entity_component::<AssetAwaitsAsyncFetch>(entity)
             {
-                let status = protocol.process_asset_bytes(handle, &mut self.storage);
-                if status.is_err()
-                    && let Ok(mut bindings) = self
-                        .storage
-                        .component_mut::<true, AssetEventBindings>(handle.entity())
-                {
-                    bindings.dispatch(AssetEvent {
-                        handle,
-                        kind: AssetEventKind::BytesProcessingFailed,
-                        path: path.clone(),
-                    })?;
-                }
+                let status = process_asset_bytes(
+                    &mut **protocol,
+                    &mut self.storage,
+                    handle,
+                    &path,
+                    self.retain_source_bytes,
+                );
                 if !self.allow_asset_progression_failures {
                     status?;
                 }
@@ -400,6 +1280,95 @@ impl AssetDatabase {
         }
     }
 
+    /// Ensures multiple assets and returns tracker of their handles.
+    ///
+    /// # Arguments
+    /// - `paths`: An iterable collection of asset paths to ensure.
+    ///
+    /// # Returns
+    /// A `Result` containing handles of ensured assets, in order of paths,
+    /// and an `AssetsTracker` already tracking them.
+    pub fn ensure_tracked(
+        &mut self,
+        paths: impl IntoIterator<Item = impl Into<AssetPathStatic>>,
+    ) -> Result<(Vec<AssetHandle>, AssetsTracker), Box<dyn Error>> {
+        let handles = paths
+            .into_iter()
+            .map(|path| self.ensure(path))
+            .collect::<Result<Vec<_>, _>>()?;
+        let tracker = AssetsTracker::default().with_many(handles.iter().copied());
+        Ok((handles, tracker))
+    }
+
+    /// Prefetches an asset, warming up fetch pipeline without processing it.
+    ///
+    /// Asset gets fetched as usual, but its bytes are not processed by protocol
+    /// until the asset gets ensured, which then reuses already fetched bytes.
+    /// Prefetched assets with bytes ready do not make database busy.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to prefetch.
+    ///
+    /// # Returns
+    /// An `AssetHandle` for the asset.
+    pub fn prefetch(
+        &mut self,
+        path: impl Into<AssetPathStatic>,
+    ) -> Result<AssetHandle, Box<dyn Error>> {
+        let path = path.into();
+        self.ensure_not_cancelled(&path)?;
+        let alias = self.aliases.contains_key(&path).then(|| path.clone());
+        let path = self.prepare_path_and_protocol(path)?;
+        let Some(protocol) = self
+            .protocols
+            .iter_mut()
+            .find(|protocol| protocol.name() == path.protocol())
+        else {
+            return Err(format!("Missing protocol for asset: `{path}`").into());
+        };
+        let path = protocol.rewrite_path(path)?;
+        if let Some(entity) = find_asset_entity(&self.storage, &path, self.case_insensitive_paths) {
+            return Ok(AssetHandle::new(entity));
+        }
+        if let Some(fetch) = self.fetch_stack.last_mut() {
+            let entity = self.storage.spawn((path.clone(), AssetPrefetched))?;
+            if self.case_insensitive_paths {
+                self.storage
+                    .insert(entity, (AssetPathCaseInsensitive::new(&path),))?;
+            }
+            if let Some(alias) = alias {
+                self.storage.insert(entity, (AssetPathAlias(alias),))?;
+            }
+            let extracted_bundle = protocol.extract_bundle_from_path(&path)?;
+            if !extracted_bundle.is_empty() {
+                self.storage.insert(entity, extracted_bundle)?;
+            }
+            let handle = AssetHandle::new(entity);
+            let status = fetch.load_bytes(handle, path, &mut self.storage);
+            if !self.allow_asset_progression_failures {
+                status?;
+            }
+            Ok(handle)
+        } else {
+            Err("There is no asset fetch on stack!".into())
+        }
+    }
+
+    /// Cancels an asset that was scheduled but its bytes were not fetched yet.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to cancel.
+    ///
+    /// # Returns
+    /// `true` if asset was cancelled, `false` if it was not found or could
+    /// not be cancelled anymore.
+    pub fn cancel(&mut self, path: impl Into<AssetPathStatic>) -> Result<bool, Box<dyn Error>> {
+        match self.find(path) {
+            Some(handle) => handle.cancel(self),
+            None => Ok(false),
+        }
+    }
+
     /// Unloads an asset by its path, removing it from the storage.
     ///
     /// # Arguments
@@ -409,7 +1378,7 @@ impl AssetDatabase {
         let to_remove = self
             .storage
             .query::<true, (Entity, &AssetPath)>()
-            .filter(|(_, p)| *p == &path)
+            .filter(|(_, p)| is_same_asset_path(p, &path, self.case_insensitive_paths))
             .map(|(entity, _)| entity);
         self.storage
             .traverse_outgoing::<true, AssetDependency>(to_remove)
@@ -418,137 +1387,768 @@ impl AssetDatabase {
             .execute(&mut self.storage)
     }
 
-    /// Schedules an asset to be stored.
+    /// Schedules an asset to be stored.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to store.
+    ///
+    /// # Returns
+    /// Result indicating success or failure.
+    pub fn store(&mut self, path: impl Into<AssetPathStatic>) -> Result<(), Box<dyn Error>> {
+        let path = self.prepare_path(path.into())?;
+        let entity = find_asset_entity(&self.storage, &path, self.case_insensitive_paths)
+            .ok_or_else(|| format!("Asset `{path}` not found"))?;
+        self.storage.insert(entity, (AssetAwaitsStoring,))?;
+        Ok(())
+    }
+
+    /// Produces bytes of an asset right away, using its protocol, without
+    /// scheduling it for storing.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to produce bytes of.
+    ///
+    /// # Returns
+    /// Result containing produced bytes of an asset.
+    pub fn produce_bytes(
+        &mut self,
+        path: impl Into<AssetPathStatic>,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let path = self.prepare_path_and_protocol(path.into())?;
+        let entity = find_asset_entity(&self.storage, &path, self.case_insensitive_paths)
+            .ok_or_else(|| format!("Asset `{path}` not found"))?;
+        let protocol = self
+            .protocols
+            .iter_mut()
+            .find(|protocol| protocol.name() == path.protocol())
+            .ok_or_else(|| format!("Missing protocol for asset: `{path}`"))?;
+        protocol.produce_bytes(AssetHandle::new(entity), &mut self.storage)
+    }
+
+    /// Tries to dereference an asset by its path. If asset has no references
+    /// left, it gets removed it from the storage.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to unload.
+    pub fn dereference_or_unload<'a>(
+        &mut self,
+        path: impl Into<AssetPath<'a>>,
+    ) -> Result<(), Box<dyn Error>> {
+        let path = path.into();
+        let to_remove = self
+            .storage
+            .query::<true, (Entity, &AssetPath)>()
+            .filter(|(_, p)| is_same_asset_path(p, &path, self.case_insensitive_paths))
+            .filter_map(|(entity, _)| {
+                if let Ok(mut counter) = self
+                    .storage
+                    .component_mut::<true, AssetReferenceCounter>(entity)
+                {
+                    counter.decrement();
+                    if counter.counter() == 0 {
+                        Some(entity)
+                    } else {
+                        None
+                    }
+                } else {
+                    Some(entity)
+                }
+            });
+        self.storage
+            .traverse_outgoing::<true, AssetDependency>(to_remove)
+            .map(|(_, entity)| entity)
+            .to_despawn_command()
+            .execute(&mut self.storage)
+    }
+
+    /// Unloads all assets that are no longer referenced.
+    ///
+    /// Assets with zero `AssetReferenceCounter` that no other asset depends
+    /// on get unloaded, together with their dependencies that become orphaned
+    /// this way (unless those are still referenced themselves). Meant to be
+    /// called on demand, not on every maintenance.
+    ///
+    /// # Returns
+    /// Number of unloaded assets.
+    pub fn collect_garbage(&mut self) -> Result<usize, Box<dyn Error>> {
+        let is_referenced = |entity: Entity| {
+            self.storage
+                .component::<true, AssetReferenceCounter>(entity)
+                .map(|counter| counter.counter() > 0)
+                .unwrap_or_default()
+        };
+        let mut queue = self
+            .storage
+            .query::<true, (Entity, &AssetReferenceCounter)>()
+            .filter(|(entity, counter)| {
+                counter.counter() == 0
+                    && self
+                        .storage
+                        .relations_incomming::<true, AssetDependency>(*entity)
+                        .next()
+                        .is_none()
+            })
+            .map(|(entity, _)| entity)
+            .collect::<VecDeque<_>>();
+        let mut garbage = queue.iter().copied().collect::<HashSet<_>>();
+        while let Some(entity) = queue.pop_front() {
+            for (_, _, dependency) in self
+                .storage
+                .relations_outgoing::<true, AssetDependency>(entity)
+            {
+                if !garbage.contains(&dependency)
+                    && !is_referenced(dependency)
+                    && self
+                        .storage
+                        .relations_incomming::<true, AssetDependency>(dependency)
+                        .all(|(dependent, _, _)| garbage.contains(&dependent))
+                {
+                    garbage.insert(dependency);
+                    queue.push_back(dependency);
+                }
+            }
+        }
+        let result = garbage.len();
+        garbage
+            .into_iter()
+            .to_despawn_command()
+            .execute(&mut self.storage)?;
+        Ok(result)
+    }
+
+    /// Reloads an asset by unloading and ensuring it is reloaded.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the asset to reload.
+    ///
+    /// # Returns
+    /// An `AssetHandle` for the reloaded asset.
+    pub fn reload(
+        &mut self,
+        path: impl Into<AssetPathStatic>,
+    ) -> Result<AssetHandle, Box<dyn Error>> {
+        let path = self.prepare_path(path.into())?;
+        self.unload(path.clone())?;
+        self.ensure(path)
+    }
+
+    /// Returns an iterator over all assets with a specific component.
+    ///
+    /// # Returns
+    /// An iterator that yields `AssetHandle` instances.
+    pub fn assets_with<T: Component>(&self) -> impl Iterator<Item = AssetHandle> + '_ {
+        self.storage
+            .query::<true, (Entity, Include<T>)>()
+            .map(|(entity, _)| AssetHandle::new(entity))
+    }
+
+    /// Returns an iterator over all assets of a specific protocol.
+    ///
+    /// # Arguments
+    /// - `protocol`: The name of the protocol.
+    ///
+    /// # Returns
+    /// An iterator that yields `AssetHandle` instances.
+    pub fn assets_with_protocol<'a>(
+        &'a self,
+        protocol: &'a str,
+    ) -> impl Iterator<Item = AssetHandle> + 'a {
+        self.storage
+            .query::<true, (Entity, &AssetPathStatic)>()
+            .filter(move |(_, path)| path.protocol() == protocol)
+            .map(|(entity, _)| AssetHandle::new(entity))
+    }
+
+    /// Returns an iterator over all assets with tags matching queried tags.
+    ///
+    /// # Arguments
+    /// - `tags`: The queried tags.
+    /// - `mode`: Semantics of matching, either all or any of queried tags.
+    ///
+    /// # Returns
+    /// An iterator that yields `AssetHandle` instances.
+    pub fn find_by_tags<'a>(
+        &'a self,
+        tags: &'a AssetTags,
+        mode: TagMatch,
+    ) -> impl Iterator<Item = AssetHandle> + 'a {
+        self.storage
+            .query::<true, (Entity, &AssetTags)>()
+            .filter(move |(_, asset_tags)| asset_tags.matches(tags, mode))
+            .map(|(entity, _)| AssetHandle::new(entity))
+    }
+
+    /// Checks if there are any assets with a specific component.
+    ///
+    /// # Returns
+    /// `true` if there is at least one asset with the component, otherwise `false
+    pub fn has<T: Component>(&self) -> bool {
+        self.storage.has_component::<T>()
+    }
+
+    /// Returns cancellation token of the database, if set.
+    pub fn cancellation(&self) -> Option<&AssetCancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    /// Tells if cancellation token of the database got cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .map(|token| token.is_cancelled())
+            .unwrap_or_default()
+    }
+
+    fn ensure_not_cancelled(&self, path: &AssetPath) -> Result<(), Box<dyn Error>> {
+        if self.is_cancelled() {
+            Err(format!("Cannot schedule asset: `{path}`, asset database is cancelled").into())
+        } else {
+            Ok(())
+        }
+    }
+
+    // Dropping markers of pending work makes deferred engines cancel jobs of
+    // assets that do not await them anymore.
+    fn drop_pending_work(&mut self) -> Result<(), Box<dyn Error>> {
+        remove_all::<AssetAwaitsResolution>(&mut self.storage)?;
+        remove_all::<AssetBytesAreReadyToProcess>(&mut self.storage)?;
+        remove_all::<AssetAwaitsAsyncFetch>(&mut self.storage)?;
+        remove_all::<AssetAwaitsAsyncProcessing>(&mut self.storage)?;
+        remove_all::<AssetAwaitsStoring>(&mut self.storage)?;
+        remove_all::<AssetBytesAreReadyToStore>(&mut self.storage)?;
+        remove_all::<AssetAwaitsAsyncStore>(&mut self.storage)?;
+        remove_all::<AssetAwaitsAsyncProducing>(&mut self.storage)?;
+        remove_all::<AssetAwaitsReprocessing>(&mut self.storage)?;
+        Ok(())
+    }
+
+    /// Counts assets which are currently fetched asynchronously.
+    ///
+    /// Useful for backpressure, like throttling incoming asset requests when
+    /// fetch engines are saturated.
+    ///
+    /// # Returns
+    /// Number of assets awaiting asynchronous fetch.
+    pub fn in_flight_fetches(&self) -> usize {
+        self.storage
+            .query::<true, (Entity, Include<AssetAwaitsAsyncFetch>)>()
+            .count()
+    }
+
+    /// Counts assets which are currently stored asynchronously.
+    ///
+    /// # Returns
+    /// Number of assets awaiting asynchronous store.
+    pub fn in_flight_stores(&self) -> usize {
+        self.storage
+            .query::<true, (Entity, Include<AssetAwaitsAsyncStore>)>()
+            .count()
+    }
+
+    /// Determines if the asset database is currently busy with tasks.
+    ///
+    /// # Returns
+    /// `true` if busy, otherwise `false`.
+    pub fn is_busy(&self) -> bool {
+        self.storage.has_component::<AssetAwaitsResolution>()
+            || self
+                .storage
+                .query::<true, (
+                    Entity,
+                    Include<AssetBytesAreReadyToProcess>,
+                    Exclude<AssetPrefetched>,
+                )>()
+                .next()
+                .is_some()
+            || self.storage.has_component::<AssetAwaitsAsyncFetch>()
+            || self.storage.has_component::<AssetAwaitsStoring>()
+            || self.storage.has_component::<AssetBytesAreReadyToStore>()
+            || self.storage.has_component::<AssetAwaitsAsyncStore>()
+            || self.storage.has_component::<AssetAwaitsAsyncProcessing>()
+            || self.storage.has_component::<AssetAwaitsAsyncProducing>()
+            || self.storage.has_component::<AssetAwaitsReprocessing>()
+    }
+
+    /// Performs maintenance until database is no longer busy.
     ///
-    /// # Arguments
-    /// - `path`: The path of the asset to store.
+    /// If `max_maintain_iterations` is set and database is still busy after
+    /// that many iterations, an error listing stuck assets is returned.
     ///
     /// # Returns
-    /// Result indicating success or failure.
-    pub fn store(&mut self, path: impl Into<AssetPathStatic>) -> Result<(), Box<dyn Error>> {
-        let path = path.into();
-        let entity = self
-            .storage
-            .find_by::<true, _>(&path)
-            .ok_or_else(|| format!("Asset `{path}` not found"))?;
-        self.storage.insert(entity, (AssetAwaitsStoring,))?;
+    /// `Ok(())` if successful, or an error if any step fails.
+    pub fn maintain_until_idle(&mut self) -> Result<(), Box<dyn Error>> {
+        let mut iterations = 0;
+        while self.is_busy() {
+            if let Some(limit) = self.max_maintain_iterations
+                && iterations >= limit
+            {
+                let stuck = self
+                    .storage
+                    .query::<true, (Entity, &AssetPath)>()
+                    .filter(|(entity, _)| is_asset_busy(&self.storage, *entity))
+                    .map(|(_, path)| format!("`{path}`"))
+                    .collect::<Vec<_>>();
+                return Err(format!(
+                    "Asset database is still busy after {} maintain iterations. Stuck assets: {}",
+                    limit,
+                    stuck.join(", ")
+                )
+                .into());
+            }
+            self.maintain()?;
+            iterations += 1;
+        }
         Ok(())
     }
 
-    /// Tries to dereference an asset by its path. If asset has no references
-    /// left, it gets removed it from the storage.
+    /// Performs maintenance like `maintain`, but first decodes bytes of assets
+    /// ready to process on worker threads of given jobs runner.
+    ///
+    /// Only assets of protocols providing `AssetProtocol::bytes_decoder` are
+    /// decoded in parallel. Decoded bundles are inserted into storage on the
+    /// calling thread once all jobs complete, and remaining assets are
+    /// processed sequentially by regular maintenance.
+    ///
+    /// Decoding jobs are spawned on threads other than calling one, so given
+    /// jobs runner should have worker threads. While waiting for jobs to
+    /// complete, calling thread runs local jobs of the runner and sleeps in
+    /// between, instead of spinning.
     ///
     /// # Arguments
-    /// - `path`: The path of the asset to unload.
-    pub fn dereference_or_unload<'a>(
-        &mut self,
-        path: impl Into<AssetPath<'a>>,
-    ) -> Result<(), Box<dyn Error>> {
-        let path = path.into();
-        let to_remove = self
+    /// - `jobs`: The jobs runner with worker threads to decode asset bytes with.
+    ///
+    /// # Returns
+    /// `Ok(())` if successful, or an error if any step fails.
+    pub fn maintain_parallel(&mut self, jobs: &Jobs) -> Result<(), Box<dyn Error>> {
+        let decoders = self
+            .protocols
+            .iter()
+            .filter_map(|protocol| Some((protocol.name().to_owned(), protocol.bytes_decoder()?)))
+            .collect::<HashMap<_, _>>();
+        let mut to_decode = self
             .storage
-            .query::<true, (Entity, &AssetPath)>()
-            .filter(|(_, p)| *p == &path)
-            .filter_map(|(entity, _)| {
-                if let Ok(mut counter) = self
+            .query::<true, (
+                Entity,
+                &AssetPath,
+                Include<AssetBytesAreReadyToProcess>,
+                Exclude<AssetPrefetched>,
+                Exclude<AssetAwaitsAsyncFetch>,
+                Exclude<AssetByteStream>,
+            )>()
+            .filter(|(_, path, _, _, _, _)| decoders.contains_key(path.protocol()))
+            .map(|(entity, _, _, _, _, _)| AssetHandle::new(entity))
+            .collect::<Vec<_>>();
+        if self.deterministic_order {
+            sort_by_path(&self.storage, &mut to_decode);
+        }
+        sort_by_priority(&self.storage, &mut to_decode, |handle| *handle);
+        let mut pending = Vec::with_capacity(to_decode.len());
+        for handle in to_decode {
+            let bytes = {
+                let mut bytes = self
                     .storage
-                    .component_mut::<true, AssetReferenceCounter>(entity)
-                {
-                    counter.decrement();
-                    if counter.counter() == 0 {
-                        Some(entity)
-                    } else {
-                        None
-                    }
-                } else {
-                    Some(entity)
+                    .component_mut::<true, AssetBytesAreReadyToProcess>(handle.entity())?;
+                // Empty assets get descriptive errors from regular processing.
+                if bytes.0.is_empty() {
+                    continue;
                 }
-            });
-        self.storage
-            .traverse_outgoing::<true, AssetDependency>(to_remove)
-            .map(|(_, entity)| entity)
-            .to_despawn_command()
-            .execute(&mut self.storage)
+                std::mem::take(&mut bytes.0)
+            };
+            if self.retain_source_bytes {
+                self.storage
+                    .insert(handle.entity(), (AssetSourceBytes(bytes.clone()),))?;
+            }
+            let path = self
+                .storage
+                .component::<true, AssetPathStatic>(handle.entity())?
+                .clone();
+            let decoder = decoders[path.protocol()].clone();
+            let path2 = path.clone();
+            let job = async move {
+                decoder(bytes).map_err(|error| {
+                    format!("Failed parallel processing of asset: `{path2}`. Error: {error}")
+                })
+            };
+            let job = jobs.spawn(JobLocation::other_than_current_thread(), job);
+            pending.push((handle, path, job));
+        }
+        let mut backoff = Duration::from_micros(10);
+        while pending.iter().any(|(_, _, job)| !job.is_done()) {
+            jobs.run_local();
+            std::thread::sleep(backoff);
+            backoff = (backoff * 2).min(Duration::from_millis(1));
+        }
+        let mut result = Ok(());
+        for (handle, path, job) in pending {
+            self.storage
+                .remove::<(AssetBytesAreReadyToProcess,)>(handle.entity())?;
+            let status: Result<(), Box<dyn Error>> = match job.take() {
+                JobResult::Completed(Ok(bundle)) => {
+                    self.storage.insert(handle.entity(), bundle)?;
+                    Ok(())
+                }
+                JobResult::Completed(Err(error)) => Err(error.into()),
+                JobResult::Cancelled | JobResult::Consumed | JobResult::InProgress => Err(format!(
+                    "Parallel processing of `{path}` asset failed with undefined error!"
+                )
+                .into()),
+            };
+            if let Err(error) = status.as_ref() {
+                self.storage
+                    .insert(handle.entity(), (AssetLoadError(error.to_string()),))?;
+            } else if self
+                .storage
+                .has_entity_component::<AssetLoadError>(handle.entity())
+            {
+                self.storage.remove::<(AssetLoadError,)>(handle.entity())?;
+            }
+            if status.is_err()
+                && let Ok(mut bindings) = self
+                    .storage
+                    .component_mut::<true, AssetEventBindings>(handle.entity())
+            {
+                bindings.dispatch(AssetEvent {
+                    handle,
+                    kind: AssetEventKind::BytesProcessingFailed,
+                    path: event_path(&self.storage, handle.entity(), &path),
+                })?;
+            }
+            // Keep inserting other decoded assets, so none is left half-processed.
+            if !self.allow_asset_progression_failures && result.is_ok() {
+                result = status;
+            }
+        }
+        result?;
+        self.maintain()
     }
 
-    /// Reloads an asset by unloading and ensuring it is reloaded.
+    /// Loads an asset and blocks until it is ready to use, returning copy of
+    /// its component.
+    ///
+    /// If `max_maintain_iterations` is set and asset is still not ready after
+    /// that many iterations, an error is returned.
     ///
     /// # Arguments
-    /// - `path`: The path of the asset to reload.
+    /// - `path`: The path of the asset to load.
     ///
     /// # Returns
-    /// An `AssetHandle` for the reloaded asset.
-    pub fn reload(
+    /// Clone of asset component `T`, or an error if loading fails or asset
+    /// has no such component.
+    pub fn load_now<T: Component + Clone>(
         &mut self,
         path: impl Into<AssetPathStatic>,
-    ) -> Result<AssetHandle, Box<dyn Error>> {
+    ) -> Result<T, Box<dyn Error>> {
         let path = path.into();
-        self.unload(path.clone())?;
-        self.ensure(path)
+        let handle = self.ensure(path.clone())?;
+        let mut iterations = 0;
+        while !handle.is_ready_to_use(self) {
+            if !handle.does_exists(self) {
+                return Err(format!("Asset `{path}` got unloaded while loading").into());
+            }
+            if let Some(limit) = self.max_maintain_iterations
+                && iterations >= limit
+            {
+                return Err(format!(
+                    "Asset `{path}` is still not ready after {limit} maintain iterations"
+                )
+                .into());
+            }
+            self.maintain()?;
+            iterations += 1;
+        }
+        handle.lookup::<&T>(self).cloned().ok_or_else(|| {
+            format!(
+                "Asset `{path}` has no {} component",
+                std::any::type_name::<T>()
+            )
+            .into()
+        })
     }
 
-    /// Returns an iterator over all assets with a specific component.
+    /// Validates database setup, useful for catching misconfiguration at startup.
+    ///
+    /// Checks that:
+    /// - there is at least one protocol registered,
+    /// - there is an asset fetch on stack,
+    /// - there is an asset store on stack if any asset awaits storing,
+    /// - every asset in database uses registered protocol.
     ///
     /// # Returns
-    /// An iterator that yields `AssetHandle` instances.
-    pub fn assets_with<T: Component>(&self) -> impl Iterator<Item = AssetHandle> + '_ {
-        self.storage
-            .query::<true, (Entity, Include<T>)>()
-            .map(|(entity, _)| AssetHandle::new(entity))
+    /// `Ok(())` if setup is valid, otherwise list of found problems.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::default();
+        if self.protocols.is_empty() {
+            problems.push("There are no asset protocols registered!".to_owned());
+        }
+        if self.fetch_stack.is_empty() {
+            problems.push("There is no asset fetch on stack!".to_owned());
+        }
+        if self.store_stack.is_empty()
+            && (self.storage.has_component::<AssetAwaitsStoring>()
+                || self.storage.has_component::<AssetBytesAreReadyToStore>())
+        {
+            problems.push("There is no asset store on stack, but assets await storing!".to_owned());
+        }
+        for (_, path) in self.storage.query::<true, (Entity, &AssetPath)>() {
+            if !self
+                .protocols
+                .iter()
+                .any(|protocol| protocol.name() == path.protocol())
+            {
+                problems.push(format!("Missing protocol for asset: `{path}`"));
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
     }
 
-    /// Checks if there are any assets with a specific component.
+    /// Exports asset dependency graph as Graphviz DOT document.
+    /// Nodes are labeled with asset paths and colored by their state:
+    /// yellow for assets in progress, green for assets ready to use.
     ///
     /// # Returns
-    /// `true` if there is at least one asset with the component, otherwise `false
-    pub fn has<T: Component>(&self) -> bool {
-        self.storage.has_component::<T>()
+    /// DOT document content.
+    pub fn export_dot(&self) -> String {
+        let nodes = self
+            .storage
+            .query::<true, (Entity, &AssetPath)>()
+            .enumerate()
+            .map(|(index, (entity, path))| (entity, (index, path.content().to_owned())))
+            .collect::<HashMap<_, _>>();
+        let mut sorted = nodes.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|(_, (index, _))| *index);
+        let mut result = String::default();
+        result.push_str("digraph assets {\n");
+        result.push_str("    node [shape=box, style=filled];\n");
+        for (entity, (index, path)) in &sorted {
+            let color = if is_asset_busy(&self.storage, **entity) {
+                "lightgoldenrod"
+            } else {
+                "palegreen"
+            };
+            let _ = writeln!(
+                &mut result,
+                "    n{} [label=\"{}\", fillcolor={}];",
+                index,
+                path.replace('\\', "\\\\").replace('"', "\\\""),
+                color
+            );
+        }
+        for (entity, (from, _)) in &sorted {
+            for (_, _, target) in self
+                .storage
+                .relations_outgoing::<true, AssetDependency>(**entity)
+            {
+                if let Some((to, _)) = nodes.get(&target) {
+                    let _ = writeln!(&mut result, "    n{from} -> n{to};");
+                }
+            }
+        }
+        result.push_str("}\n");
+        result
     }
 
-    /// Determines if the asset database is currently busy with tasks.
+    /// Collects asset and its dependencies reachable within given depth.
+    /// When deeper dependencies exist, asset gets marked as exceeding
+    /// dependency depth on next maintenance.
+    fn dependencies_within_depth(&self, root: Entity, max_depth: usize) -> Vec<Entity> {
+        let mut nodes = vec![(root, 0)];
+        let mut visited = HashSet::from([root]);
+        let mut exceeded = false;
+        let mut index = 0;
+        while let Some((entity, depth)) = nodes.get(index).copied() {
+            index += 1;
+            for (_, _, target) in self
+                .storage
+                .relations_outgoing::<true, AssetDependency>(entity)
+            {
+                if depth >= max_depth {
+                    exceeded = true;
+                    break;
+                }
+                if visited.insert(target) {
+                    nodes.push((target, depth + 1));
+                }
+            }
+        }
+        if exceeded
+            && !self
+                .storage
+                .has_entity_component::<AssetDependencyDepthExceeded>(root)
+        {
+            self.commands_sender()
+                .send(Box::new(move |storage: &mut World| {
+                    if !storage.has_entity_component::<AssetDependencyDepthExceeded>(root) {
+                        let _ = storage.insert(root, (AssetDependencyDepthExceeded,));
+                    }
+                }));
+        }
+        nodes.into_iter().map(|(entity, _)| entity).collect()
+    }
+
+    /// Computes processing order of asset and all its dependencies, where
+    /// dependencies always come before assets that depend on them.
+    /// Useful for systems that have to initialize resources bottom-up
+    /// (textures before materials).
+    ///
+    /// # Arguments
+    /// - `root`: Handle of the root asset.
     ///
     /// # Returns
-    /// `true` if busy, otherwise `false`.
-    pub fn is_busy(&self) -> bool {
-        self.storage.has_component::<AssetAwaitsResolution>()
-            || self.storage.has_component::<AssetBytesAreReadyToProcess>()
-            || self.storage.has_component::<AssetAwaitsAsyncFetch>()
-            || self.storage.has_component::<AssetAwaitsStoring>()
-            || self.storage.has_component::<AssetBytesAreReadyToStore>()
-            || self.storage.has_component::<AssetAwaitsAsyncStore>()
-            || self.storage.has_component::<AssetAwaitsAsyncProcessing>()
-            || self.storage.has_component::<AssetAwaitsAsyncProducing>()
+    /// Asset handles in dependency order (ending with root asset), or
+    /// `CycleError` listing assets that could not be ordered because of
+    /// dependency cycle.
+    pub fn topological_order(&self, root: AssetHandle) -> Result<Vec<AssetHandle>, CycleError> {
+        let mut nodes = vec![root.entity()];
+        let mut visited = HashSet::from([root.entity()]);
+        let mut index = 0;
+        while let Some(entity) = nodes.get(index).copied() {
+            index += 1;
+            for (_, _, target) in self
+                .storage
+                .relations_outgoing::<true, AssetDependency>(entity)
+            {
+                if visited.insert(target) {
+                    nodes.push(target);
+                }
+            }
+        }
+        let mut remaining = nodes
+            .iter()
+            .map(|entity| {
+                let count = self
+                    .storage
+                    .relations_outgoing::<true, AssetDependency>(*entity)
+                    .count();
+                (*entity, count)
+            })
+            .collect::<HashMap<_, _>>();
+        let mut queue = nodes
+            .iter()
+            .filter(|entity| remaining.get(entity).copied() == Some(0))
+            .copied()
+            .collect::<VecDeque<_>>();
+        let mut result = Vec::with_capacity(nodes.len());
+        while let Some(entity) = queue.pop_front() {
+            result.push(AssetHandle::new(entity));
+            for (source, _, _) in self
+                .storage
+                .relations_incomming::<true, AssetDependency>(entity)
+            {
+                if let Some(count) = remaining.get_mut(&source) {
+                    *count = count.saturating_sub(1);
+                    if *count == 0 {
+                        queue.push_back(source);
+                    }
+                }
+            }
+        }
+        if result.len() == nodes.len() {
+            Ok(result)
+        } else {
+            Err(CycleError {
+                handles: nodes
+                    .into_iter()
+                    .filter(|entity| remaining.get(entity).copied().unwrap_or_default() > 0)
+                    .map(AssetHandle::new)
+                    .collect(),
+            })
+        }
     }
 
     /// Reports the status of assets in the database.
     ///
+    /// Assets being loaded again (for example reloaded or reprocessed) are
+    /// reported by their loading stage, even if their previous load failed.
+    ///
     /// # Arguments
     /// - `out_status`: A mutable reference to output `AssetsLoadingStatus`.
     pub fn report_loading_status(&self, out_status: &mut AssetsStatus) {
         out_status.clear();
         for (
             handle,
+            asset_load_error,
             asset_awaits_resolution,
             asset_bytes_ready_to_process,
+            asset_awaits_reprocessing,
             asset_awaits_async_fetch,
         ) in self.storage.query::<true, (
             AssetHandle,
+            Option<&AssetLoadError>,
             Option<&AssetAwaitsResolution>,
             Option<&AssetBytesAreReadyToProcess>,
+            Option<&AssetAwaitsReprocessing>,
             Option<&AssetAwaitsAsyncFetch>,
         )>() {
             if asset_awaits_resolution.is_some() {
                 out_status.awaiting_resolution.add(handle);
-            } else if asset_bytes_ready_to_process.is_some() {
+            } else if asset_bytes_ready_to_process.is_some() || asset_awaits_reprocessing.is_some()
+            {
                 out_status.with_bytes_ready_to_process.add(handle);
             } else if asset_awaits_async_fetch.is_some() {
                 out_status.awaiting_async_fetch.add(handle);
+            } else if asset_load_error.is_some() {
+                out_status.failed.add(handle);
             } else {
                 out_status.ready_to_use.add(handle);
             }
         }
     }
 
+    /// Reports the storing status of assets in the database.
+    ///
+    /// Only assets that were requested to be stored are reported, where
+    /// already stored ones are put into `ready_to_use` category.
+    ///
+    /// # Arguments
+    /// - `out_status`: A mutable reference to output `AssetsStatus`.
+    pub fn report_storing_status(&self, out_status: &mut AssetsStatus) {
+        out_status.clear();
+        for (
+            handle,
+            timing,
+            asset_awaits_storing,
+            asset_bytes_ready_to_store,
+            asset_awaits_async_store,
+        ) in self.storage.query::<true, (
+            AssetHandle,
+            Option<&AssetStoreTiming>,
+            Option<&AssetAwaitsStoring>,
+            Option<&AssetBytesAreReadyToStore>,
+            Option<&AssetAwaitsAsyncStore>,
+        )>() {
+            if asset_awaits_storing.is_some() {
+                out_status.awaiting_storing.add(handle);
+            } else if asset_bytes_ready_to_store.is_some() {
+                out_status.with_bytes_ready_to_store.add(handle);
+            } else if asset_awaits_async_store.is_some() {
+                out_status.awaiting_async_store.add(handle);
+            } else if timing.is_some() {
+                out_status.ready_to_use.add(handle);
+            }
+        }
+    }
+
+    // Commands can send more commands, so queue gets drained in rounds until
+    // it settles, bounded to not loop forever on commands resending themselves.
+    fn run_commands(&mut self) {
+        for _ in 0..MAX_COMMANDS_ROUNDS {
+            let commands = match self.commands.lock() {
+                Ok(mut queue) => std::mem::take(&mut *queue),
+                Err(_) => return,
+            };
+            if commands.is_empty() {
+                return;
+            }
+            for command in commands {
+                command(&mut self.storage);
+            }
+        }
+    }
+
     /// Returns the sender for asset database commands.
     /// This can be used to send commands to the asset database from external places.
     pub fn commands_sender(&self) -> AssetDatabaseCommandsSender {
@@ -559,18 +2159,19 @@ impl AssetDatabase {
 
     /// Performs maintenance on the asset database, processing events and managing states.
     ///
+    /// - Runs queued commands, including commands sent by other commands.
     /// - Processes changed assets and dispatches relevant events.
     /// - Maintains fetch and store engines and protocols.
     /// - Resolves assets and processes their data using protocols.
     /// - Stores requested assets.
+    /// - Flushes events accumulated for batched event listeners.
     ///
     /// # Returns
     /// `Ok(())` if successful, or an error if any step fails.
     pub fn maintain(&mut self) -> Result<(), Box<dyn Error>> {
-        if let Ok(mut queue) = self.commands.lock() {
-            while let Some(command) = queue.pop_front() {
-                command(&mut self.storage);
-            }
+        self.run_commands();
+        if self.is_cancelled() {
+            self.drop_pending_work()?;
         }
         let despawn = if let Some(changes) = self.storage.updated() {
             if changes.has_component::<AssetReferenceCounter>() {
@@ -599,6 +2200,55 @@ impl AssetDatabase {
         if let Some(despawn) = despawn {
             despawn.execute(&mut self.storage)?;
         }
+        let source_changed = self
+            .storage
+            .added()
+            .iter_of::<AssetSourceChanged>()
+            .collect::<Vec<_>>();
+        let store_started = self
+            .storage
+            .added()
+            .iter_of::<AssetAwaitsStoring>()
+            .collect::<Vec<_>>();
+        for entity in &source_changed {
+            if let Ok(path) = self.storage.component::<true, AssetPathStatic>(*entity) {
+                self.events.dispatch(AssetEvent {
+                    handle: AssetHandle::new(*entity),
+                    kind: AssetEventKind::SourceChanged,
+                    path: event_path(&self.storage, *entity, &path),
+                })?;
+            }
+        }
+        // Assets spawned at runtime have no event bindings, so these events
+        // are dispatched to global listeners regardless.
+        let noticed = self
+            .storage
+            .added()
+            .iter_of::<AssetFirstAccessed>()
+            .map(|entity| (entity, AssetEventKind::Accessed))
+            .chain(
+                self.storage
+                    .added()
+                    .iter_of::<AssetDependencyDepthExceeded>()
+                    .map(|entity| (entity, AssetEventKind::DepthExceeded)),
+            )
+            .collect::<Vec<_>>();
+        for (entity, kind) in noticed {
+            if let Ok(path) = self.storage.component::<true, AssetPathStatic>(entity) {
+                let event = AssetEvent {
+                    handle: AssetHandle::new(entity),
+                    kind,
+                    path: event_path(&self.storage, entity, &path),
+                };
+                self.events.dispatch(event.clone())?;
+                if let Ok(mut bindings) = self
+                    .storage
+                    .component_mut::<true, AssetEventBindings>(entity)
+                {
+                    bindings.dispatch(event)?;
+                }
+            }
+        }
         {
             let mut lookup = self
                 .storage
@@ -608,7 +2258,7 @@ impl AssetDatabase {
                     let event = AssetEvent {
                         handle: AssetHandle::new(entity),
                         kind: AssetEventKind::AwaitsResolution,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
@@ -619,7 +2269,7 @@ impl AssetDatabase {
                     let event = AssetEvent {
                         handle: AssetHandle::new(entity),
                         kind: AssetEventKind::AwaitsAsyncFetch,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
@@ -634,7 +2284,7 @@ impl AssetDatabase {
                     let event = AssetEvent {
                         handle: AssetHandle::new(entity),
                         kind: AssetEventKind::BytesReadyToProcess,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
@@ -645,15 +2295,13 @@ impl AssetDatabase {
                 .removed()
                 .iter_of::<AssetBytesAreReadyToProcess>()
             {
+                // Dispatched also for assets that became ready to use, so
+                // listeners waiting for finished loads get notified.
                 if let Some((path, bindings)) = lookup.access(entity) {
-                    let handle = AssetHandle::new(entity);
-                    if handle.is_ready_to_use(self) {
-                        continue;
-                    }
                     let event = AssetEvent {
-                        handle,
+                        handle: AssetHandle::new(entity),
                         kind: AssetEventKind::BytesProcessed,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
@@ -664,7 +2312,7 @@ impl AssetDatabase {
                     let event = AssetEvent {
                         handle: AssetHandle::new(entity),
                         kind: AssetEventKind::Unloaded,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
@@ -675,7 +2323,7 @@ impl AssetDatabase {
                     let event = AssetEvent {
                         handle: AssetHandle::new(entity),
                         kind: AssetEventKind::AwaitsStoring,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
@@ -686,7 +2334,7 @@ impl AssetDatabase {
                     let event = AssetEvent {
                         handle: AssetHandle::new(entity),
                         kind: AssetEventKind::AwaitsAsyncStore,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
@@ -697,7 +2345,7 @@ impl AssetDatabase {
                     let event = AssetEvent {
                         handle: AssetHandle::new(entity),
                         kind: AssetEventKind::BytesReadyToStore,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
@@ -713,54 +2361,111 @@ impl AssetDatabase {
                     let event = AssetEvent {
                         handle,
                         kind: AssetEventKind::BytesStored,
-                        path: path.clone(),
+                        path: event_path(&self.storage, entity, path),
                     };
                     self.events.dispatch(event.clone())?;
                     bindings.dispatch(event)?;
                 }
             }
         }
+        for entity in source_changed {
+            if self
+                .storage
+                .has_entity_component::<AssetSourceChanged>(entity)
+            {
+                self.storage.remove::<(AssetSourceChanged,)>(entity)?;
+            }
+        }
+        for entity in store_started {
+            if self
+                .storage
+                .has_entity_component::<AssetAwaitsStoring>(entity)
+            {
+                self.storage
+                    .insert(entity, (AssetStoreTiming::default(),))?;
+            }
+        }
         self.storage.clear_changes();
+        if self.case_insensitive_paths {
+            // Assets spawned directly in storage (like protocol dependencies)
+            // do not have case-insensitive key yet.
+            let to_key = self
+                .storage
+                .query::<true, (Entity, &AssetPath, Option<&AssetPathCaseInsensitive>)>()
+                .filter(|(_, _, key)| key.is_none())
+                .map(|(entity, path, _)| (entity, AssetPathCaseInsensitive::new(path)))
+                .collect::<Vec<_>>();
+            for (entity, key) in to_key {
+                self.storage.insert(entity, (key,))?;
+            }
+        }
         for fetch in &mut self.fetch_stack {
             fetch.maintain(&mut self.storage)?;
         }
         for store in &mut self.store_stack {
             store.maintain(&mut self.storage)?;
         }
+        if !self.protocol_factories.is_empty() {
+            // Assets spawned directly in storage (like protocol dependencies)
+            // might use protocols not constructed yet.
+            let names = self
+                .storage
+                .query::<true, (&AssetPath, Include<AssetBytesAreReadyToProcess>)>()
+                .map(|(path, _)| path.protocol().to_owned())
+                .collect::<HashSet<_>>();
+            for name in names {
+                self.construct_protocol(&name);
+            }
+        }
         for protocol in &mut self.protocols {
-            protocol.maintain(&mut self.storage)?;
-            let to_process = self
+            // Asynchronous protocols report assets failed to process here.
+            let status = protocol.maintain(&mut self.storage);
+            if !self.allow_asset_progression_failures {
+                status?;
+            }
+            // Protocols might request reprocessing of their dependents.
+            prepare_reprocessing(&mut self.storage)?;
+            let mut to_process = self
                 .storage
-                .query::<true, (Entity, &AssetPath, Include<AssetBytesAreReadyToProcess>)>()
-                .filter(|(_, path, _)| path.protocol() == protocol.name())
-                .map(|(entity, _, _)| AssetHandle::new(entity))
+                .query::<true, (
+                    Entity,
+                    &AssetPath,
+                    Include<AssetBytesAreReadyToProcess>,
+                    Exclude<AssetPrefetched>,
+                    Exclude<AssetAwaitsAsyncFetch>,
+                )>()
+                .filter(|(_, path, _, _, _)| path.protocol() == protocol.name())
+                .map(|(entity, _, _, _, _)| AssetHandle::new(entity))
                 .collect::<Vec<_>>();
+            if self.deterministic_order {
+                sort_by_path(&self.storage, &mut to_process);
+            }
+            sort_by_priority(&self.storage, &mut to_process, |handle| *handle);
             for handle in to_process {
-                let status = protocol.process_asset_bytes(handle, &mut self.storage);
-                if status.is_err()
-                    && let Ok(mut bindings) = self
-                        .storage
-                        .component_mut::<true, AssetEventBindings>(handle.entity())
-                {
-                    bindings.dispatch(AssetEvent {
-                        handle,
-                        kind: AssetEventKind::BytesProcessingFailed,
-                        path: self
-                            .storage
-                            .component::<true, AssetPathStatic>(handle.entity())?
-                            .clone(),
-                    })?;
-                }
+                let path = self
+                    .storage
+                    .component::<true, AssetPathStatic>(handle.entity())?
+                    .clone();
+                let status = process_asset_bytes(
+                    &mut **protocol,
+                    &mut self.storage,
+                    handle,
+                    &path,
+                    self.retain_source_bytes,
+                );
                 if !self.allow_asset_progression_failures {
                     status?;
                 }
             }
-            let to_produce = self
+            let mut to_produce = self
                 .storage
                 .query::<true, (Entity, &AssetPath, Include<AssetAwaitsStoring>)>()
                 .filter(|(_, path, _)| path.protocol() == protocol.name())
                 .map(|(entity, _, _)| AssetHandle::new(entity))
                 .collect::<Vec<_>>();
+            if self.deterministic_order {
+                sort_by_path(&self.storage, &mut to_produce);
+            }
             for handle in to_produce {
                 let status = protocol.produce_asset_bytes(handle, &mut self.storage);
                 if status.is_err() {
@@ -771,10 +2476,13 @@ impl AssetDatabase {
                         bindings.dispatch(AssetEvent {
                             handle,
                             kind: AssetEventKind::BytesStoringFailed,
-                            path: self
-                                .storage
-                                .component::<true, AssetPathStatic>(handle.entity())?
-                                .clone(),
+                            path: event_path(
+                                &self.storage,
+                                handle.entity(),
+                                &*self
+                                    .storage
+                                    .component::<true, AssetPathStatic>(handle.entity())?,
+                            ),
                         })?;
                     }
                 } else {
@@ -786,11 +2494,15 @@ impl AssetDatabase {
                 }
             }
         }
-        let to_resolve = self
+        let mut to_resolve = self
             .storage
             .query::<true, (AssetHandle, &AssetPath, Include<AssetAwaitsResolution>)>()
             .map(|(handle, path, _)| (handle, path.clone()))
             .collect::<Vec<_>>();
+        if self.deterministic_order {
+            to_resolve.sort_by(|(_, a), (_, b)| a.content().cmp(b.content()));
+        }
+        sort_by_priority(&self.storage, &mut to_resolve, |(handle, _)| *handle);
         if !to_resolve.is_empty() {
             if let Some(fetch) = self.fetch_stack.last_mut() {
                 for (handle, path) in to_resolve {
@@ -805,11 +2517,14 @@ impl AssetDatabase {
                 return Err("There is no asset fetch on stack!".into());
             }
         }
-        let to_store = self
+        let mut to_store = self
             .storage
             .query::<true, (AssetHandle, &AssetPath, &mut AssetBytesAreReadyToStore)>()
             .map(|(handle, path, bytes)| (handle, path.clone(), std::mem::take(&mut bytes.0)))
             .collect::<Vec<_>>();
+        if self.deterministic_order {
+            to_store.sort_by(|(_, a, _), (_, b, _)| a.content().cmp(b.content()));
+        }
         if !to_store.is_empty() {
             if let Some(store) = self.store_stack.last_mut() {
                 for (handle, path, bytes) in to_store {
@@ -824,10 +2539,63 @@ impl AssetDatabase {
                 return Err("There is no asset store on stack!".into());
             }
         }
+        for (timing, awaits_storing, bytes_ready_to_store, awaits_async_store) in
+            self.storage.query::<true, (
+                &mut AssetStoreTiming,
+                Option<&AssetAwaitsStoring>,
+                Option<&AssetBytesAreReadyToStore>,
+                Option<&AssetAwaitsAsyncStore>,
+            )>()
+        {
+            if timing.stored.is_none()
+                && awaits_storing.is_none()
+                && bytes_ready_to_store.is_none()
+                && awaits_async_store.is_none()
+            {
+                timing.stored = Some(Instant::now());
+            }
+        }
+        if self.progress_listener.is_some() {
+            let mut status = AssetsStatus::amount();
+            self.report_loading_status(&mut status);
+            let progress = status.progress();
+            if self.last_progress != Some(progress)
+                && let Some(listener) = self.progress_listener.as_mut()
+            {
+                listener(progress);
+                self.last_progress = Some(progress);
+            }
+        }
+        self.events.flush();
+        for (bindings,) in self.storage.query::<true, (&mut AssetEventBindings,)>() {
+            bindings.flush();
+        }
         Ok(())
     }
 }
 
+/// Error message of failed fetching or processing of an asset.
+///
+/// Removed once asset gets successfully fetched or processed again.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AssetLoadError(pub String);
+
+/// Time of first access to asset data, recorded when access tracking is
+/// enabled in asset database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssetFirstAccessed(pub Instant);
+
+/// Marker component of assets which dependency graph got truncated by
+/// dependency depth limit set in asset database.
+pub struct AssetDependencyDepthExceeded;
+
+/// Load priority of an asset.
+///
+/// Assets with higher priority get resolved and processed first within single
+/// maintenance, assets without it are treated as having zero priority.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssetPriority(pub i32);
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct AssetReferenceCounter(usize);
 
@@ -844,3 +2612,22 @@ impl AssetReferenceCounter {
         self.0 = self.0.saturating_sub(1);
     }
 }
+
+/// Error reported when asset dependency graph contains a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// Assets that are part of a cycle or depend on one.
+    pub handles: Vec<AssetHandle>,
+}
+
+impl std::fmt::Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Asset dependency cycle detected between assets:")?;
+        for handle in &self.handles {
+            write!(f, " {handle}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for CycleError {}
@@ -7,8 +7,20 @@ use std::{
     fmt::Write,
     hash::{Hash, Hasher},
     ops::Range,
+    str::FromStr,
 };
 
+/// Decodes byte of percent-encoded sequence at the start of given bytes.
+fn percent_sequence(bytes: &[u8]) -> Option<u8> {
+    match bytes {
+        [b'%', high, low, ..] if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => {
+            let hex = [*high, *low];
+            u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()
+        }
+        _ => None,
+    }
+}
+
 /// A static version of `AssetPath` that has a `'static` lifetime.
 pub type AssetPathStatic = AssetPath<'static>;
 
@@ -19,6 +31,8 @@ pub type AssetPathStatic = AssetPath<'static>;
 /// - **Protocol**: The scheme of the asset path (e.g., `file`, `http`).
 /// - **Path**: The main path to the asset (e.g., `/assets/texture.png`).
 /// - **Meta**: Optional metadata for the asset, typically a query string (e.g., `?version=1`).
+/// - **Fragment**: Optional sub-asset selector (e.g., `#mesh0`), which protocols
+///   can use to pick part of multi-resource asset.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(from = "String", into = "String")]
 pub struct AssetPath<'a> {
@@ -33,6 +47,9 @@ pub struct AssetPath<'a> {
     /// Range of the meta section in the content string.
     #[serde(skip)]
     meta: Range<usize>,
+    /// Range of the fragment section in the content string.
+    #[serde(skip)]
+    fragment: Range<usize>,
 }
 
 impl<'a> AssetPath<'a> {
@@ -44,21 +61,40 @@ impl<'a> AssetPath<'a> {
         } else {
             (0..0, 0)
         };
-        let (path_end, meta) = if let Some(path_end) = content.find('?') {
-            (path_end, (path_end + b"?".len())..content.len())
+        let (meta_end, fragment) = if let Some(meta_end) = content[path_start..].find('#') {
+            let meta_end = path_start + meta_end;
+            (meta_end, (meta_end + b"#".len())..content.len())
         } else {
             (content.len(), content.len()..content.len())
         };
+        let (path_end, meta) = if let Some(path_end) = content[path_start..meta_end].find('?') {
+            let path_end = path_start + path_end;
+            (path_end, (path_end + b"?".len())..meta_end)
+        } else {
+            (meta_end, meta_end..meta_end)
+        };
         Self {
             content,
             protocol,
             path: path_start..path_end,
             meta,
+            fragment,
         }
     }
 
     /// Constructs an `AssetPath` from separate protocol, path, and metadata strings.
     pub fn from_parts(protocol: &str, path: &str, meta: &str) -> Self {
+        Self::from_parts_with_fragment(protocol, path, meta, "")
+    }
+
+    /// Constructs an `AssetPath` from separate protocol, path, metadata and
+    /// fragment strings.
+    pub fn from_parts_with_fragment(
+        protocol: &str,
+        path: &str,
+        meta: &str,
+        fragment: &str,
+    ) -> Self {
         let mut result = String::new();
         if !protocol.is_empty() {
             let _ = write!(&mut result, "{protocol}://");
@@ -67,6 +103,9 @@ impl<'a> AssetPath<'a> {
         if !meta.is_empty() {
             let _ = write!(&mut result, "?{meta}");
         }
+        if !fragment.is_empty() {
+            let _ = write!(&mut result, "#{fragment}");
+        }
         Self::new(result)
     }
 
@@ -77,6 +116,7 @@ impl<'a> AssetPath<'a> {
             protocol: self.protocol,
             path: self.path,
             meta: self.meta,
+            fragment: self.fragment,
         }
     }
 
@@ -147,17 +187,159 @@ impl<'a> AssetPath<'a> {
         self.meta_items().any(|(k, v)| k == key && v == value)
     }
 
+    /// Retrieves raw value of specific meta key.
+    pub fn meta_value(&self, key: &str) -> Option<&str> {
+        self.meta_items().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// Parses value of specific meta key.
+    /// Returns `None` if key is missing, otherwise parsing result.
+    pub fn meta_get<T: FromStr>(&self, key: &str) -> Option<Result<T, T::Err>> {
+        self.meta_value(key).map(|value| value.parse())
+    }
+
+    /// Parses value of specific meta key, falling back to default value if
+    /// key is missing or its value is malformed.
+    pub fn meta_get_or<T: FromStr>(&self, key: &str, default: T) -> T {
+        self.meta_get(key).and_then(Result::ok).unwrap_or(default)
+    }
+
+    /// Checks if specific meta key is set as flag, either without value or
+    /// with one of: `true`, `yes`, `on`, `1` (case-insensitive).
+    pub fn meta_flag(&self, key: &str) -> bool {
+        self.meta_value(key)
+            .map(|value| {
+                value.is_empty()
+                    || ["true", "yes", "on", "1"]
+                        .iter()
+                        .any(|item| value.eq_ignore_ascii_case(item))
+            })
+            .unwrap_or_default()
+    }
+
     /// Tries to retrieve the metadata, returning `None` if it's empty.
     pub fn try_meta(&self) -> Option<&str> {
         let meta = self.meta();
         if meta.is_empty() { None } else { Some(meta) }
     }
 
+    /// Returns the fragment part of the `AssetPath`.
+    pub fn fragment(&self) -> &str {
+        &self.content[self.fragment.clone()]
+    }
+
+    /// Tries to retrieve the fragment, returning `None` if it's empty.
+    pub fn try_fragment(&self) -> Option<&str> {
+        let fragment = self.fragment();
+        if fragment.is_empty() {
+            None
+        } else {
+            Some(fragment)
+        }
+    }
+
+    /// Returns copy of the `AssetPath` without fragment, addressing whole
+    /// asset instead of its part.
+    pub fn without_fragment(&self) -> AssetPathStatic {
+        AssetPath::from_parts(self.protocol(), self.path(), self.meta())
+    }
+
     /// Returns the combined path and metadata of the `AssetPath`.
     pub fn path_with_meta(&self) -> &str {
         &self.content[self.path.start..self.meta.end]
     }
 
+    /// Resolves relative path against directory of this path, preserving
+    /// protocol of this path.
+    ///
+    /// - `.` and `..` segments are normalized, never going above the root.
+    /// - Relative path starting with `/` is resolved against the root.
+    /// - Relative path with its own protocol is returned as is.
+    /// - Meta and fragment of relative path are kept, ones of this path are dropped.
+    ///
+    /// # Example
+    /// `AssetPath::new("shader://shaders/image.shader").join("common.glsl")`
+    /// yields `shader://shaders/common.glsl`.
+    pub fn join(&self, relative: &str) -> AssetPathStatic {
+        let relative = AssetPath::new(relative);
+        if !relative.protocol().is_empty() {
+            return relative.into_static();
+        }
+        let mut parts = Vec::default();
+        if !relative.path().starts_with(['/', '\\']) {
+            parts.extend(
+                self.path_parts()
+                    .filter(|part| !part.is_empty() && *part != "."),
+            );
+            parts.pop();
+        }
+        for part in relative.path_parts() {
+            match part {
+                "" | "." => {}
+                ".." => {
+                    parts.pop();
+                }
+                part => parts.push(part),
+            }
+        }
+        AssetPath::from_parts_with_fragment(
+            self.protocol(),
+            &parts.join("/"),
+            relative.meta(),
+            relative.fragment(),
+        )
+    }
+
+    /// Returns copy of the `AssetPath` with path part percent-encoded, so it
+    /// can be safely used in URLs. Protocol, meta and fragment parts are left intact,
+    /// as well as path separators and already encoded sequences.
+    pub fn percent_encoded(&self) -> AssetPathStatic {
+        let path = self.path().as_bytes();
+        let mut result = String::with_capacity(path.len());
+        let mut index = 0;
+        while index < path.len() {
+            let byte = path[index];
+            if percent_sequence(&path[index..]).is_some() {
+                result.push_str(&self.path()[index..(index + 3)]);
+                index += 3;
+                continue;
+            }
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~' | b'/') {
+                result.push(byte as char);
+            } else {
+                let _ = write!(&mut result, "%{byte:02X}");
+            }
+            index += 1;
+        }
+        AssetPath::from_parts_with_fragment(self.protocol(), &result, self.meta(), self.fragment())
+    }
+
+    /// Returns copy of the `AssetPath` with percent-encoded sequences of path
+    /// part decoded. Protocol, meta and fragment parts are left intact. Invalid
+    /// sequences and encoded `?` and `#` separators are kept as they are.
+    pub fn percent_decoded(&self) -> AssetPathStatic {
+        let path = self.path().as_bytes();
+        let mut result = Vec::with_capacity(path.len());
+        let mut index = 0;
+        while index < path.len() {
+            if let Some(byte) = percent_sequence(&path[index..])
+                && !matches!(byte, b'?' | b'#')
+            {
+                result.push(byte);
+                index += 3;
+            } else {
+                result.push(path[index]);
+                index += 1;
+            }
+        }
+        AssetPath::from_parts_with_fragment(
+            self.protocol(),
+            &String::from_utf8_lossy(&result),
+            self.meta(),
+            self.fragment(),
+        )
+    }
+
     /// Schedules the asset in the given `AssetDatabase`.
     pub fn schedule(&self, database: &mut AssetDatabase) -> Result<AssetHandle, Box<dyn Error>> {
         database.schedule(self.clone().into_static())
@@ -174,6 +356,29 @@ impl<'a> AssetPath<'a> {
     }
 }
 
+/// Key component used to find assets when case-insensitive paths are enabled
+/// in `AssetDatabase`. Holds asset path with lowercased path part, while
+/// protocol, meta and fragment parts are left intact.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssetPathCaseInsensitive(pub AssetPathStatic);
+
+impl AssetPathCaseInsensitive {
+    /// Creates case-insensitive key of given asset path.
+    pub fn new(path: &AssetPath) -> Self {
+        Self(AssetPath::from_parts_with_fragment(
+            path.protocol(),
+            &path.path().to_lowercase(),
+            path.meta(),
+            path.fragment(),
+        ))
+    }
+}
+
+/// Component holding alias path that asset was requested with.
+/// Events of such asset are reported under alias path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssetPathAlias(pub AssetPathStatic);
+
 impl PartialOrd for AssetPath<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -201,6 +406,9 @@ impl std::fmt::Display for AssetPath<'_> {
         if !self.meta.is_empty() {
             write!(f, "?{}", self.meta())?;
         }
+        if !self.fragment.is_empty() {
+            write!(f, "#{}", self.fragment())?;
+        }
         Ok(())
     }
 }
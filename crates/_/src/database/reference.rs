@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use std::{
     error::Error,
     ops::{Deref, DerefMut},
-    sync::RwLock,
+    sync::{Arc, RwLock},
 };
 
 /// A reference to an asset in the asset database.
@@ -109,6 +109,36 @@ impl AssetRef {
         }
     }
 
+    /// Resolves the asset handle using shared asset database and returns
+    /// owned copy of requested asset component.
+    ///
+    /// Database read lock is held only for the time of resolution and cloning,
+    /// which plays nicely with async handlers. Note that component gets cloned
+    /// on every call, so for big assets consider cloning cheap handles to its
+    /// data, like `Arc<T>`, instead.
+    ///
+    /// # Arguments
+    /// - `database`: Shared `AssetDatabase` to resolve the asset.
+    ///
+    /// # Returns
+    /// A clone of asset component `T`, or an error if resolution fails or
+    /// asset has no such component.
+    pub fn resolve_shared<T: Component + Clone>(
+        &self,
+        database: &Arc<RwLock<AssetDatabase>>,
+    ) -> Result<T, Box<dyn Error>> {
+        let database = database.read().map_err(|error| format!("{error}"))?;
+        let resolved = self.resolve(&database)?;
+        resolved.access_checked::<&T>().cloned().ok_or_else(|| {
+            format!(
+                "Asset with `{}` path has no {} component!",
+                self.path,
+                std::any::type_name::<T>()
+            )
+            .into()
+        })
+    }
+
     /// Ensures existence of the asset with handle using the asset database.
     ///
     /// # Arguments
@@ -129,6 +159,27 @@ impl AssetRef {
             Ok(AssetResolved::new(result, database))
         }
     }
+
+    /// Reloads the asset using the asset database and replaces cached handle.
+    ///
+    /// Cached handle gets invalidated first, then asset gets unloaded and
+    /// scheduled again, so reference never points to stale asset entity.
+    ///
+    /// # Arguments
+    /// - `database`: Reference to the `AssetDatabase` to reload the asset.
+    ///
+    /// # Returns
+    /// A reloaded `AssetResolved` object, or an error if reloading fails.
+    pub fn reload<'a>(
+        &'a self,
+        database: &'a mut AssetDatabase,
+    ) -> Result<AssetResolved<'a>, Box<dyn Error>> {
+        let mut handle = self.handle.write().map_err(|error| format!("{error}"))?;
+        *handle = None;
+        let result = database.reload(self.path.clone())?;
+        *handle = Some(result);
+        Ok(AssetResolved::new(result, database))
+    }
 }
 
 impl Clone for AssetRef {
@@ -234,9 +285,7 @@ impl<'a> AssetResolved<'a> {
             .dependencies(self.database)
             .filter_map(|handle| {
                 Some(AssetRef::new_resolved(
-                    handle
-                        .access_checked::<&AssetPathStatic>(self.database)?
-                        .clone(),
+                    handle.lookup::<&AssetPathStatic>(self.database)?.clone(),
                     handle,
                 ))
             })
@@ -246,9 +295,7 @@ impl<'a> AssetResolved<'a> {
     pub fn dependent(&self) -> impl Iterator<Item = AssetRef> + '_ {
         self.handle.dependent(self.database).filter_map(|handle| {
             Some(AssetRef::new_resolved(
-                handle
-                    .access_checked::<&AssetPathStatic>(self.database)?
-                    .clone(),
+                handle.lookup::<&AssetPathStatic>(self.database)?.clone(),
                 handle,
             ))
         })
@@ -260,15 +307,24 @@ impl<'a> AssetResolved<'a> {
             .traverse_dependencies(self.database)
             .filter_map(|handle| {
                 Some(AssetRef::new_resolved(
-                    handle
-                        .access_checked::<&AssetPathStatic>(self.database)?
-                        .clone(),
+                    handle.lookup::<&AssetPathStatic>(self.database)?.clone(),
                     handle,
                 ))
             })
     }
 }
 
+fn release_reference(sender: &AssetDatabaseCommandsSender, handle: AssetHandle) {
+    sender.send(Box::new(move |storage| {
+        if let Ok(mut counter) =
+            storage.component_mut::<true, AssetReferenceCounter>(handle.entity())
+        {
+            counter.decrement();
+            storage.update::<AssetReferenceCounter>(handle.entity());
+        }
+    }));
+}
+
 /// A smart reference to an asset in the asset database.
 /// Uses asset reference counting to ensure asset lifetime.
 pub struct SmartAssetRef {
@@ -279,14 +335,7 @@ pub struct SmartAssetRef {
 impl Drop for SmartAssetRef {
     fn drop(&mut self) {
         if let Ok(handle) = self.inner.handle() {
-            self.sender.send(Box::new(move |storage| {
-                if let Ok(mut counter) =
-                    storage.component_mut::<true, AssetReferenceCounter>(handle.entity())
-                {
-                    counter.decrement();
-                    storage.update::<AssetReferenceCounter>(handle.entity());
-                }
-            }));
+            release_reference(&self.sender, handle);
         }
     }
 }
@@ -368,3 +417,135 @@ impl DerefMut for SmartAssetRef {
         &mut self.inner
     }
 }
+
+/// A lazily pinned reference to an asset in the asset database.
+///
+/// Unlike `SmartAssetRef`, it can be created (or deserialized) from path
+/// alone, and increments asset reference counter only once it gets pinned,
+/// holding that reference until unpinned or dropped. That guarantees pinned
+/// asset survives reference-counted unloading and garbage collection.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(from = "AssetPathStatic", into = "AssetPathStatic")]
+pub struct PinnedAssetRef {
+    inner: AssetRef,
+    #[serde(skip)]
+    sender: RwLock<Option<AssetDatabaseCommandsSender>>,
+}
+
+impl Drop for PinnedAssetRef {
+    fn drop(&mut self) {
+        let _ = self.unpin();
+    }
+}
+
+impl PinnedAssetRef {
+    /// Creates a new unpinned `PinnedAssetRef` with the given asset path.
+    ///
+    /// # Arguments
+    /// - `path`: The path to the asset.
+    ///
+    /// # Returns
+    /// An instance of `PinnedAssetRef`.
+    pub fn new(path: impl Into<AssetPathStatic>) -> Self {
+        Self::from_ref(AssetRef::new(path))
+    }
+
+    /// Creates a new unpinned `PinnedAssetRef` from an existing `AssetRef`.
+    ///
+    /// # Arguments
+    /// - `inner`: The `AssetRef` to create the `PinnedAssetRef` from.
+    ///
+    /// # Returns
+    /// An instance of `PinnedAssetRef`.
+    pub fn from_ref(inner: AssetRef) -> Self {
+        Self {
+            inner,
+            sender: Default::default(),
+        }
+    }
+
+    /// Tells if asset is pinned by this reference.
+    pub fn is_pinned(&self) -> bool {
+        self.sender
+            .read()
+            .map(|sender| sender.is_some())
+            .unwrap_or_default()
+    }
+
+    /// Ensures existence of the asset and pins it by incrementing asset
+    /// reference counter, if not already pinned.
+    ///
+    /// # Arguments
+    /// - `database`: Reference to the `AssetDatabase` to ensure the asset.
+    ///
+    /// # Returns
+    /// An ensured `AssetResolved` object, or an error if resolution fails.
+    pub fn pin<'a>(
+        &'a self,
+        database: &'a mut AssetDatabase,
+    ) -> Result<AssetResolved<'a>, Box<dyn Error>> {
+        let mut sender = self.sender.write().map_err(|error| format!("{error}"))?;
+        let handle = self.inner.ensure(database)?.handle;
+        if sender.is_none() {
+            handle
+                .ensure::<AssetReferenceCounter>(database)?
+                .increment();
+            database
+                .storage
+                .update::<AssetReferenceCounter>(handle.entity());
+            *sender = Some(database.commands_sender());
+        }
+        Ok(AssetResolved::new(handle, database))
+    }
+
+    /// Releases pinned asset by decrementing asset reference counter, if
+    /// pinned. Released asset gets unloaded with next database maintenance
+    /// if no longer referenced.
+    ///
+    /// # Returns
+    /// An error if reference could not be released.
+    pub fn unpin(&self) -> Result<(), Box<dyn Error>> {
+        let sender = self
+            .sender
+            .write()
+            .map_err(|error| format!("{error}"))?
+            .take();
+        if let Some(sender) = sender {
+            release_reference(&sender, self.inner.handle()?);
+        }
+        Ok(())
+    }
+
+    /// Converts the `PinnedAssetRef` into a regular `AssetRef`, unpinning
+    /// asset in the process.
+    pub fn into_ref(self) -> AssetRef {
+        self.inner.clone()
+    }
+}
+
+impl Clone for PinnedAssetRef {
+    /// Clones reference as unpinned, so it has to be pinned on its own.
+    fn clone(&self) -> Self {
+        Self::from_ref(self.inner.clone())
+    }
+}
+
+impl Deref for PinnedAssetRef {
+    type Target = AssetRef;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl From<AssetPathStatic> for PinnedAssetRef {
+    fn from(path: AssetPathStatic) -> Self {
+        Self::new(path)
+    }
+}
+
+impl From<PinnedAssetRef> for AssetPathStatic {
+    fn from(value: PinnedAssetRef) -> Self {
+        value.inner.path.clone()
+    }
+}
@@ -1,5 +1,15 @@
 use std::{borrow::Cow, collections::HashSet};
 
+/// Semantics of matching asset tags against queried tags.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagMatch {
+    /// Asset has to have all queried tags.
+    #[default]
+    All,
+    /// Asset has to have at least one of queried tags.
+    Any,
+}
+
 /// A structure to manage a collection of unique tags associated with assets.
 ///
 /// `AssetTags` provides a set-based implementation to store and query tags.
@@ -123,6 +133,21 @@ impl AssetTags {
     pub fn union(&self, other: &Self) -> Self {
         self.tags.union(&other.tags).cloned().collect()
     }
+
+    /// Checks if this collection matches queried tags.
+    ///
+    /// # Arguments
+    /// - `query`: The queried tags.
+    /// - `mode`: Semantics of matching.
+    ///
+    /// # Returns
+    /// `true` if this collection matches queried tags.
+    pub fn matches(&self, query: &Self, mode: TagMatch) -> bool {
+        match mode {
+            TagMatch::All => self.is_superset_of(query),
+            TagMatch::Any => !self.intersection(query).is_empty(),
+        }
+    }
 }
 
 impl FromIterator<Cow<'static, str>> for AssetTags {
@@ -1,6 +1,11 @@
 use crate::{
-    database::{AssetDatabase, handle::AssetHandle, path::AssetPathStatic},
-    fetch::{AssetAwaitsAsyncFetch, AssetAwaitsResolution, AssetBytesAreReadyToProcess},
+    database::{
+        AssetDatabase, AssetLoadError, handle::AssetHandle, path::AssetPathStatic, sort_by_path,
+    },
+    fetch::{
+        AssetAwaitsAsyncFetch, AssetAwaitsReprocessing, AssetAwaitsResolution,
+        AssetBytesAreReadyToProcess,
+    },
     store::{AssetAwaitsAsyncStore, AssetAwaitsStoring, AssetBytesAreReadyToStore},
 };
 use anput::component::Component;
@@ -86,6 +91,17 @@ impl AssetsTracker {
         self.handles.iter().copied()
     }
 
+    /// Iterate over tracked handles sorted by their asset paths, for
+    /// deterministic output in logs and tests.
+    ///
+    /// # Arguments
+    /// - `database`: A reference to the `AssetDatabase` to read paths from.
+    pub fn iter_sorted(&self, database: &AssetDatabase) -> impl Iterator<Item = AssetHandle> {
+        let mut handles = self.handles.iter().copied().collect::<Vec<_>>();
+        sort_by_path(&database.storage, &mut handles);
+        handles.into_iter()
+    }
+
     /// Reports the status of tracked assets in the database.
     ///
     /// # Arguments
@@ -102,10 +118,14 @@ impl AssetsTracker {
                     out_status.awaiting_async_store.add(*handle);
                 } else if handle.has::<AssetAwaitsResolution>(database) {
                     out_status.awaiting_resolution.add(*handle);
-                } else if handle.has::<AssetBytesAreReadyToProcess>(database) {
+                } else if handle.has::<AssetBytesAreReadyToProcess>(database)
+                    || handle.has::<AssetAwaitsReprocessing>(database)
+                {
                     out_status.with_bytes_ready_to_process.add(*handle);
                 } else if handle.has::<AssetAwaitsAsyncFetch>(database) {
                     out_status.awaiting_async_fetch.add(*handle);
+                } else if handle.has::<AssetLoadError>(database) {
+                    out_status.failed.add(*handle);
                 } else {
                     out_status.ready_to_use.add(*handle);
                 }
@@ -180,6 +200,7 @@ pub struct AssetsStatus {
     pub with_bytes_ready_to_process: AssetsStatusCategory,
     pub awaiting_async_fetch: AssetsStatusCategory,
     pub ready_to_use: AssetsStatusCategory,
+    pub failed: AssetsStatusCategory,
 }
 
 impl AssetsStatus {
@@ -193,6 +214,7 @@ impl AssetsStatus {
             with_bytes_ready_to_process: AssetsStatusCategory::amount(),
             awaiting_async_fetch: AssetsStatusCategory::amount(),
             ready_to_use: AssetsStatusCategory::amount(),
+            failed: AssetsStatusCategory::amount(),
         }
     }
 
@@ -206,15 +228,20 @@ impl AssetsStatus {
             with_bytes_ready_to_process: AssetsStatusCategory::list(),
             awaiting_async_fetch: AssetsStatusCategory::list(),
             ready_to_use: AssetsStatusCategory::list(),
+            failed: AssetsStatusCategory::list(),
         }
     }
 
     /// Clears all categories in status.
     pub fn clear(&mut self) {
+        self.awaiting_storing.clear();
+        self.with_bytes_ready_to_store.clear();
+        self.awaiting_async_store.clear();
         self.awaiting_resolution.clear();
         self.with_bytes_ready_to_process.clear();
         self.awaiting_async_fetch.clear();
         self.ready_to_use.clear();
+        self.failed.clear();
     }
 
     /// Returns the progress of status.
@@ -230,6 +257,23 @@ impl AssetsStatus {
             with_bytes_ready_to_process: self.with_bytes_ready_to_process.len(),
             awaiting_async_fetch: self.awaiting_async_fetch.len(),
             ready_to_use: self.ready_to_use.len(),
+            failed: self.failed.len(),
+        }
+    }
+
+    /// Returns the storing progress of status.
+    ///
+    /// Assets in `ready_to_use` category are considered stored, which is the
+    /// case for status reported by `AssetDatabase::report_storing_status`.
+    ///
+    /// # Returns
+    /// An `AssetsStoringProgress` struct representing the storing progress of status.
+    pub fn storing_progress(&self) -> AssetsStoringProgress {
+        AssetsStoringProgress {
+            awaiting_storing: self.awaiting_storing.len(),
+            with_bytes_ready_to_store: self.with_bytes_ready_to_store.len(),
+            awaiting_async_store: self.awaiting_async_store.len(),
+            stored: self.ready_to_use.len(),
         }
     }
 }
@@ -244,6 +288,7 @@ pub struct AssetsProgress {
     pub with_bytes_ready_to_process: usize,
     pub awaiting_async_fetch: usize,
     pub ready_to_use: usize,
+    pub failed: usize,
 }
 
 impl AssetsProgress {
@@ -257,6 +302,7 @@ impl AssetsProgress {
             with_bytes_ready_to_process: 0,
             awaiting_async_fetch: 0,
             ready_to_use: 0,
+            failed: 0,
         }
     }
 
@@ -270,6 +316,7 @@ impl AssetsProgress {
             with_bytes_ready_to_process: self.with_bytes_ready_to_process,
             awaiting_async_fetch: self.awaiting_async_fetch,
             ready_to_use: self.ready_to_use,
+            failed: self.failed,
         }
     }
 
@@ -282,9 +329,11 @@ impl AssetsProgress {
             + self.with_bytes_ready_to_process
             + self.awaiting_async_fetch
             + self.ready_to_use
+            + self.failed
     }
 
-    /// Tells if progress is complete.
+    /// Tells if progress is complete, where both ready to use and failed
+    /// assets are finished.
     pub fn is_complete(&self) -> bool {
         self.awaiting_storing == 0
             && self.with_bytes_ready_to_store == 0
@@ -299,13 +348,54 @@ impl AssetsProgress {
         !self.is_complete()
     }
 
-    /// Returns the factor of progress (0-1).
+    /// Returns the factor of progress (0-1), counting failed assets as finished.
+    pub fn factor(&self) -> f32 {
+        let total = self.total();
+        if total == 0 {
+            1.0
+        } else {
+            (self.ready_to_use + self.failed) as f32 / total as f32
+        }
+    }
+}
+
+/// A struct to represent storing progress of assets.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AssetsStoringProgress {
+    pub awaiting_storing: usize,
+    pub with_bytes_ready_to_store: usize,
+    pub awaiting_async_store: usize,
+    pub stored: usize,
+}
+
+impl AssetsStoringProgress {
+    /// Returns the total number of assets being stored.
+    pub fn total(&self) -> usize {
+        self.awaiting_storing
+            + self.with_bytes_ready_to_store
+            + self.awaiting_async_store
+            + self.stored
+    }
+
+    /// Tells if storing is complete.
+    pub fn is_complete(&self) -> bool {
+        self.awaiting_storing == 0
+            && self.with_bytes_ready_to_store == 0
+            && self.awaiting_async_store == 0
+    }
+
+    /// Tells if storing is in progress.
+    pub fn is_in_progress(&self) -> bool {
+        !self.is_complete()
+    }
+
+    /// Returns the factor of storing progress (0-1).
     pub fn factor(&self) -> f32 {
         let total = self.total();
         if total == 0 {
             1.0
         } else {
-            self.ready_to_use as f32 / total as f32
+            self.stored as f32 / total as f32
         }
     }
 }
@@ -380,3 +470,137 @@ impl<T: Component + Default> ConsumedSingleAssetLoader<T> {
         }
     }
 }
+
+/// Set of asset components that can be taken out of an asset at once.
+///
+/// Implemented for tuples of up to 8 components.
+pub trait ConsumedAssetBundle: Sized {
+    /// Takes components out of an asset, leaving their default values in place.
+    ///
+    /// # Arguments
+    /// - `database`: The asset database.
+    /// - `handle`: Handle of the asset to consume components from.
+    ///
+    /// # Returns
+    /// Taken components, or an error if asset lacks any of them.
+    fn consume(database: &AssetDatabase, handle: AssetHandle) -> Result<Self, Box<dyn Error>>;
+}
+
+macro_rules! impl_consumed_asset_bundle {
+    ($($type:ident),+) => {
+        impl<$($type: Component + Default),+> ConsumedAssetBundle for ($($type,)+) {
+            fn consume(
+                database: &AssetDatabase,
+                handle: AssetHandle,
+            ) -> Result<Self, Box<dyn Error>> {
+                $(
+                    if !database
+                        .storage
+                        .has_entity_component::<$type>(handle.entity())
+                    {
+                        return Err(format!(
+                            "Asset {} has no {} component",
+                            handle,
+                            std::any::type_name::<$type>()
+                        )
+                        .into());
+                    }
+                )+
+                Ok(($(
+                    std::mem::take(
+                        &mut *database
+                            .storage
+                            .component_mut::<true, $type>(handle.entity())?,
+                    ),
+                )+))
+            }
+        }
+    };
+}
+
+impl_consumed_asset_bundle!(A);
+impl_consumed_asset_bundle!(A, B);
+impl_consumed_asset_bundle!(A, B, C);
+impl_consumed_asset_bundle!(A, B, C, D);
+impl_consumed_asset_bundle!(A, B, C, D, E);
+impl_consumed_asset_bundle!(A, B, C, D, E, F);
+impl_consumed_asset_bundle!(A, B, C, D, E, F, G);
+impl_consumed_asset_bundle!(A, B, C, D, E, F, G, H);
+
+/// Helper type to handle one-shot single asset loading which consumes multiple
+/// components of an asset and deletes it and its dependencies after consumption.
+/// Works like `ConsumedSingleAssetLoader`, but waits for asset to be ready to
+/// use and takes a whole bundle of components, like `(T, U)`.
+pub enum ConsumedAssetLoader<B: ConsumedAssetBundle> {
+    Path(AssetPathStatic),
+    Handle(AssetHandle),
+    Data(B),
+    Error(Box<dyn Error>),
+}
+
+impl<B: ConsumedAssetBundle> ConsumedAssetLoader<B> {
+    /// Creates a new `ConsumedAssetLoader` instance from asset path.
+    pub fn path(path: impl Into<AssetPathStatic>) -> Self {
+        ConsumedAssetLoader::Path(path.into())
+    }
+
+    /// Creates a new `ConsumedAssetLoader` instance from asset handle.
+    pub fn handle(handle: AssetHandle) -> Self {
+        ConsumedAssetLoader::Handle(handle)
+    }
+
+    /// Tells if asset loading is complete (either has consumed data or an error).
+    pub fn is_complete(&self) -> bool {
+        matches!(
+            self,
+            ConsumedAssetLoader::Data(_) | ConsumedAssetLoader::Error(_)
+        )
+    }
+
+    /// Tells if asset loading is in progress (either has path or handle).
+    pub fn is_in_progress(&self) -> bool {
+        matches!(
+            self,
+            ConsumedAssetLoader::Path(_) | ConsumedAssetLoader::Handle(_)
+        )
+    }
+
+    /// Maintains asset loader state by handling asset resolution and consuming
+    /// its components when asset is ready to use.
+    pub fn maintain(&mut self, database: &mut AssetDatabase) {
+        match self {
+            ConsumedAssetLoader::Path(path) => {
+                let handle = match database.ensure(path.clone()) {
+                    Ok(handle) => handle,
+                    Err(err) => {
+                        *self = ConsumedAssetLoader::Error(err);
+                        return;
+                    }
+                };
+                *self = ConsumedAssetLoader::Handle(handle);
+            }
+            ConsumedAssetLoader::Handle(handle) => {
+                let handle = *handle;
+                if !handle.does_exists(database) {
+                    *self = ConsumedAssetLoader::Error(
+                        format!("Asset {handle} got unloaded before consumption").into(),
+                    );
+                    return;
+                }
+                if !handle.is_ready_to_use(database) {
+                    return;
+                }
+                let data = match B::consume(database, handle) {
+                    Ok(data) => data,
+                    Err(err) => {
+                        *self = ConsumedAssetLoader::Error(err);
+                        return;
+                    }
+                };
+                let _ = handle.delete(database);
+                *self = ConsumedAssetLoader::Data(data);
+            }
+            _ => {}
+        }
+    }
+}
@@ -70,7 +70,9 @@ impl<Partial: ContainerPartialFetch> AssetFetch for ContainerAssetFetch<Partial>
             .map_err(|error| format!("{error}"))?
             .load_bytes(path.clone())?;
         let mut bundle = DynamicBundle::default();
-        let _ = bundle.add_component(AssetBytesAreReadyToProcess(bytes));
+        bundle
+            .add_component(AssetBytesAreReadyToProcess(bytes))
+            .map_err(|_| format!("Failed to add bytes to bundle for asset: `{path}`"))?;
         let _ = bundle.add_component(AssetFromContainer);
         Ok(bundle)
     }
@@ -0,0 +1,65 @@
+use crate::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::{AssetBytesAreReadyToProcess, AssetFetch, FetchError},
+};
+use anput::bundle::DynamicBundle;
+use std::{
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+/// A marker component for assets fetched from another asset database.
+pub struct AssetFromDatabase;
+
+/// An implementation of the `AssetFetch` trait that serves bytes produced by
+/// assets of another asset database.
+///
+/// Asset gets looked up under the same path in source database, and its
+/// protocol produces bytes, which allows composing multi-stage pipelines, like
+/// "source" database building assets and "cooked" database loading them.
+/// Source database must not be the one using this fetch, since it gets locked
+/// while producing bytes.
+pub struct DatabaseAssetFetch {
+    database: Arc<RwLock<AssetDatabase>>,
+}
+
+impl DatabaseAssetFetch {
+    /// Creates a new `DatabaseAssetFetch` serving assets of source database.
+    ///
+    /// # Arguments
+    /// - `database`: Shared source asset database.
+    ///
+    /// # Returns
+    /// - A new `DatabaseAssetFetch` instance.
+    pub fn new(database: Arc<RwLock<AssetDatabase>>) -> Self {
+        Self { database }
+    }
+}
+
+impl AssetFetch for DatabaseAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        let path = path.into_static();
+        let mut database = self.database.write().map_err(|error| {
+            format!(
+                "Failed to get write access to source database for asset: `{path}`. Error: {error}"
+            )
+        })?;
+        if database.find(path.clone()).is_none() {
+            return Err(FetchError::NotFound(format!(
+                "Asset `{path}` not found in source database"
+            ))
+            .into());
+        }
+        let bytes = database.produce_bytes(path.clone()).map_err(|error| {
+            format!("Failed to produce bytes of asset: `{path}` in source database. Error: {error}")
+        })?;
+        let mut bundle = DynamicBundle::default();
+        bundle
+            .add_component(AssetBytesAreReadyToProcess(bytes))
+            .map_err(|_| format!("Failed to add bytes to bundle for asset: `{path}`"))?;
+        bundle
+            .add_component(AssetFromDatabase)
+            .map_err(|_| format!("Failed to add marker to bundle for asset: `{path}`"))?;
+        Ok(bundle)
+    }
+}
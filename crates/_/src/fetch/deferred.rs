@@ -1,6 +1,12 @@
 use crate::{
-    database::path::{AssetPath, AssetPathStatic},
-    fetch::{AssetAwaitsAsyncFetch, AssetFetch},
+    database::{
+        handle::AssetHandle,
+        path::{AssetPath, AssetPathStatic},
+    },
+    fetch::{
+        AssetAwaitsAsyncFetch, AssetFetch, AssetFetchMany, FetchError, insert_sub_assets,
+        report_fetch_failure, split_requested,
+    },
 };
 use anput::{
     bundle::DynamicBundle, third_party::intuicio_data::managed::value::ManagedValue, world::World,
@@ -10,22 +16,44 @@ use moirai::{
     jobs::Jobs,
 };
 use std::{
+    any::Any,
     collections::HashMap,
     error::Error,
+    panic::{AssertUnwindSafe, catch_unwind},
     sync::{Arc, RwLock},
 };
 
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic payload".to_owned()
+    }
+}
+
 /// A deferred asset fetcher that queues tasks for loading asset bytes asynchronously
 /// on separate jobs and defers processing until the tasks are completed.
 ///
 /// The `DeferredAssetFetch` struct allows asset fetching to occur in the background
 /// on jobs, with tasks being executed asynchronously and loaded asset bytes
 /// being processed only when the task has finished.
+///
+/// Loads of asset that is already being fetched reuse its in-flight job.
+///
+/// Jobs load assets with inner fetch `load_many`, so sub-assets produced by
+/// inner fetch engine get spawned once their job completes.
+///
+/// Jobs are spawned in order assets get resolved, so assets with higher
+/// `AssetPriority` get their fetch jobs queued first.
 pub struct DeferredAssetFetch<Fetch: AssetFetch> {
     fetch: Arc<RwLock<Fetch>>,
     jobs: ManagedValue<Jobs>,
+    drive_jobs: bool,
     #[allow(clippy::type_complexity)]
-    job_handles: RwLock<HashMap<AssetPathStatic, JobHandle<Result<DynamicBundle, String>>>>,
+    job_handles: RwLock<HashMap<AssetPathStatic, JobHandle<Result<AssetFetchMany, FetchError>>>>,
+    catch_panics: bool,
 }
 
 impl<Fetch: AssetFetch> DeferredAssetFetch<Fetch> {
@@ -40,12 +68,19 @@ impl<Fetch: AssetFetch> DeferredAssetFetch<Fetch> {
         Self {
             fetch: Arc::new(RwLock::new(fetch)),
             jobs: ManagedValue::Owned(Default::default()),
+            drive_jobs: true,
             job_handles: Default::default(),
+            catch_panics: false,
         }
     }
 
     /// Sets the jobs for the deferred asset fetcher.
     ///
+    /// Owned jobs runner gets its local jobs run on every maintenance of this
+    /// fetcher. Jobs runner shared between multiple deferred engines (passed as
+    /// `Managed::lazy()` for example) is not driven by any of them, unless one
+    /// gets marked with `drive_jobs`, so local jobs run only once per tick.
+    ///
     /// # Arguments
     /// - `jobs`: The jobs runner to be managed by the deferred asset fetcher.
     ///
@@ -53,6 +88,39 @@ impl<Fetch: AssetFetch> DeferredAssetFetch<Fetch> {
     /// - A new `DeferredAssetFetch` instance with the updated jobs.
     pub fn jobs(mut self, jobs: impl Into<ManagedValue<Jobs>>) -> Self {
         self.jobs = jobs.into();
+        self.drive_jobs = matches!(self.jobs, ManagedValue::Owned(_));
+        self
+    }
+
+    /// Makes this fetcher run local jobs of its jobs runner on maintenance,
+    /// even if jobs runner is shared. Only one of engines sharing jobs runner
+    /// should drive it.
+    ///
+    /// # Returns
+    /// - A new `DeferredAssetFetch` instance driving its jobs runner.
+    pub fn drive_jobs(mut self) -> Self {
+        self.drive_jobs = true;
+        self
+    }
+
+    /// Returns number of fetches currently running in background jobs.
+    ///
+    /// # Returns
+    /// - Number of in-flight jobs of this engine.
+    pub fn in_flight(&self) -> usize {
+        self.job_handles
+            .read()
+            .map(|job_handles| job_handles.len())
+            .unwrap_or_default()
+    }
+
+    /// Makes panics of inner fetch engine caught and reported as fetch errors
+    /// of the offending asset, including panic message.
+    ///
+    /// # Returns
+    /// - A new `DeferredAssetFetch` instance with panics catching enabled.
+    pub fn catch_panics(mut self) -> Self {
+        self.catch_panics = true;
         self
     }
 }
@@ -60,35 +128,55 @@ impl<Fetch: AssetFetch> DeferredAssetFetch<Fetch> {
 impl<Fetch: AssetFetch> AssetFetch for DeferredAssetFetch<Fetch> {
     fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
         let path = path.into_static();
+        let mut job_handles = self
+            .job_handles
+            .write()
+            .map_err(|error| format!("{error}"))?;
+        let mut bundle = DynamicBundle::default();
+        let _ = bundle.add_component(AssetAwaitsAsyncFetch);
+        // Reuse job already fetching this asset instead of spawning duplicate.
+        if job_handles.contains_key(&path) {
+            return Ok(bundle);
+        }
         let path2 = path.clone();
         let fetch = self.fetch.clone();
+        let catch_panics = self.catch_panics;
         let job = async move {
-            fetch.read().map_err(|error| {
-                format!(
-                    "Failed to get read access to inner fetch engine in async fetch for asset: `{path}`. Error: {error}"
-                )
-            })?.load_bytes(path.clone()).map_err(|error| {
-                format!(
-                    "Failed async fetch for asset: `{path}`. Error: {error}"
-                )
-            })
+            let load = || {
+                fetch.read().map_err(|error| {
+                    FetchError::Other(format!(
+                        "Failed to get read access to inner fetch engine in async fetch for asset: `{path}`. Error: {error}"
+                    ))
+                })?.load_many(path.clone()).map_err(|error| {
+                    FetchError::from_error(
+                        format!("Failed async fetch for asset: `{path}`"),
+                        error.as_ref(),
+                    )
+                })
+            };
+            if catch_panics {
+                catch_unwind(AssertUnwindSafe(load)).unwrap_or_else(|payload| {
+                    Err(FetchError::Other(format!(
+                        "Async fetch for asset: `{path}` panicked: {}",
+                        panic_message(payload.as_ref())
+                    )))
+                })
+            } else {
+                load()
+            }
         };
         let jobs = self.jobs.read().ok_or_else(|| {
             format!("Failed to get read access to jobs runner in async fetch for asset: `{path2}`")
         })?;
         let handle = jobs.spawn(JobLocation::other_than_current_thread(), job);
-        self.job_handles
-            .write()
-            .map_err(|error| format!("{error}"))?
-            .insert(path2, handle);
-        let mut bundle = DynamicBundle::default();
-        let _ = bundle.add_component(AssetAwaitsAsyncFetch);
+        job_handles.insert(path2, handle);
         Ok(bundle)
     }
 
     fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
-        if let ManagedValue::Owned(jobs) = &self.jobs {
-            jobs.read()
+        if self.drive_jobs {
+            self.jobs
+                .read()
                 .ok_or("Failed to get read access to jobs runner in deferred fetch maintainance.")?
                 .run_local();
         }
@@ -98,6 +186,32 @@ impl<Fetch: AssetFetch> AssetFetch for DeferredAssetFetch<Fetch> {
             .map_err(|error| format!("Failed deferred fetch engine maintainance. Error: {error}"))?
             .maintain(storage)?;
 
+        // Assets cancelled or unloaded while still being fetched do not need
+        // their jobs anymore, same as assets that stopped awaiting them.
+        let abandoned = self
+            .job_handles
+            .read()
+            .map_err(|error| format!("{error}"))?
+            .keys()
+            .filter(|path| {
+                storage
+                    .find_by::<true, _>(*path)
+                    .map(|entity| !storage.has_entity_component::<AssetAwaitsAsyncFetch>(entity))
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for path in abandoned {
+            if let Some(handle) = self
+                .job_handles
+                .write()
+                .map_err(|error| format!("{error}"))?
+                .remove(&path)
+            {
+                handle.cancel();
+            }
+        }
+
         let complete = self
             .job_handles
             .read()
@@ -118,11 +232,20 @@ impl<Fetch: AssetFetch> AssetFetch for DeferredAssetFetch<Fetch> {
                     if let Some(entity) = storage.find_by::<true, _>(&path) {
                         storage.remove::<(AssetAwaitsAsyncFetch,)>(entity)?;
                     }
-                    let result = result.map_err(|error| {
-                        format!("Async fetch execution of `{path}` asset panicked! Error: {error}")
-                    })?;
+                    if let Err(error) = result.as_ref()
+                        && let Some(entity) = storage.find_by::<true, _>(&path)
+                    {
+                        report_fetch_failure(
+                            storage,
+                            AssetHandle::new(entity),
+                            path.clone(),
+                            error,
+                        )?;
+                    }
+                    let (bundle, sub_assets) = split_requested(&path, result?)?;
                     if let Some(entity) = storage.find_by::<true, _>(&path) {
-                        storage.insert(entity, result)?;
+                        storage.insert(entity, bundle)?;
+                        insert_sub_assets(storage, entity, sub_assets)?;
                     }
                 }
                 JobResult::Cancelled | JobResult::Consumed => {
@@ -1,6 +1,6 @@
 use crate::{
     database::path::{AssetPath, AssetPathStatic},
-    fetch::AssetFetch,
+    fetch::{AssetFetch, AssetFetchMany},
 };
 use anput::{bundle::DynamicBundle, world::World};
 use std::error::Error;
@@ -62,27 +62,47 @@ impl<Fetch: AssetFetch> FallbackAssetFetch<Fetch> {
         self.factories.push(Box::new(factory));
         self
     }
+
+    fn load_fallback(
+        &self,
+        path: &AssetPath,
+        mut status: Result<DynamicBundle, Box<dyn Error>>,
+    ) -> Result<DynamicBundle, Box<dyn Error>> {
+        for factory in &self.factories {
+            if let Some(bundle) = factory(path) {
+                return Ok(bundle);
+            }
+        }
+        for asset in &self.assets {
+            if asset.protocol() == path.protocol() {
+                status = self.fetch.load_bytes(asset.clone());
+                if status.is_ok() {
+                    break;
+                }
+            }
+        }
+        status
+    }
 }
 
 impl<Fetch: AssetFetch> AssetFetch for FallbackAssetFetch<Fetch> {
     fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
-        let mut status = self.fetch.load_bytes(path.clone());
+        let status = self.fetch.load_bytes(path.clone());
         if status.is_err() {
-            for factory in &self.factories {
-                if let Some(bundle) = factory(&path) {
-                    return Ok(bundle);
-                }
-            }
-            for asset in &self.assets {
-                if asset.protocol() == path.protocol() {
-                    status = self.fetch.load_bytes(asset.clone());
-                    if status.is_ok() {
-                        break;
-                    }
-                }
+            self.load_fallback(&path, status)
+        } else {
+            status
+        }
+    }
+
+    fn load_many(&self, path: AssetPath) -> Result<AssetFetchMany, Box<dyn Error>> {
+        match self.fetch.load_many(path.clone()) {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let bundle = self.load_fallback(&path, Err(error))?;
+                Ok(vec![(path.into_static(), bundle)])
             }
         }
-        status
     }
 
     fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
@@ -1,14 +1,109 @@
 use crate::{
     database::path::AssetPath,
-    fetch::{AssetBytesAreReadyToProcess, AssetFetch},
+    fetch::{
+        AssetByteRange, AssetBytesAreReadyToProcess, AssetFetch, FetchError,
+        pool::{AssetBufferPool, AssetBytesFromPool},
+    },
 };
 use anput::bundle::DynamicBundle;
-use std::{error::Error, path::PathBuf};
+use std::{
+    error::Error,
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+fn list_directory(
+    directory: &Path,
+    root: &Path,
+    recursive: bool,
+    output: &mut Vec<String>,
+) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if recursive {
+                list_directory(&path, root, recursive, output)?;
+            }
+        } else {
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            output.push(
+                relative
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            );
+        }
+    }
+    Ok(())
+}
 
-fn load_file_bundle(file_path: PathBuf) -> Result<DynamicBundle, Box<dyn Error>> {
-    let bytes = std::fs::read(&file_path)
-        .map_err(|error| format!("Failed to load `{file_path:?}` file bytes: {error}"))?;
-    let metadata = std::fs::metadata(&file_path)?;
+fn read_file_range(file_path: &Path, range: &AssetByteRange) -> std::io::Result<Vec<u8>> {
+    let mut file = File::open(file_path)?;
+    file.seek(SeekFrom::Start(range.0.start))?;
+    let mut bytes = Vec::default();
+    file.take(range.0.end - range.0.start)
+        .read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_file_pooled(file_path: &Path, pool: &AssetBufferPool) -> std::io::Result<Vec<u8>> {
+    let mut file = File::open(file_path)?;
+    let size = file.metadata()?.len() as usize;
+    let mut bytes = pool.acquire(size);
+    if let Err(error) = file.read_to_end(&mut bytes) {
+        pool.release(bytes);
+        return Err(error);
+    }
+    Ok(bytes)
+}
+
+fn load_file_bundle(
+    file_path: PathBuf,
+    root: &Path,
+    path: &AssetPath,
+    pool: Option<&AssetBufferPool>,
+) -> Result<DynamicBundle, Box<dyn Error>> {
+    let range = AssetByteRange::from_path(path)?;
+    let bytes = if file_path.is_dir() {
+        // Directories produce sorted listing of their files, one per line.
+        let mut listing = Vec::default();
+        list_directory(
+            &file_path,
+            root,
+            path.has_meta_key("recursive"),
+            &mut listing,
+        )
+        .map_err(|error| {
+            FetchError::from_io(format!("Failed to list `{file_path:?}` directory"), &error)
+        })?;
+        listing.sort();
+        listing.join("\n").into_bytes()
+    } else if let Some(range) = range.as_ref() {
+        read_file_range(&file_path, range).map_err(|error| {
+            FetchError::from_io(
+                format!("Failed to load `{file_path:?}` file bytes range"),
+                &error,
+            )
+        })?
+    } else if let Some(pool) = pool {
+        read_file_pooled(&file_path, pool).map_err(|error| {
+            FetchError::from_io(format!("Failed to load `{file_path:?}` file bytes"), &error)
+        })?
+    } else {
+        std::fs::read(&file_path).map_err(|error| {
+            FetchError::from_io(format!("Failed to load `{file_path:?}` file bytes"), &error)
+        })?
+    };
+    let metadata = std::fs::metadata(&file_path).map_err(|error| {
+        FetchError::from_io(
+            format!("Failed to read `{file_path:?}` file metadata"),
+            &error,
+        )
+    })?;
+    let modified = metadata.modified().ok().map(FileModifiedTime);
     let mut bundle = DynamicBundle::default();
     bundle
         .add_component(AssetBytesAreReadyToProcess(bytes))
@@ -22,28 +117,80 @@ fn load_file_bundle(file_path: PathBuf) -> Result<DynamicBundle, Box<dyn Error>>
     bundle.add_component(file_path.clone()).map_err(|_| {
         format!("Failed to add file system path to bundle for asset file: {file_path:?}")
     })?;
+    if let Some(modified) = modified {
+        bundle.add_component(modified).map_err(|_| {
+            format!("Failed to add modification time to bundle for asset file: {file_path:?}")
+        })?;
+    }
+    if let Some(range) = range {
+        bundle.add_component(range).map_err(|_| {
+            format!("Failed to add byte range to bundle for asset file: {file_path:?}")
+        })?;
+    } else if let Some(pool) = pool
+        && !file_path.is_dir()
+    {
+        bundle
+            .add_component(AssetBytesFromPool(pool.clone()))
+            .map_err(|_| {
+                format!("Failed to add buffer pool to bundle for asset file: {file_path:?}")
+            })?;
+    }
     Ok(bundle)
 }
 
 /// Marker component for assets that originate from files.
 pub struct AssetFromFile;
 
+/// Component holding modification time of asset file, at the moment it was
+/// loaded. Cheaper to compare than whole file metadata when detecting changes.
+/// Not present on platforms that do not report modification time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileModifiedTime(pub SystemTime);
+
+impl FileModifiedTime {
+    /// Tells if file at given path was modified since this time was taken.
+    /// Files that cannot be inspected are reported as modified.
+    ///
+    /// # Arguments
+    /// - `path`: File system path of asset file.
+    pub fn is_outdated(&self, path: &Path) -> bool {
+        std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .map(|modified| modified != self.0)
+            .unwrap_or(true)
+    }
+}
+
 /// An implementation of the `AssetFetch` trait that loads assets from the
 /// file system using absolute paths.
+///
+/// Directory paths produce sorted listing of absolute file paths, one per
+/// line, including files of subdirectories if path has `recursive` meta.
 #[derive(Debug, Default, Clone)]
 pub struct AbsoluteFileAssetFetch;
 
 impl AssetFetch for AbsoluteFileAssetFetch {
     fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
-        load_file_bundle(PathBuf::from(path.path()))
+        load_file_bundle(PathBuf::from(path.path()), Path::new(""), &path, None)
     }
 }
 
 /// An implementation of the `AssetFetch` trait that loads assets from the
 /// file system using specified root path.
+///
+/// Directory paths produce sorted listing of file paths relative to root,
+/// one per line, including files of subdirectories if path has `recursive`
+/// meta.
+///
+/// With buffer pool set, file bytes are read into pooled buffers and assets
+/// get marked with `AssetBytesFromPool`. Protocols not keeping asset bytes
+/// (like built-in text protocols) release them back to the pool after
+/// decoding, so loading many small files allocates one buffer per distinct
+/// file size class instead of one buffer per file.
 #[derive(Debug, Default, Clone)]
 pub struct FileAssetFetch {
     pub root: PathBuf,
+    buffer_pool: Option<AssetBufferPool>,
 }
 
 impl FileAssetFetch {
@@ -58,10 +205,27 @@ impl FileAssetFetch {
         self.root = root.into();
         self
     }
+
+    /// Sets buffer pool used to read file bytes into.
+    ///
+    /// # Arguments
+    /// - `pool`: Buffer pool to read file bytes into.
+    ///
+    /// # Returns
+    /// - A modified `FileAssetFetch` instance reading into pooled buffers.
+    pub fn with_buffer_pool(mut self, pool: AssetBufferPool) -> Self {
+        self.buffer_pool = Some(pool);
+        self
+    }
 }
 
 impl AssetFetch for FileAssetFetch {
     fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
-        load_file_bundle(self.root.join(path.path()))
+        load_file_bundle(
+            self.root.join(path.path()),
+            &self.root,
+            &path,
+            self.buffer_pool.as_ref(),
+        )
     }
 }
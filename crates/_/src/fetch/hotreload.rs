@@ -1,28 +1,70 @@
 use crate::{
     database::path::AssetPath,
-    fetch::{AssetAwaitsResolution, AssetFetch, file::FileAssetFetch},
+    fetch::{
+        AssetAwaitsResolution, AssetFetch, AssetFetchMany, AssetSourceChanged,
+        file::{FileAssetFetch, FileModifiedTime},
+    },
 };
 use anput::{
     bundle::DynamicBundle, entity::Entity, query::Update,
     third_party::intuicio_data::type_hash::TypeHash, world::World,
 };
-use notify::{Config, Event, PollWatcher, RecursiveMode, Result as NotifyResult, Watcher};
+use notify::{
+    Config, Event, PollWatcher, RecommendedWatcher, RecursiveMode, Result as NotifyResult, Watcher,
+};
 use std::{
+    collections::HashMap,
     error::Error,
     path::PathBuf,
     sync::{
         Mutex,
         mpsc::{Receiver, channel},
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
+fn refresh_paths(storage: &mut World, paths: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+    // Spurious file system events, which do not change modification time of
+    // a file, do not need reloading of its asset.
+    let to_refresh = storage
+        .query::<true, (
+            Entity,
+            &PathBuf,
+            Option<&FileModifiedTime>,
+            Update<AssetPath>,
+        )>()
+        .filter(|(_, path, modified, _)| {
+            paths.contains(path)
+                && modified
+                    .map(|modified| modified.is_outdated(path))
+                    .unwrap_or(true)
+        })
+        .inspect(|(_, _, _, path)| path.notify(storage))
+        .map(|(entity, _, _, _)| entity)
+        .collect::<Vec<_>>();
+    for entity in to_refresh {
+        let columns = storage
+            .row::<true>(entity)?
+            .columns()
+            .filter(|info| info.type_hash() != TypeHash::of::<AssetPath>())
+            .cloned()
+            .collect::<Vec<_>>();
+        storage.remove_raw(entity, columns)?;
+        storage.insert(entity, (AssetAwaitsResolution, AssetSourceChanged))?;
+    }
+    Ok(())
+}
+
 /// A file asset fetcher with hot reload capabilities.
 /// This fetcher watches a specified directory for file changes and reloads affected assets on modification.
+/// Changes can be detected either by polling file system, or by native
+/// file system events.
 pub struct HotReloadFileAssetFetch {
     fetch: FileAssetFetch,
     rx: Mutex<Receiver<NotifyResult<Event>>>,
-    _watcher: PollWatcher,
+    _watcher: Mutex<Box<dyn Watcher + Send>>,
+    debounce: Option<Duration>,
+    pending: HashMap<PathBuf, Instant>,
 }
 
 impl HotReloadFileAssetFetch {
@@ -36,15 +78,54 @@ impl HotReloadFileAssetFetch {
     /// - An error if the watcher fails to initialize.
     pub fn new(fetch: FileAssetFetch, poll_interval: Duration) -> Result<Self, Box<dyn Error>> {
         let (tx, rx) = channel::<NotifyResult<Event>>();
-        let mut watcher =
-            PollWatcher::new(tx, Config::default().with_poll_interval(poll_interval))?;
+        let watcher = PollWatcher::new(tx, Config::default().with_poll_interval(poll_interval))?;
+        Self::with_watcher(fetch, rx, Box::new(watcher))
+    }
+
+    /// Creates a new `HotReloadFileAssetFetch` with the specified file fetcher,
+    /// that uses platform native (event-driven) file system watcher instead
+    /// of polling.
+    ///
+    /// # Arguments
+    /// - `fetch`: A `FileAssetFetch` that defines the root directory to watch and the logic for loading asset bytes.
+    ///
+    /// # Returns
+    /// - A new `HotReloadFileAssetFetch` instance if initialization succeeds.
+    /// - An error if the watcher fails to initialize.
+    pub fn new_native(fetch: FileAssetFetch) -> Result<Self, Box<dyn Error>> {
+        let (tx, rx) = channel::<NotifyResult<Event>>();
+        let watcher = RecommendedWatcher::new(tx, Config::default())?;
+        Self::with_watcher(fetch, rx, Box::new(watcher))
+    }
+
+    fn with_watcher(
+        fetch: FileAssetFetch,
+        rx: Receiver<NotifyResult<Event>>,
+        mut watcher: Box<dyn Watcher + Send>,
+    ) -> Result<Self, Box<dyn Error>> {
         watcher.watch(&fetch.root, RecursiveMode::Recursive)?;
         Ok(Self {
             fetch,
             rx: Mutex::new(rx),
-            _watcher: watcher,
+            _watcher: Mutex::new(watcher),
+            debounce: None,
+            pending: Default::default(),
         })
     }
+
+    /// Sets debounce window for file changes.
+    /// Assets get reloaded only after their file was not modified for the
+    /// duration of debounce window, so bursts of writes trigger single reload.
+    ///
+    /// # Arguments
+    /// - `debounce`: Duration of debounce window.
+    ///
+    /// # Returns
+    /// - The updated `HotReloadFileAssetFetch` instance.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = Some(debounce);
+        self
+    }
 }
 
 impl AssetFetch for HotReloadFileAssetFetch {
@@ -52,27 +133,39 @@ impl AssetFetch for HotReloadFileAssetFetch {
         self.fetch.load_bytes(path)
     }
 
+    fn load_many(&self, path: AssetPath) -> Result<AssetFetchMany, Box<dyn Error>> {
+        self.fetch.load_many(path)
+    }
+
     fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
-        let rx = self.rx.lock().map_err(|error| format!("{error}"))?;
-        while let Ok(Ok(event)) = rx.try_recv() {
-            if event.kind.is_modify() && !event.paths.is_empty() {
-                let to_refresh = storage
-                    .query::<true, (Entity, &PathBuf, Update<AssetPath>)>()
-                    .filter(|(_, path, _)| event.paths.contains(path))
-                    .inspect(|(_, _, path)| path.notify(storage))
-                    .map(|(entity, _, _)| entity)
-                    .collect::<Vec<_>>();
-                for entity in to_refresh {
-                    let columns = storage
-                        .row::<true>(entity)?
-                        .columns()
-                        .filter(|info| info.type_hash() != TypeHash::of::<AssetPath>())
-                        .cloned()
-                        .collect::<Vec<_>>();
-                    storage.remove_raw(entity, columns)?;
-                    storage.insert(entity, (AssetAwaitsResolution,))?;
+        let mut modified = Vec::default();
+        {
+            let rx = self.rx.lock().map_err(|error| format!("{error}"))?;
+            while let Ok(Ok(event)) = rx.try_recv() {
+                if event.kind.is_modify() {
+                    modified.extend(event.paths);
+                }
+            }
+        }
+        if let Some(debounce) = self.debounce {
+            let now = Instant::now();
+            for path in modified {
+                self.pending.insert(path, now);
+            }
+            let quiet = self
+                .pending
+                .iter()
+                .filter(|(_, timestamp)| now.duration_since(**timestamp) >= debounce)
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>();
+            if !quiet.is_empty() {
+                for path in &quiet {
+                    self.pending.remove(path);
                 }
+                refresh_paths(storage, &quiet)?;
             }
+        } else if !modified.is_empty() {
+            refresh_paths(storage, &modified)?;
         }
         self.fetch.maintain(storage)
     }
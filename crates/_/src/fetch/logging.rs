@@ -0,0 +1,145 @@
+use crate::{
+    database::path::{AssetPath, AssetPathStatic},
+    fetch::{AssetBytesAreReadyToProcess, AssetFetch},
+};
+use anput::{bundle::DynamicBundle, world::World};
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Single recorded call of inner fetch engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetFetchLogEntry {
+    /// Path of fetched asset.
+    pub path: AssetPathStatic,
+    /// Number of fetched bytes, if fetch provided them right away.
+    pub bytes_len: Option<usize>,
+    /// Duration of fetch call.
+    pub elapsed: Duration,
+    /// Result of fetch call, with error message if it failed.
+    pub result: Result<(), String>,
+}
+
+/// Shared log of fetch calls.
+pub type AssetFetchLog = Arc<Mutex<Vec<AssetFetchLogEntry>>>;
+
+/// A wrapper for an [`AssetFetch`] implementation that records every fetch
+/// call into shared log, without changing fetch behavior.
+///
+/// Useful for debugging and reproducing non-deterministic loads. Fetches that
+/// deliver bytes later, like deferred ones, have no bytes length recorded.
+pub struct LoggingAssetFetch<Fetch: AssetFetch> {
+    fetch: Fetch,
+    log: AssetFetchLog,
+}
+
+impl<Fetch: AssetFetch> LoggingAssetFetch<Fetch> {
+    /// Creates a new `LoggingAssetFetch` wrapping inner fetch engine.
+    ///
+    /// # Arguments
+    /// - `fetch`: The inner fetch engine.
+    ///
+    /// # Returns
+    /// - A new `LoggingAssetFetch` instance.
+    pub fn new(fetch: Fetch) -> Self {
+        Self {
+            fetch,
+            log: Default::default(),
+        }
+    }
+
+    /// Sets shared log to record fetch calls into.
+    ///
+    /// # Arguments
+    /// - `log`: The shared log.
+    ///
+    /// # Returns
+    /// - The `LoggingAssetFetch` instance with the log set.
+    pub fn with_log(mut self, log: AssetFetchLog) -> Self {
+        self.log = log;
+        self
+    }
+
+    /// Returns shared log of fetch calls.
+    pub fn log(&self) -> AssetFetchLog {
+        self.log.clone()
+    }
+}
+
+fn bundle_bytes_len(bundle: &mut DynamicBundle) -> Option<usize> {
+    let bytes = bundle.remove_component::<AssetBytesAreReadyToProcess>()?;
+    let len = bytes.0.len();
+    let _ = bundle.add_component(bytes);
+    Some(len)
+}
+
+impl<Fetch: AssetFetch> LoggingAssetFetch<Fetch> {
+    fn record(
+        &self,
+        path: AssetPathStatic,
+        bytes_len: Option<usize>,
+        elapsed: Duration,
+        result: Result<(), String>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.log
+            .lock()
+            .map_err(|error| format!("Failed to record fetch of asset: `{path}`. Error: {error}"))?
+            .push(AssetFetchLogEntry {
+                path,
+                bytes_len,
+                elapsed,
+                result,
+            });
+        Ok(())
+    }
+}
+
+impl<Fetch: AssetFetch> AssetFetch for LoggingAssetFetch<Fetch> {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        let timer = Instant::now();
+        let mut result = self.fetch.load_bytes(path.clone());
+        let elapsed = timer.elapsed();
+        let bytes_len = result.as_mut().ok().and_then(bundle_bytes_len);
+        self.record(
+            path.into_static(),
+            bytes_len,
+            elapsed,
+            result
+                .as_ref()
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+        )?;
+        result
+    }
+
+    fn load_many(
+        &self,
+        path: AssetPath,
+    ) -> Result<Vec<(AssetPathStatic, DynamicBundle)>, Box<dyn Error>> {
+        let timer = Instant::now();
+        let mut result = self.fetch.load_many(path.clone());
+        let elapsed = timer.elapsed();
+        let bytes_len = result.as_mut().ok().and_then(|bundles| {
+            bundles
+                .iter_mut()
+                .find(|(item, _)| item.content() == path.content())
+                .and_then(|(_, bundle)| bundle_bytes_len(bundle))
+        });
+        self.record(
+            path.into_static(),
+            bytes_len,
+            elapsed,
+            result
+                .as_ref()
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+        )?;
+        result
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        self.fetch.maintain(storage)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    database::path::AssetPath,
+    fetch::{AssetFetch, AssetFetchMany, rename_requested},
+};
+use anput::{bundle::DynamicBundle, world::World};
+use std::{collections::HashMap, error::Error, path::Path};
+
+/// A wrapper for an [`AssetFetch`] implementation that maps logical asset
+/// paths to real ones listed in a build-time manifest, before delegating to
+/// the inner fetcher.
+///
+/// Useful for web deployments with fingerprinted file names used for cache
+/// busting, where manifest is a JSON object mapping logical paths to hashed
+/// ones, for example: `{ "app.js": "app.abc123.js" }`.
+///
+/// Only path part of the asset path is mapped, protocol and meta are kept.
+pub struct ManifestAssetFetch<Fetch: AssetFetch> {
+    fetch: Fetch,
+    entries: HashMap<String, String>,
+    strict: bool,
+}
+
+impl<Fetch: AssetFetch> ManifestAssetFetch<Fetch> {
+    /// Creates a new instance of [`ManifestAssetFetch`] from JSON manifest
+    /// content.
+    ///
+    /// # Arguments
+    /// - `fetch`: The inner fetcher that handles asset fetching.
+    /// - `manifest`: JSON object mapping logical paths to real paths.
+    ///
+    /// # Returns
+    /// A new [`ManifestAssetFetch`] instance, or an error if manifest is malformed.
+    pub fn new(fetch: Fetch, manifest: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let entries = serde_json::from_slice::<HashMap<String, String>>(manifest)
+            .map_err(|error| format!("Malformed assets manifest. Error: {error}"))?;
+        Ok(Self::from_entries(fetch, entries))
+    }
+
+    /// Creates a new instance of [`ManifestAssetFetch`] from JSON manifest file.
+    ///
+    /// # Arguments
+    /// - `fetch`: The inner fetcher that handles asset fetching.
+    /// - `path`: File system path to JSON manifest file.
+    ///
+    /// # Returns
+    /// A new [`ManifestAssetFetch`] instance, or an error if manifest could
+    /// not be read or is malformed.
+    pub fn from_file(fetch: Fetch, path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        let manifest = std::fs::read(path)
+            .map_err(|error| format!("Failed to read assets manifest: {path:?}. Error: {error}"))?;
+        Self::new(fetch, &manifest)
+    }
+
+    /// Creates a new instance of [`ManifestAssetFetch`] from manifest entries.
+    ///
+    /// # Arguments
+    /// - `fetch`: The inner fetcher that handles asset fetching.
+    /// - `entries`: Pairs of logical and real paths.
+    ///
+    /// # Returns
+    /// A new [`ManifestAssetFetch`] instance.
+    pub fn from_entries(
+        fetch: Fetch,
+        entries: impl IntoIterator<Item = (impl ToString, impl ToString)>,
+    ) -> Self {
+        Self {
+            fetch,
+            entries: entries
+                .into_iter()
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect(),
+            strict: false,
+        }
+    }
+
+    /// Makes fetching assets missing in manifest fail, instead of falling back
+    /// to their original paths.
+    ///
+    /// # Returns
+    /// The updated [`ManifestAssetFetch`] instance.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Gets real path mapped to logical path in manifest.
+    ///
+    /// # Arguments
+    /// - `path`: Logical path.
+    ///
+    /// # Returns
+    /// Real path if manifest has entry for logical path.
+    pub fn get(&self, path: &str) -> Option<&str> {
+        self.entries.get(path).map(|path| path.as_str())
+    }
+
+    fn map_path<'a>(&self, path: AssetPath<'a>) -> Result<AssetPath<'a>, Box<dyn Error>> {
+        if let Some(real) = self.get(path.path()) {
+            Ok(AssetPath::from_parts_with_fragment(
+                path.protocol(),
+                real,
+                path.meta(),
+                path.fragment(),
+            ))
+        } else if self.strict {
+            Err(format!("Asset `{path}` is missing in assets manifest").into())
+        } else {
+            Ok(path)
+        }
+    }
+}
+
+impl<Fetch: AssetFetch> AssetFetch for ManifestAssetFetch<Fetch> {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        self.fetch.load_bytes(self.map_path(path)?)
+    }
+
+    fn load_many(&self, path: AssetPath) -> Result<AssetFetchMany, Box<dyn Error>> {
+        let real = self.map_path(path.clone())?;
+        Ok(rename_requested(
+            self.fetch.load_many(real.clone())?,
+            &real,
+            &path,
+        ))
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        self.fetch.maintain(storage)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod collections;
 pub mod container;
+pub mod database;
 pub mod deferred;
 pub mod extract;
 pub mod fallback;
@@ -7,17 +8,110 @@ pub mod file;
 pub mod future;
 #[cfg(feature = "hotreload")]
 pub mod hotreload;
+pub mod logging;
+#[cfg(feature = "manifest")]
+pub mod manifest;
+pub mod pool;
+pub mod reader;
 pub mod rewrite;
 pub mod router;
+pub mod stream;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod throttled;
+#[cfg(feature = "verify")]
+pub mod verify;
 
 use crate::database::{
+    AssetLoadError, event_path,
     events::{AssetEvent, AssetEventBindings, AssetEventKind},
-    handle::AssetHandle,
-    path::AssetPath,
+    handle::{AssetDependency, AssetHandle},
+    path::{AssetPath, AssetPathStatic},
 };
-use anput::{bundle::DynamicBundle, world::World};
-use std::error::Error;
+use anput::{bundle::DynamicBundle, entity::Entity, world::World};
+use std::{error::Error, fmt::Display, io::ErrorKind, ops::Range};
+
+/// Typed error of fetch engines, allowing to tell apart failure reasons.
+///
+/// Fetch engines return it boxed as regular error, so it can be recovered
+/// with `error.downcast_ref::<FetchError>()`. Asynchronous fetch engines keep
+/// it across job boundaries, with errors of other types turned into `Other`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchError {
+    /// Asset source does not exist.
+    NotFound(String),
+    /// Access to asset source was denied.
+    PermissionDenied(String),
+    /// Reading asset source failed.
+    Io(String),
+    /// Network transfer of asset source failed.
+    Network(String),
+    /// Any other failure.
+    Other(String),
+}
+
+impl FetchError {
+    /// Creates fetch error from I/O error, mapping its kind to variant.
+    ///
+    /// # Arguments
+    /// - `context`: Description of failed operation, put before error message.
+    /// - `error`: The I/O error.
+    ///
+    /// # Returns
+    /// A new `FetchError` instance.
+    pub fn from_io(context: impl Display, error: &std::io::Error) -> Self {
+        let message = format!("{context}: {error}");
+        match error.kind() {
+            ErrorKind::NotFound => Self::NotFound(message),
+            ErrorKind::PermissionDenied => Self::PermissionDenied(message),
+            _ => Self::Io(message),
+        }
+    }
+
+    /// Creates fetch error from any error, keeping its variant if it is fetch
+    /// error already, or making it `Other` otherwise.
+    ///
+    /// # Arguments
+    /// - `context`: Description of failed operation, put before error message.
+    /// - `error`: The error.
+    ///
+    /// # Returns
+    /// A new `FetchError` instance.
+    pub fn from_error(context: impl Display, error: &(dyn Error + 'static)) -> Self {
+        let message = format!("{context}: {error}");
+        match error.downcast_ref::<Self>() {
+            Some(Self::NotFound(_)) => Self::NotFound(message),
+            Some(Self::PermissionDenied(_)) => Self::PermissionDenied(message),
+            Some(Self::Io(_)) => Self::Io(message),
+            Some(Self::Network(_)) => Self::Network(message),
+            Some(Self::Other(_)) | None => Self::Other(message),
+        }
+    }
+
+    /// Returns error message.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::NotFound(message)
+            | Self::PermissionDenied(message)
+            | Self::Io(message)
+            | Self::Network(message)
+            | Self::Other(message) => message,
+        }
+    }
+
+    /// Tells if asset source does not exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound(_))
+    }
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl Error for FetchError {}
 
 /// Marker type for assets that are awaiting resolution of their path.
 pub struct AssetAwaitsResolution;
@@ -30,8 +124,105 @@ pub struct AssetBytesAreReadyToProcess(pub Vec<u8>);
 /// asynchronous and it's pending completion.
 pub struct AssetAwaitsAsyncFetch;
 
+/// Marker component used to signify that source of the asset has changed
+/// and asset was scheduled for reload (for example by hot reloading).
+pub struct AssetSourceChanged;
+
+/// Marker component used to request processing of already loaded asset
+/// again, from its retained `AssetSourceBytes`.
+///
+/// Protocols can insert it into dependent assets once their dependencies
+/// finish loading, instead of polling for them on their own. Reprocessing
+/// requires `AssetDatabase::with_retain_source_bytes` to be enabled.
+pub struct AssetAwaitsReprocessing;
+
+/// Copy of asset bytes as they were fetched, before protocol processed them.
+/// Retained only when `AssetDatabase::with_retain_source_bytes` is enabled.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AssetSourceBytes(pub Vec<u8>);
+
+/// Range of asset bytes to fetch, instead of whole content.
+///
+/// Requested with `range` asset path meta, with inclusive end like in HTTP
+/// `Range` header: `bytes://big.dat?range=0-1023` fetches first 1024 bytes,
+/// while `bytes://big.dat?range=1024-` fetches everything after them.
+/// Fetch engines supporting ranges put it into fetched asset bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssetByteRange(pub Range<u64>);
+
+impl AssetByteRange {
+    /// Parses byte range from `range` meta of asset path.
+    ///
+    /// # Arguments
+    /// - `path`: The asset path.
+    ///
+    /// # Returns
+    /// - `Ok(Some(AssetByteRange))` if path requests byte range.
+    /// - `Ok(None)` if path does not request byte range.
+    /// - An error if range is malformed.
+    pub fn from_path(path: &AssetPath) -> Result<Option<Self>, Box<dyn Error>> {
+        let Some(range) = path.meta_value("range") else {
+            return Ok(None);
+        };
+        let malformed = || format!("Malformed byte range: `{range}` of asset: `{path}`");
+        let (start, end) = range.split_once('-').ok_or_else(malformed)?;
+        let start = start.trim().parse::<u64>().map_err(|_| malformed())?;
+        let end = if end.trim().is_empty() {
+            u64::MAX
+        } else {
+            end.trim()
+                .parse::<u64>()
+                .map_err(|_| malformed())?
+                .saturating_add(1)
+        };
+        if end <= start {
+            return Err(malformed().into());
+        }
+        Ok(Some(Self(start..end)))
+    }
+
+    /// Tells if range has no end and spans until end of content.
+    pub fn is_open(&self) -> bool {
+        self.0.end == u64::MAX
+    }
+
+    /// Returns value of HTTP `Range` header requesting this range.
+    pub fn http_header(&self) -> String {
+        if self.is_open() {
+            format!("bytes={}-", self.0.start)
+        } else {
+            format!("bytes={}-{}", self.0.start, self.0.end - 1)
+        }
+    }
+
+    /// Returns part of bytes covered by this range, clamped to bytes length.
+    ///
+    /// # Arguments
+    /// - `bytes`: Whole content bytes.
+    pub fn slice<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        let len = bytes.len() as u64;
+        let start = self.0.start.min(len) as usize;
+        let end = self.0.end.min(len) as usize;
+        &bytes[start..end]
+    }
+}
+
+/// Marker component used to signify that the asset was only prefetched and
+/// its bytes should not be processed until asset gets ensured.
+pub struct AssetPrefetched;
+
 /// Defines the interface for fetching asset data from an external source.
 pub trait AssetFetch: Send + Sync + 'static {
+    /// Returns the name of the fetch engine.
+    ///
+    /// Used for diagnostics, like logs and editor panels.
+    ///
+    /// # Returns
+    /// - Name of the fetch engine, by default its type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
     /// Loads the raw bytes of an asset given its path.
     ///
     /// # Arguments
@@ -41,6 +232,25 @@ pub trait AssetFetch: Send + Sync + 'static {
     /// - A `DynamicBundle` containing the asset data or an error if loading fails.
     fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>>;
 
+    /// Loads multiple logical assets from single asset source, for example
+    /// sprite atlases or sound banks packing many assets into one file.
+    ///
+    /// Result has to contain bundle for requested asset path, while other
+    /// entries become sub-assets that are spawned (or updated) as
+    /// dependencies of requested asset. By default only requested asset is
+    /// loaded with `load_bytes`. Fetch engines wrapping other fetch engines
+    /// should forward it to their inner engine.
+    ///
+    /// # Arguments
+    /// - `path`: The path to the asset.
+    ///
+    /// # Returns
+    /// - List of asset paths with their `DynamicBundle`s or an error if loading fails.
+    fn load_many(&self, path: AssetPath) -> Result<AssetFetchMany, Box<dyn Error>> {
+        let bundle = self.load_bytes(path.clone())?;
+        Ok(vec![(path.into_static(), bundle)])
+    }
+
     /// Maintains the fetcher's state.
     ///
     /// Can be used for handling periodic or deferred operations.
@@ -56,6 +266,106 @@ pub trait AssetFetch: Send + Sync + 'static {
     }
 }
 
+/// Result of loading many logical assets from single asset source.
+pub type AssetFetchMany = Vec<(AssetPathStatic, DynamicBundle)>;
+
+// Separates bundle of requested asset from bundles of its sub-assets.
+pub(crate) fn split_requested(
+    path: &AssetPath,
+    mut result: AssetFetchMany,
+) -> Result<(DynamicBundle, AssetFetchMany), Box<dyn Error>> {
+    let index = result
+        .iter()
+        .position(|(sub_path, _)| sub_path == path)
+        .ok_or_else(|| format!("Fetch did not produce requested asset: `{path}`"))?;
+    let (_, bundle) = result.swap_remove(index);
+    Ok((bundle, result))
+}
+
+// Fetch wrappers changing requested path before passing it to inner fetch
+// have to report requested asset under path they were asked for.
+pub(crate) fn rename_requested(
+    mut result: AssetFetchMany,
+    from: &AssetPath,
+    to: &AssetPath,
+) -> AssetFetchMany {
+    if from != to
+        && let Some((path, _)) = result.iter_mut().find(|(path, _)| path == from)
+    {
+        *path = to.clone().into_static();
+    }
+    result
+}
+
+// Marks asset as failed and notifies its bindings, telling apart missing
+// asset sources from other fetch failures.
+pub(crate) fn report_fetch_failure(
+    storage: &mut World,
+    handle: AssetHandle,
+    path: AssetPath,
+    error: &(dyn Error + 'static),
+) -> Result<(), Box<dyn Error>> {
+    // Listeners know asset by its stored path (or alias), not the fetched one.
+    let path = storage
+        .component::<true, AssetPathStatic>(handle.entity())
+        .map(|path| path.clone())
+        .unwrap_or_else(|_| path.into_static());
+    let path = event_path(storage, handle.entity(), &path);
+    if let Ok(mut bindings) = storage.component_mut::<true, AssetEventBindings>(handle.entity()) {
+        let not_found = error
+            .downcast_ref::<FetchError>()
+            .is_some_and(|error| error.is_not_found());
+        bindings.dispatch(AssetEvent {
+            handle,
+            kind: if not_found {
+                AssetEventKind::BytesNotFound
+            } else {
+                AssetEventKind::BytesFetchingFailed
+            },
+            path,
+        })?;
+    }
+    storage.insert(handle.entity(), (AssetLoadError(error.to_string()),))?;
+    Ok(())
+}
+
+// Spawns (or updates) sub-assets loaded together with requested asset, as its
+// dependencies.
+pub(crate) fn insert_sub_assets(
+    storage: &mut World,
+    entity: Entity,
+    sub_assets: AssetFetchMany,
+) -> Result<(), Box<dyn Error>> {
+    for (sub_path, bundle) in sub_assets {
+        let sub_entity = if let Some(sub_entity) = storage.find_by::<true, _>(&sub_path) {
+            sub_entity
+        } else {
+            storage.spawn((sub_path,))?
+        };
+        storage.insert(sub_entity, bundle)?;
+        storage.relate::<true, _>(AssetDependency, entity, sub_entity)?;
+    }
+    Ok(())
+}
+
+impl AssetFetch for Box<dyn AssetFetch> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        (**self).load_bytes(path)
+    }
+
+    fn load_many(&self, path: AssetPath) -> Result<AssetFetchMany, Box<dyn Error>> {
+        (**self).load_many(path)
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        (**self).maintain(storage)
+    }
+}
+
 pub(crate) struct AssetFetchEngine {
     fetch: Box<dyn AssetFetch>,
 }
@@ -71,25 +381,28 @@ impl AssetFetchEngine {
         self.fetch
     }
 
+    pub fn name(&self) -> &str {
+        self.fetch.name()
+    }
+
     pub fn load_bytes(
         &self,
         handle: AssetHandle,
         path: AssetPath,
         storage: &mut World,
     ) -> Result<(), Box<dyn Error>> {
-        let result = self.fetch.load_bytes(path.clone());
-        if result.is_err()
-            && let Ok(mut bindings) =
-                storage.component_mut::<true, AssetEventBindings>(handle.entity())
-        {
-            bindings.dispatch(AssetEvent {
-                handle,
-                kind: AssetEventKind::BytesFetchingFailed,
-                path: path.into_static(),
-            })?;
+        let result = self
+            .fetch
+            .load_many(path.clone())
+            .and_then(|result| split_requested(&path, result));
+        if let Err(error) = result.as_ref() {
+            report_fetch_failure(storage, handle, path, error.as_ref())?;
+        } else if storage.has_entity_component::<AssetLoadError>(handle.entity()) {
+            storage.remove::<(AssetLoadError,)>(handle.entity())?;
         }
-        storage.insert(handle.entity(), result?)?;
-        Ok(())
+        let (bundle, sub_assets) = result?;
+        storage.insert(handle.entity(), bundle)?;
+        insert_sub_assets(storage, handle.entity(), sub_assets)
     }
 
     pub fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
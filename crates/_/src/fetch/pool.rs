@@ -0,0 +1,133 @@
+use anput::{entity::Entity, world::World};
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+
+/// Shared pool of byte buffers, used by fetch engines to read asset bytes
+/// into recycled buffers instead of allocating fresh ones for every asset.
+///
+/// Fetched bytes are handed over to assets, so buffers get back to the pool
+/// only when released - built-in text protocols release bytes of assets
+/// marked with `AssetBytesFromPool` after decoding them, while protocols
+/// keeping bytes (like bytes protocol) do not. Cloned pools share the same
+/// buffers.
+#[derive(Debug, Clone)]
+pub struct AssetBufferPool {
+    buffers: Arc<Mutex<Vec<Vec<u8>>>>,
+    max_buffers: usize,
+    allocated: Arc<AtomicUsize>,
+    reused: Arc<AtomicUsize>,
+}
+
+impl Default for AssetBufferPool {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl AssetBufferPool {
+    /// Creates a new empty buffer pool.
+    ///
+    /// # Arguments
+    /// - `max_buffers`: Maximum number of idle buffers kept in the pool.
+    ///
+    /// # Returns
+    /// - A new `AssetBufferPool` instance.
+    pub fn new(max_buffers: usize) -> Self {
+        Self {
+            buffers: Default::default(),
+            max_buffers,
+            allocated: Default::default(),
+            reused: Default::default(),
+        }
+    }
+
+    /// Takes empty buffer able to hold at least `capacity` bytes.
+    ///
+    /// Pooled buffers with enough capacity are preferred, otherwise new
+    /// buffer gets allocated.
+    ///
+    /// # Arguments
+    /// - `capacity`: Number of bytes buffer has to be able to hold.
+    ///
+    /// # Returns
+    /// - Empty buffer.
+    pub fn acquire(&self, capacity: usize) -> Vec<u8> {
+        let buffer = self.buffers.lock().ok().and_then(|mut buffers| {
+            let index = buffers
+                .iter()
+                .position(|buffer| buffer.capacity() >= capacity)?;
+            Some(buffers.swap_remove(index))
+        });
+        match buffer {
+            Some(mut buffer) => {
+                self.reused.fetch_add(1, Ordering::Relaxed);
+                buffer.clear();
+                buffer
+            }
+            None => {
+                self.allocated.fetch_add(1, Ordering::Relaxed);
+                Vec::with_capacity(capacity)
+            }
+        }
+    }
+
+    /// Gives buffer back to the pool, so it can be reused by next fetches.
+    /// Buffer gets dropped if pool is already full.
+    ///
+    /// # Arguments
+    /// - `buffer`: Buffer to recycle.
+    pub fn release(&self, buffer: Vec<u8>) {
+        if buffer.capacity() == 0 {
+            return;
+        }
+        if let Ok(mut buffers) = self.buffers.lock()
+            && buffers.len() < self.max_buffers
+        {
+            buffers.push(buffer);
+        }
+    }
+
+    /// Returns number of idle buffers in the pool.
+    pub fn len(&self) -> usize {
+        self.buffers
+            .lock()
+            .map(|buffers| buffers.len())
+            .unwrap_or_default()
+    }
+
+    /// Tells if pool has no idle buffers.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns number of buffers allocated because no pooled buffer could
+    /// be reused.
+    pub fn allocated(&self) -> usize {
+        self.allocated.load(Ordering::Relaxed)
+    }
+
+    /// Returns number of pooled buffers reused instead of allocating.
+    pub fn reused(&self) -> usize {
+        self.reused.load(Ordering::Relaxed)
+    }
+}
+
+/// Marks asset which bytes were read into buffer of given pool, so protocols
+/// not keeping asset bytes can release them back with `release_asset_bytes`.
+#[derive(Debug, Clone)]
+pub struct AssetBytesFromPool(pub AssetBufferPool);
+
+/// Gives asset bytes back to the pool they were read from, if asset is
+/// marked with `AssetBytesFromPool`. Otherwise bytes are just dropped.
+///
+/// # Arguments
+/// - `storage`: The world storing the asset.
+/// - `entity`: The asset entity.
+/// - `bytes`: Asset bytes no longer needed after decoding.
+pub fn release_asset_bytes(storage: &World, entity: Entity, bytes: Vec<u8>) {
+    if let Ok(pool) = storage.component::<true, AssetBytesFromPool>(entity) {
+        pool.0.release(bytes);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    database::path::AssetPath,
+    fetch::{AssetByteRange, AssetBytesAreReadyToProcess, AssetFetch},
+};
+use anput::bundle::DynamicBundle;
+use std::{
+    error::Error,
+    io::{Read, Result as IoResult, sink},
+    sync::Mutex,
+};
+
+fn read_range(mut reader: impl Read, range: &AssetByteRange) -> IoResult<Vec<u8>> {
+    std::io::copy(&mut (&mut reader).take(range.0.start), &mut sink())?;
+    let mut bytes = Vec::default();
+    reader
+        .take(range.0.end - range.0.start)
+        .read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Factory of readers providing content of requested assets.
+pub type AssetReaderFactory = Box<dyn FnMut(AssetPath) -> IoResult<Box<dyn Read>> + Send>;
+
+/// Asset fetch reading asset bytes from any `std::io::Read` source.
+///
+/// Readers are created by factory on each fetch and read until the end of
+/// their content, which makes it quick to bridge custom byte sources without
+/// implementing `AssetFetch` directly. Byte ranges requested with `range`
+/// asset path meta are respected by skipping and limiting read bytes.
+pub struct ReaderAssetFetch {
+    factory: Mutex<AssetReaderFactory>,
+}
+
+impl ReaderAssetFetch {
+    /// Creates a new `ReaderAssetFetch` with reader factory.
+    ///
+    /// # Arguments
+    /// - `factory`: Closure creating reader of asset content for given path.
+    ///
+    /// # Returns
+    /// A new `ReaderAssetFetch` instance.
+    pub fn new(factory: impl FnMut(AssetPath) -> IoResult<Box<dyn Read>> + Send + 'static) -> Self {
+        Self {
+            factory: Mutex::new(Box::new(factory)),
+        }
+    }
+}
+
+impl AssetFetch for ReaderAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        let range = AssetByteRange::from_path(&path)?;
+        let mut reader = {
+            let mut factory = self.factory.lock().map_err(|error| {
+                format!(
+                    "Failed to get access to reader factory for asset: `{path}`. Error: {error}"
+                )
+            })?;
+            factory(path.clone()).map_err(|error| {
+                format!("Failed to open reader for asset: `{path}`. Error: {error}")
+            })?
+        };
+        let bytes = if let Some(range) = range.as_ref() {
+            read_range(reader, range)
+        } else {
+            let mut bytes = Vec::default();
+            reader.read_to_end(&mut bytes).map(|_| bytes)
+        }
+        .map_err(|error| format!("Failed to read bytes of asset: `{path}`. Error: {error}"))?;
+        let mut bundle = DynamicBundle::default();
+        bundle
+            .add_component(AssetBytesAreReadyToProcess(bytes))
+            .map_err(|_| format!("Failed to add bytes to bundle for asset: `{path}`"))?;
+        if let Some(range) = range {
+            bundle
+                .add_component(range)
+                .map_err(|_| format!("Failed to add byte range to bundle for asset: `{path}`"))?;
+        }
+        Ok(bundle)
+    }
+}
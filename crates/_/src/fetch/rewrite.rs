@@ -1,4 +1,7 @@
-use crate::{database::path::AssetPath, fetch::AssetFetch};
+use crate::{
+    database::path::AssetPath,
+    fetch::{AssetFetch, AssetFetchMany, rename_requested},
+};
 use anput::{bundle::DynamicBundle, world::World};
 use std::error::Error;
 
@@ -8,10 +11,14 @@ use std::error::Error;
 /// This struct is useful for dynamically rewriting asset paths at runtime,
 /// allowing redirection or transformation of the requested asset paths
 /// without modifying the underlying fetcher implementation.
+///
+/// Rewrite rules are applied in order they were added, each one receiving
+/// path produced by the previous one, so they can change protocol, path and
+/// meta of the asset path.
 pub struct RewriteAssetFetch<Fetch: AssetFetch> {
     fetch: Fetch,
     #[allow(clippy::type_complexity)]
-    callback: Box<dyn Fn(AssetPath) -> Result<AssetPath, Box<dyn Error>> + Send + Sync>,
+    rules: Vec<Box<dyn Fn(AssetPath) -> Result<AssetPath, Box<dyn Error>> + Send + Sync>>,
 }
 
 impl<Fetch: AssetFetch> RewriteAssetFetch<Fetch> {
@@ -29,17 +36,80 @@ impl<Fetch: AssetFetch> RewriteAssetFetch<Fetch> {
         fetch: Fetch,
         callback: impl Fn(AssetPath) -> Result<AssetPath, Box<dyn Error>> + Send + Sync + 'static,
     ) -> Self {
+        Self::new_empty(fetch).with_fn(callback)
+    }
+
+    /// Creates a new instance of [`RewriteAssetFetch`] without any rewrite rules.
+    ///
+    /// # Arguments
+    /// - `fetch`: The inner fetcher that handles asset fetching.
+    ///
+    /// # Returns
+    /// A new [`RewriteAssetFetch`] instance.
+    pub fn new_empty(fetch: Fetch) -> Self {
         Self {
             fetch,
-            callback: Box::new(callback),
+            rules: Default::default(),
+        }
+    }
+
+    /// Adds rule that replaces given prefix of the asset path content.
+    /// Paths not starting with the prefix are left unchanged.
+    ///
+    /// # Arguments
+    /// - `from`: Prefix to replace, for example `texture://`.
+    /// - `to`: Replacement prefix, for example `file://textures/`.
+    ///
+    /// # Returns
+    /// The updated [`RewriteAssetFetch`] instance.
+    pub fn with_prefix(self, from: impl ToString, to: impl ToString) -> Self {
+        let from = from.to_string();
+        let to = to.to_string();
+        self.with_fn(move |path| {
+            if let Some(rest) = path.content().strip_prefix(from.as_str()) {
+                Ok(AssetPath::new(format!("{to}{rest}")))
+            } else {
+                Ok(path)
+            }
+        })
+    }
+
+    /// Adds rule that rewrites asset path with a closure.
+    ///
+    /// # Arguments
+    /// - `callback`: A closure that rewrites the provided asset path.
+    ///
+    /// # Returns
+    /// The updated [`RewriteAssetFetch`] instance.
+    pub fn with_fn(
+        mut self,
+        callback: impl Fn(AssetPath) -> Result<AssetPath, Box<dyn Error>> + Send + Sync + 'static,
+    ) -> Self {
+        self.rules.push(Box::new(callback));
+        self
+    }
+
+    fn rewrite<'a>(&self, path: AssetPath<'a>) -> Result<AssetPath<'a>, Box<dyn Error>> {
+        let mut path = path;
+        for rule in &self.rules {
+            path = rule(path)?;
         }
+        Ok(path)
     }
 }
 
 impl<Fetch: AssetFetch> AssetFetch for RewriteAssetFetch<Fetch> {
     fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
-        let path = (self.callback)(path)?;
-        self.fetch.load_bytes(path)
+        self.fetch.load_bytes(self.rewrite(path)?)
+    }
+
+    fn load_many(&self, path: AssetPath) -> Result<AssetFetchMany, Box<dyn Error>> {
+        let rewritten = self.rewrite(path.clone())?;
+        Ok(rename_requested(
+            self.fetch.load_many(rewritten.clone())?,
+            &rewritten,
+            &path,
+        ))
     }
 
     fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
@@ -1,4 +1,7 @@
-use crate::{database::path::AssetPath, fetch::AssetFetch};
+use crate::{
+    database::path::AssetPath,
+    fetch::{AssetFetch, AssetFetchMany},
+};
 use anput::{bundle::DynamicBundle, world::World};
 use std::{error::Error, sync::RwLock};
 
@@ -72,6 +75,20 @@ impl AssetFetch for RouterAssetFetch {
         Err(format!("Could not find route for asset: `{path}`").into())
     }
 
+    fn load_many(&self, path: AssetPath) -> Result<AssetFetchMany, Box<dyn Error>> {
+        for (rule, fetch, _) in self
+            .table
+            .read()
+            .map_err(|error| format!("{error}"))?
+            .iter()
+        {
+            if rule(&path) {
+                return fetch.load_many(path);
+            }
+        }
+        Err(format!("Could not find route for asset: `{path}`").into())
+    }
+
     fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
         for (_, fetch, _) in self
             .table
@@ -0,0 +1,177 @@
+use crate::{
+    database::path::AssetPath,
+    fetch::{AssetBytesAreReadyToProcess, AssetFetch, file::AssetFromFile},
+};
+use anput::bundle::DynamicBundle;
+use std::{error::Error, fs::File, io::Read, path::PathBuf};
+
+/// Default size of chunks yielded by asset byte streams.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Component holding asset content as a stream of byte chunks, instead of
+/// single buffer of bytes.
+///
+/// Streamed assets also carry empty `AssetBytesAreReadyToProcess` component,
+/// so they go through regular processing pipeline. Protocols that support
+/// streaming override `AssetProtocol::process_stream`, other protocols get
+/// whole stream buffered into bytes before processing.
+pub struct AssetByteStream {
+    reader: Box<dyn Read + Send + Sync>,
+    chunk_size: usize,
+}
+
+impl Default for AssetByteStream {
+    fn default() -> Self {
+        Self::new(std::io::empty())
+    }
+}
+
+impl AssetByteStream {
+    /// Creates a new stream reading from given reader.
+    ///
+    /// # Arguments
+    /// - `reader`: Source of stream bytes.
+    ///
+    /// # Returns
+    /// A new `AssetByteStream` instance.
+    pub fn new(reader: impl Read + Send + Sync + 'static) -> Self {
+        Self {
+            reader: Box::new(reader),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Sets maximum size of yielded chunks.
+    ///
+    /// # Arguments
+    /// - `chunk_size`: Maximum chunk size in bytes.
+    ///
+    /// # Returns
+    /// The updated `AssetByteStream` instance.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Returns maximum size of yielded chunks.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Reads next chunk of bytes.
+    ///
+    /// # Returns
+    /// Next chunk, `None` if stream has ended, or an error if reading fails.
+    pub fn next_chunk(&mut self) -> std::io::Result<Option<Vec<u8>>> {
+        let mut chunk = vec![0; self.chunk_size];
+        let mut size = 0;
+        while size < chunk.len() {
+            match self.reader.read(&mut chunk[size..]) {
+                Ok(0) => break,
+                Ok(count) => size += count,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+        if size == 0 {
+            Ok(None)
+        } else {
+            chunk.truncate(size);
+            Ok(Some(chunk))
+        }
+    }
+
+    /// Reads the rest of stream into single buffer.
+    ///
+    /// # Returns
+    /// All remaining bytes, or an error if reading fails.
+    pub fn read_all(mut self) -> std::io::Result<Vec<u8>> {
+        let mut result = Vec::default();
+        self.reader.read_to_end(&mut result)?;
+        Ok(result)
+    }
+}
+
+impl Iterator for AssetByteStream {
+    type Item = std::io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+impl Read for AssetByteStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+/// An implementation of the `AssetFetch` trait that streams assets from the
+/// file system using specified root path, without loading whole files into
+/// memory.
+#[derive(Debug, Clone)]
+pub struct StreamingAssetFetch {
+    pub root: PathBuf,
+    pub chunk_size: usize,
+}
+
+impl Default for StreamingAssetFetch {
+    fn default() -> Self {
+        Self {
+            root: Default::default(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+}
+
+impl StreamingAssetFetch {
+    /// Sets the root directory for file-based asset streaming.
+    ///
+    /// # Arguments
+    /// - `root`: The root path to set for fetching assets.
+    ///
+    /// # Returns
+    /// - A modified `StreamingAssetFetch` instance with the new root directory.
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    /// Sets maximum size of chunks yielded by asset streams.
+    ///
+    /// # Arguments
+    /// - `chunk_size`: Maximum chunk size in bytes.
+    ///
+    /// # Returns
+    /// - A modified `StreamingAssetFetch` instance with the new chunk size.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+}
+
+impl AssetFetch for StreamingAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        let file_path = self.root.join(path.path());
+        let file = File::open(&file_path)
+            .map_err(|error| format!("Failed to open `{file_path:?}` file stream: {error}"))?;
+        let metadata = file.metadata()?;
+        let mut bundle = DynamicBundle::default();
+        bundle
+            .add_component(AssetBytesAreReadyToProcess(Default::default()))
+            .map_err(|_| format!("Failed to add bytes to bundle for asset file: {file_path:?}"))?;
+        bundle
+            .add_component(AssetByteStream::new(file).with_chunk_size(self.chunk_size))
+            .map_err(|_| format!("Failed to add stream to bundle for asset file: {file_path:?}"))?;
+        bundle
+            .add_component(AssetFromFile)
+            .map_err(|_| format!("Failed to add marker to bundle for asset file: {file_path:?}"))?;
+        bundle.add_component(metadata).map_err(|_| {
+            format!("Failed to add metadata to bundle for asset file: {file_path:?}")
+        })?;
+        bundle.add_component(file_path.clone()).map_err(|_| {
+            format!("Failed to add file system path to bundle for asset file: {file_path:?}")
+        })?;
+        Ok(bundle)
+    }
+}
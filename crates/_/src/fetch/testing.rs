@@ -0,0 +1,61 @@
+use crate::{
+    database::path::AssetPath,
+    fetch::{AssetBytesAreReadyToProcess, AssetFetch},
+};
+use anput::bundle::DynamicBundle;
+use std::{collections::HashMap, error::Error};
+
+/// Asset fetch meant for tests, serving fixed bytes from memory.
+///
+/// Assets are looked up by path part of the asset path. Fetch can also be
+/// made to always fail, to test error handling.
+#[derive(Debug, Default, Clone)]
+pub struct NullAssetFetch {
+    assets: HashMap<String, Vec<u8>>,
+    error: Option<String>,
+}
+
+impl NullAssetFetch {
+    /// Adds asset bytes served under given path.
+    ///
+    /// # Arguments
+    /// - `path`: Path part of the asset path.
+    /// - `bytes`: Bytes of the asset.
+    ///
+    /// # Returns
+    /// The updated `NullAssetFetch` instance.
+    pub fn with(mut self, path: impl ToString, bytes: impl Into<Vec<u8>>) -> Self {
+        self.assets.insert(path.to_string(), bytes.into());
+        self
+    }
+
+    /// Makes every fetch fail with given error message.
+    ///
+    /// # Arguments
+    /// - `message`: Error message reported for every fetched asset.
+    ///
+    /// # Returns
+    /// The updated `NullAssetFetch` instance.
+    pub fn failing(mut self, message: impl ToString) -> Self {
+        self.error = Some(message.to_string());
+        self
+    }
+}
+
+impl AssetFetch for NullAssetFetch {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        if let Some(error) = self.error.as_ref() {
+            return Err(format!("Failed to fetch asset: `{path}`. Error: {error}").into());
+        }
+        let bytes = self
+            .assets
+            .get(path.path())
+            .cloned()
+            .ok_or_else(|| format!("Missing asset: `{path}`"))?;
+        let mut bundle = DynamicBundle::default();
+        bundle
+            .add_component(AssetBytesAreReadyToProcess(bytes))
+            .map_err(|_| format!("Failed to add bytes to `{path}` asset"))?;
+        Ok(bundle)
+    }
+}
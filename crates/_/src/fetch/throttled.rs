@@ -1,6 +1,6 @@
 use crate::{
     database::path::{AssetPath, AssetPathStatic},
-    fetch::{AssetAwaitsAsyncFetch, AssetFetch},
+    fetch::{AssetAwaitsAsyncFetch, AssetFetch, insert_sub_assets, split_requested},
 };
 use anput::{
     bundle::DynamicBundle,
@@ -18,6 +18,11 @@ pub enum ThrottledAssetFetchStrategy {
     Duration(Duration),
 }
 
+/// A wrapper for an [`AssetFetch`] implementation that spreads fetching of
+/// assets over multiple maintenance ticks, according to throttling strategy.
+///
+/// Awaiting assets are kept in a set, so multiple loads of the same asset
+/// before it gets fetched result in single inner fetch.
 pub struct ThrottledAssetFetch<Fetch: AssetFetch> {
     fetch: RwLock<Fetch>,
     strategy: ThrottledAssetFetchStrategy,
@@ -32,6 +37,17 @@ impl<Fetch: AssetFetch> ThrottledAssetFetch<Fetch> {
             awaiting: Default::default(),
         }
     }
+
+    /// Returns number of assets awaiting to be fetched in next maintenance ticks.
+    ///
+    /// # Returns
+    /// - Number of in-flight fetches of this engine.
+    pub fn in_flight(&self) -> usize {
+        self.awaiting
+            .read()
+            .map(|awaiting| awaiting.len())
+            .unwrap_or_default()
+    }
 }
 
 impl<Fetch: AssetFetch> AssetFetch for ThrottledAssetFetch<Fetch> {
@@ -71,12 +87,14 @@ impl<Fetch: AssetFetch> AssetFetch for ThrottledAssetFetch<Fetch> {
                         "Failed to get write access to inner fetch engine in throttled fetch for asset: `{path}`. Error: {error}"
                     )
                 })?
-                .load_bytes(path.clone());
+                .load_many(path.clone())
+                .and_then(|result| split_requested(&path, result));
             match bundle {
-                Ok(bundle) => {
+                Ok((bundle, sub_assets)) => {
                     if let Some(entity) = storage.find_by::<true, _>(&path) {
                         storage.remove::<(AssetAwaitsAsyncFetch,)>(entity)?;
                         storage.insert(entity, bundle)?;
+                        insert_sub_assets(storage, entity, sub_assets)?;
                     }
                 }
                 Err(e) => {
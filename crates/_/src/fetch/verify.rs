@@ -0,0 +1,144 @@
+use crate::{
+    database::{
+        AssetLoadError,
+        events::{AssetEvent, AssetEventBindings, AssetEventKind},
+        handle::AssetHandle,
+        path::{AssetPath, AssetPathStatic},
+    },
+    fetch::{AssetAwaitsAsyncFetch, AssetBytesAreReadyToProcess, AssetFetch, AssetFetchMany},
+};
+use anput::{bundle::DynamicBundle, entity::Entity, world::World};
+use sha2::{Digest, Sha256};
+use std::error::Error;
+
+/// Component holding expected SHA-256 hash of asset bytes, that awaits
+/// verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetAwaitsVerification {
+    pub sha256: String,
+}
+
+fn mark_verification(
+    path: &AssetPath,
+    mut bundle: DynamicBundle,
+) -> Result<DynamicBundle, Box<dyn Error>> {
+    if let Some(sha256) = path.meta_value("sha256").map(|hash| hash.to_lowercase()) {
+        bundle
+            .add_component(AssetAwaitsVerification { sha256 })
+            .map_err(|_| {
+                format!("Failed to add verification marker to bundle for asset: `{path}`")
+            })?;
+        // Inner async fetch might have already marked asset as awaiting.
+        let _ = bundle.add_component(AssetAwaitsAsyncFetch);
+    }
+    Ok(bundle)
+}
+
+/// Computes lowercase hexadecimal SHA-256 hash of bytes.
+///
+/// # Arguments
+/// - `bytes`: Bytes to hash.
+///
+/// # Returns
+/// Hexadecimal representation of the hash.
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Asset fetch wrapper that verifies integrity of fetched asset bytes.
+///
+/// Expected hash is read from `sha256` asset path meta (for example
+/// `text://lorem.txt?sha256=<hex>`). Assets without that meta pass through
+/// unchanged. Verified assets are kept awaiting (as with async fetch) until
+/// next maintenance, where their bytes get hashed and either released for
+/// processing, or rejected with `BytesFetchingFailed` event on mismatch.
+pub struct VerifyingAssetFetch<Fetch: AssetFetch> {
+    fetch: Fetch,
+}
+
+impl<Fetch: AssetFetch> VerifyingAssetFetch<Fetch> {
+    /// Creates a new `VerifyingAssetFetch` wrapping inner fetch engine.
+    ///
+    /// # Arguments
+    /// - `fetch`: The inner fetcher that handles asset fetching.
+    ///
+    /// # Returns
+    /// A new `VerifyingAssetFetch` instance.
+    pub fn new(fetch: Fetch) -> Self {
+        Self { fetch }
+    }
+
+    /// Returns the inner fetch engine.
+    pub fn into_inner(self) -> Fetch {
+        self.fetch
+    }
+}
+
+impl<Fetch: AssetFetch> AssetFetch for VerifyingAssetFetch<Fetch> {
+    fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        let bundle = self.fetch.load_bytes(path.clone())?;
+        mark_verification(&path, bundle)
+    }
+
+    fn load_many(&self, path: AssetPath) -> Result<AssetFetchMany, Box<dyn Error>> {
+        // Only requested asset carries expected hash in its path.
+        self.fetch
+            .load_many(path.clone())?
+            .into_iter()
+            .map(|(sub_path, bundle)| {
+                if sub_path == path {
+                    Ok((sub_path, mark_verification(&path, bundle)?))
+                } else {
+                    Ok((sub_path, bundle))
+                }
+            })
+            .collect()
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        self.fetch.maintain(storage)?;
+        let to_verify = storage
+            .query::<true, (
+                Entity,
+                &AssetPathStatic,
+                &AssetBytesAreReadyToProcess,
+                &AssetAwaitsVerification,
+            )>()
+            .map(|(entity, path, bytes, verification)| {
+                let actual = sha256_hex(&bytes.0);
+                (entity, path.clone(), actual, verification.sha256.to_owned())
+            })
+            .collect::<Vec<_>>();
+        let mut errors = Vec::default();
+        for (entity, path, actual, expected) in to_verify {
+            storage.remove::<(AssetAwaitsVerification,)>(entity)?;
+            if storage.has_entity_component::<AssetAwaitsAsyncFetch>(entity) {
+                storage.remove::<(AssetAwaitsAsyncFetch,)>(entity)?;
+            }
+            if actual == expected {
+                continue;
+            }
+            storage.remove::<(AssetBytesAreReadyToProcess,)>(entity)?;
+            if let Ok(mut bindings) = storage.component_mut::<true, AssetEventBindings>(entity) {
+                bindings.dispatch(AssetEvent {
+                    handle: AssetHandle::new(entity),
+                    kind: AssetEventKind::BytesFetchingFailed,
+                    path: path.clone(),
+                })?;
+            }
+            let error = format!(
+                "Integrity verification failed for asset: `{path}`. Expected SHA-256: {expected}, got: {actual}"
+            );
+            storage.insert(entity, (AssetLoadError(error.clone()),))?;
+            errors.push(error);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("\n").into())
+        }
+    }
+}
@@ -129,4 +129,30 @@ pub mod store;
 
 pub mod third_party {
     pub use anput;
+    #[cfg(feature = "bincode")]
+    pub use bincode;
+    #[cfg(feature = "csv")]
+    pub use csv;
+    #[cfg(feature = "encoding")]
+    pub use encoding_rs;
+    #[cfg(feature = "image")]
+    pub use image;
+    #[cfg(feature = "protobuf")]
+    pub use prost;
+    #[cfg(feature = "markdown")]
+    pub use pulldown_cmark;
+    #[cfg(feature = "xml")]
+    pub use quick_xml;
+    #[cfg(feature = "msgpack")]
+    pub use rmp_serde;
+    #[cfg(feature = "ron")]
+    pub use ron;
+    #[cfg(any(feature = "markdown", feature = "manifest"))]
+    pub use serde_json;
+    #[cfg(feature = "markdown")]
+    pub use serde_yaml;
+    #[cfg(feature = "verify")]
+    pub use sha2;
+    #[cfg(feature = "font")]
+    pub use ttf_parser;
 }
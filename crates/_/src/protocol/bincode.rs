@@ -0,0 +1,139 @@
+use crate::{
+    database::{inspector::AssetInspector, path::AssetPathStatic},
+    protocol::bundle::{
+        BundleAssetProtocol, BundleWithDependencies, BundleWithDependenciesProcessor,
+        StoreWithDependencies,
+    },
+};
+use anput::component::Component;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{error::Error, marker::PhantomData};
+
+/// Bundle protocol that decodes bincode assets into `T` component.
+pub type BincodeAssetProtocol<T> = BundleAssetProtocol<BincodeAssetProcessor<T>>;
+
+/// Encoding of integers in bincode assets.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BincodeIntEncoding {
+    /// Variable length integers, producing smaller assets.
+    #[default]
+    Varint,
+    /// Fixed size integers, compatible with bincode 1.x format.
+    Fixint,
+}
+
+/// Processor for bincode binary assets.
+///
+/// Decodes asset bytes into `T` component and encodes it back when asset gets
+/// stored. Meant to be used with `BundleAssetProtocol`:
+/// `BundleAssetProtocol::new("bincode", BincodeAssetProcessor::<T>::default())`.
+///
+/// Bincode format does not describe its content, so assets encoded with
+/// different version of `T` either fail to decode or leave trailing bytes,
+/// which is reported as decode error.
+pub struct BincodeAssetProcessor<T: Component + Serialize + DeserializeOwned> {
+    int_encoding: BincodeIntEncoding,
+    #[allow(clippy::type_complexity)]
+    dependencies: Option<Box<dyn Fn(&T) -> Vec<AssetPathStatic> + Send + Sync>>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Component + Serialize + DeserializeOwned> Default for BincodeAssetProcessor<T> {
+    fn default() -> Self {
+        Self {
+            int_encoding: Default::default(),
+            dependencies: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Component + Serialize + DeserializeOwned> BincodeAssetProcessor<T> {
+    /// Sets encoding of integers in assets.
+    /// By default integers are encoded as variable length integers.
+    ///
+    /// # Arguments
+    /// - `int_encoding`: Encoding of integers.
+    ///
+    /// # Returns
+    /// The updated `BincodeAssetProcessor` instance.
+    pub fn int_encoding(mut self, int_encoding: BincodeIntEncoding) -> Self {
+        self.int_encoding = int_encoding;
+        self
+    }
+
+    /// Sets function that reports asset dependencies of decoded asset.
+    ///
+    /// # Arguments
+    /// - `dependencies`: Function returning asset paths decoded asset depends on.
+    ///
+    /// # Returns
+    /// The updated `BincodeAssetProcessor` instance.
+    pub fn dependencies(
+        mut self,
+        dependencies: impl Fn(&T) -> Vec<AssetPathStatic> + Send + Sync + 'static,
+    ) -> Self {
+        self.dependencies = Some(Box::new(dependencies));
+        self
+    }
+}
+
+impl<T: Component + Serialize + DeserializeOwned> BundleWithDependenciesProcessor
+    for BincodeAssetProcessor<T>
+{
+    type Bundle = (T,);
+
+    fn process_bytes(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<BundleWithDependencies<Self::Bundle>, Box<dyn Error>> {
+        let config = bincode::config::standard();
+        let decoded = match self.int_encoding {
+            BincodeIntEncoding::Varint => bincode::serde::decode_from_slice::<T, _>(&bytes, config),
+            BincodeIntEncoding::Fixint => {
+                bincode::serde::decode_from_slice::<T, _>(&bytes, config.with_fixed_int_encoding())
+            }
+        };
+        let (asset, read) = decoded.map_err(|error| {
+            format!(
+                "Failed to decode {} bincode asset. Error: {}",
+                std::any::type_name::<T>(),
+                error
+            )
+        })?;
+        if read != bytes.len() {
+            return Err(format!(
+                "Failed to decode {} bincode asset. Error: {} trailing bytes left, asset was likely encoded with different version of the type",
+                std::any::type_name::<T>(),
+                bytes.len() - read
+            )
+            .into());
+        }
+        let dependencies = self
+            .dependencies
+            .as_ref()
+            .map(|dependencies| dependencies(&asset))
+            .unwrap_or_default();
+        Ok(BundleWithDependencies::new((asset,)).dependencies(dependencies))
+    }
+
+    fn produce_bytes(
+        &mut self,
+        inspector: AssetInspector,
+    ) -> Result<StoreWithDependencies, Box<dyn Error>> {
+        let asset = inspector.access_checked::<&T>().ok_or_else(|| {
+            format!(
+                "Could not get {} asset component",
+                std::any::type_name::<T>(),
+            )
+        })?;
+        let config = bincode::config::standard();
+        let bytes = match self.int_encoding {
+            BincodeIntEncoding::Varint => bincode::serde::encode_to_vec(asset, config)?,
+            BincodeIntEncoding::Fixint => {
+                bincode::serde::encode_to_vec(asset, config.with_fixed_int_encoding())?
+            }
+        };
+        Ok(StoreWithDependencies::new(bytes))
+    }
+}
@@ -210,10 +210,16 @@ where
     }
 }
 
+/// Post-processing step invoked after bundle of processed asset gets inserted.
+pub type BundleAssetPostProcess =
+    Box<dyn FnMut(AssetHandle, &mut World) -> Result<(), Box<dyn Error>> + Send + Sync>;
+
 /// Protocol for handling bundles using a user-defined processor.
 pub struct BundleAssetProtocol<Processor: BundleWithDependenciesProcessor> {
     name: String,
     processor: Processor,
+    extensions: Vec<&'static str>,
+    post_process: Vec<BundleAssetPostProcess>,
 }
 
 impl<Processor: BundleWithDependenciesProcessor> BundleAssetProtocol<Processor> {
@@ -222,8 +228,42 @@ impl<Processor: BundleWithDependenciesProcessor> BundleAssetProtocol<Processor>
         Self {
             name: name.to_string(),
             processor,
+            extensions: Default::default(),
+            post_process: Default::default(),
         }
     }
+
+    /// Sets file extensions of assets this protocol handles.
+    ///
+    /// # Arguments
+    /// - `extensions`: File extensions, without leading dot.
+    ///
+    /// # Returns
+    /// The updated `BundleAssetProtocol` instance.
+    pub fn with_extensions(mut self, extensions: impl IntoIterator<Item = &'static str>) -> Self {
+        self.extensions = extensions.into_iter().collect();
+        self
+    }
+
+    /// Adds post-processing step invoked after processed bundle and its
+    /// dependencies get inserted into asset, useful for attaching extra
+    /// components or validation. Steps are invoked in order they were added.
+    ///
+    /// # Arguments
+    /// - `post_process`: Function receiving handle of processed asset and storage.
+    ///
+    /// # Returns
+    /// The updated `BundleAssetProtocol` instance.
+    pub fn with_post_process(
+        mut self,
+        post_process: impl FnMut(AssetHandle, &mut World) -> Result<(), Box<dyn Error>>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.post_process.push(Box::new(post_process));
+        self
+    }
 }
 
 impl<Processor: BundleWithDependenciesProcessor> AssetProtocol for BundleAssetProtocol<Processor> {
@@ -231,6 +271,10 @@ impl<Processor: BundleWithDependenciesProcessor> AssetProtocol for BundleAssetPr
         &self.name
     }
 
+    fn extensions(&self) -> &[&str] {
+        &self.extensions
+    }
+
     fn extract_bundle_from_path(&self, path: &AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
         self.processor.extract_bundle_from_path(path)
     }
@@ -254,6 +298,9 @@ impl<Processor: BundleWithDependenciesProcessor> AssetProtocol for BundleAssetPr
             let entity = storage.spawn((path, AssetAwaitsResolution))?;
             storage.relate::<true, _>(AssetDependency, handle.entity(), entity)?;
         }
+        for post_process in &mut self.post_process {
+            post_process(handle, storage)?;
+        }
         Ok(())
     }
 
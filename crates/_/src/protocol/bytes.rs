@@ -1,6 +1,9 @@
-use crate::{database::handle::AssetHandle, protocol::AssetProtocol};
-use anput::world::World;
-use std::error::Error;
+use crate::{
+    database::handle::AssetHandle,
+    protocol::{AssetBytesDecoder, AssetProtocol},
+};
+use anput::{bundle::DynamicBundle, world::World};
+use std::{error::Error, sync::Arc};
 
 /// Protocol implementation for handling generic byte-based assets.
 ///
@@ -13,6 +16,16 @@ impl AssetProtocol for BytesAssetProtocol {
         "bytes"
     }
 
+    fn bytes_decoder(&self) -> Option<AssetBytesDecoder> {
+        Some(Arc::new(|bytes| {
+            let mut bundle = DynamicBundle::default();
+            bundle
+                .add_component(bytes)
+                .map_err(|_| "Failed to add bytes to bundle")?;
+            Ok(bundle)
+        }))
+    }
+
     fn process_bytes(
         &mut self,
         handle: AssetHandle,
@@ -0,0 +1,150 @@
+use crate::{
+    database::{
+        handle::AssetHandle,
+        path::{AssetPath, AssetPathStatic},
+    },
+    fetch::{AssetBytesAreReadyToProcess, stream::AssetByteStream},
+    protocol::{AssetBytesDecoder, AssetProtocol},
+};
+use anput::{bundle::DynamicBundle, world::World};
+use sha2::{Digest, Sha256};
+use std::{error::Error, sync::Arc};
+
+/// Component holding SHA-256 checksum of asset source bytes.
+///
+/// Assets with identical content get identical checksums, which allows
+/// telling if reloaded asset content actually changed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssetChecksum(pub [u8; 32]);
+
+impl AssetChecksum {
+    /// Computes checksum of bytes.
+    ///
+    /// # Arguments
+    /// - `bytes`: Bytes to hash.
+    ///
+    /// # Returns
+    /// A new `AssetChecksum` instance.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(Sha256::digest(bytes).into())
+    }
+
+    /// Returns lowercase hexadecimal representation of checksum.
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
+/// Protocol wrapper that computes `AssetChecksum` component of asset source
+/// bytes, before inner protocol processes them.
+///
+/// Streamed assets are not hashed, since their content is never buffered.
+pub struct ChecksumAssetProtocol<Protocol: AssetProtocol> {
+    protocol: Protocol,
+}
+
+impl<Protocol: AssetProtocol> ChecksumAssetProtocol<Protocol> {
+    /// Creates a new `ChecksumAssetProtocol` wrapping inner protocol.
+    ///
+    /// # Arguments
+    /// - `protocol`: The inner protocol that processes asset bytes.
+    ///
+    /// # Returns
+    /// A new `ChecksumAssetProtocol` instance.
+    pub fn new(protocol: Protocol) -> Self {
+        Self { protocol }
+    }
+
+    /// Returns the inner protocol.
+    pub fn into_inner(self) -> Protocol {
+        self.protocol
+    }
+}
+
+impl<Protocol: AssetProtocol> AssetProtocol for ChecksumAssetProtocol<Protocol> {
+    fn name(&self) -> &str {
+        self.protocol.name()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.protocol.extensions()
+    }
+
+    fn sniff(&self, bytes: &[u8]) -> bool {
+        self.protocol.sniff(bytes)
+    }
+
+    fn bytes_decoder(&self) -> Option<AssetBytesDecoder> {
+        let decoder = self.protocol.bytes_decoder()?;
+        Some(Arc::new(move |bytes| {
+            let checksum = AssetChecksum::from_bytes(&bytes);
+            let mut bundle = decoder(bytes)?;
+            bundle
+                .add_component(checksum)
+                .map_err(|_| "Failed to add checksum to bundle")?;
+            Ok(bundle)
+        }))
+    }
+
+    fn extract_bundle_from_path(&self, path: &AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        self.protocol.extract_bundle_from_path(path)
+    }
+
+    fn rewrite_path(&self, path: AssetPathStatic) -> Result<AssetPathStatic, Box<dyn Error>> {
+        self.protocol.rewrite_path(path)
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.protocol.process_bytes(handle, storage, bytes)
+    }
+
+    fn process_stream(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        stream: AssetByteStream,
+    ) -> Result<(), Box<dyn Error>> {
+        self.protocol.process_stream(handle, storage, stream)
+    }
+
+    fn process_asset_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<(), Box<dyn Error>> {
+        if !storage.has_entity_component::<AssetByteStream>(handle.entity()) {
+            let checksum = storage
+                .component::<true, AssetBytesAreReadyToProcess>(handle.entity())
+                .map(|bytes| AssetChecksum::from_bytes(&bytes.0));
+            if let Ok(checksum) = checksum {
+                storage.insert(handle.entity(), (checksum,))?;
+            }
+        }
+        self.protocol.process_asset_bytes(handle, storage)
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        self.protocol.produce_bytes(handle, storage)
+    }
+
+    fn produce_asset_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<(), Box<dyn Error>> {
+        self.protocol.produce_asset_bytes(handle, storage)
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        self.protocol.maintain(storage)
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    database::{handle::AssetHandle, inspector::AssetInspector, path::AssetPathStatic},
+    protocol::{
+        AssetProtocol,
+        bundle::{
+            BundleAssetProtocol, BundleWithDependencies, BundleWithDependenciesProcessor,
+            StoreWithDependencies,
+        },
+    },
+};
+use anput::{component::Component, world::World};
+use csv::{ReaderBuilder, WriterBuilder};
+use serde::{Serialize, de::DeserializeOwned};
+use std::{error::Error, marker::PhantomData};
+
+/// Raw CSV table asset component.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CsvTable {
+    /// Header row columns. Empty when table has no header row.
+    pub headers: Vec<String>,
+    /// Data rows, excluding header row.
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Protocol implementation for decoding CSV assets into raw `CsvTable`
+/// component.
+///
+/// By default uses comma delimiter and treats first row as header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvAssetProtocol {
+    delimiter: u8,
+    has_headers: bool,
+}
+
+impl Default for CsvAssetProtocol {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_headers: true,
+        }
+    }
+}
+
+impl CsvAssetProtocol {
+    /// Sets field delimiter.
+    ///
+    /// # Arguments
+    /// - `delimiter`: Byte separating fields in a row.
+    ///
+    /// # Returns
+    /// The updated `CsvAssetProtocol` instance.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether first row is a header row.
+    ///
+    /// # Arguments
+    /// - `has_headers`: True if first row contains column names.
+    ///
+    /// # Returns
+    /// The updated `CsvAssetProtocol` instance.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+}
+
+impl AssetProtocol for CsvAssetProtocol {
+    fn name(&self) -> &str {
+        "csv"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["csv", "tsv"]
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut reader = ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .flexible(true)
+            .from_reader(bytes.as_slice());
+        let headers = if self.has_headers {
+            reader
+                .headers()?
+                .iter()
+                .map(|item| item.to_owned())
+                .collect()
+        } else {
+            Default::default()
+        };
+        let rows = reader
+            .records()
+            .map(|record| Ok(record?.iter().map(|item| item.to_owned()).collect()))
+            .collect::<Result<Vec<_>, csv::Error>>()?;
+        storage.insert(handle.entity(), (CsvTable { headers, rows },))?;
+        Ok(())
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let table = storage.component::<true, CsvTable>(handle.entity())?;
+        let mut writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_writer(Vec::default());
+        if self.has_headers {
+            writer.write_record(&table.headers)?;
+        }
+        for row in &table.rows {
+            writer.write_record(row)?;
+        }
+        Ok(writer.into_inner().map_err(|error| error.to_string())?)
+    }
+}
+
+/// Bundle protocol that decodes CSV assets into `Vec<T>` component.
+pub type CsvRecordsAssetProtocol<T> = BundleAssetProtocol<CsvRecordsAssetProcessor<T>>;
+
+/// Processor for CSV assets deserialized into list of `T` records.
+///
+/// Meant to be used with `BundleAssetProtocol`:
+/// `BundleAssetProtocol::new("csv", CsvRecordsAssetProcessor::<T>::default())`.
+pub struct CsvRecordsAssetProcessor<T: Serialize + DeserializeOwned + Send + Sync + 'static> {
+    delimiter: u8,
+    has_headers: bool,
+    #[allow(clippy::type_complexity)]
+    dependencies: Option<Box<dyn Fn(&[T]) -> Vec<AssetPathStatic> + Send + Sync>>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> Default
+    for CsvRecordsAssetProcessor<T>
+{
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_headers: true,
+            dependencies: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> CsvRecordsAssetProcessor<T> {
+    /// Sets field delimiter.
+    ///
+    /// # Arguments
+    /// - `delimiter`: Byte separating fields in a row.
+    ///
+    /// # Returns
+    /// The updated `CsvRecordsAssetProcessor` instance.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether first row is a header row.
+    /// With header row, records are deserialized by column names, otherwise
+    /// by column order.
+    ///
+    /// # Arguments
+    /// - `has_headers`: True if first row contains column names.
+    ///
+    /// # Returns
+    /// The updated `CsvRecordsAssetProcessor` instance.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Sets function that reports asset dependencies of decoded records.
+    ///
+    /// # Arguments
+    /// - `dependencies`: Function returning asset paths decoded records depend on.
+    ///
+    /// # Returns
+    /// The updated `CsvRecordsAssetProcessor` instance.
+    pub fn dependencies(
+        mut self,
+        dependencies: impl Fn(&[T]) -> Vec<AssetPathStatic> + Send + Sync + 'static,
+    ) -> Self {
+        self.dependencies = Some(Box::new(dependencies));
+        self
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> BundleWithDependenciesProcessor
+    for CsvRecordsAssetProcessor<T>
+where
+    Vec<T>: Component,
+{
+    type Bundle = (Vec<T>,);
+
+    fn process_bytes(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<BundleWithDependencies<Self::Bundle>, Box<dyn Error>> {
+        let records = ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .from_reader(bytes.as_slice())
+            .deserialize::<T>()
+            .collect::<Result<Vec<_>, _>>()?;
+        let dependencies = self
+            .dependencies
+            .as_ref()
+            .map(|dependencies| dependencies(&records))
+            .unwrap_or_default();
+        Ok(BundleWithDependencies::new((records,)).dependencies(dependencies))
+    }
+
+    fn produce_bytes(
+        &mut self,
+        inspector: AssetInspector,
+    ) -> Result<StoreWithDependencies, Box<dyn Error>> {
+        let records = inspector.access_checked::<&Vec<T>>().ok_or_else(|| {
+            format!(
+                "Could not get {} asset component",
+                std::any::type_name::<Vec<T>>(),
+            )
+        })?;
+        let mut writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .from_writer(Vec::default());
+        for record in records {
+            writer.serialize(record)?;
+        }
+        let bytes = writer.into_inner().map_err(|error| error.to_string())?;
+        Ok(StoreWithDependencies::new(bytes))
+    }
+}
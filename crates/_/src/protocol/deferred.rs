@@ -0,0 +1,229 @@
+use crate::{
+    database::{
+        AssetLoadError,
+        handle::AssetHandle,
+        path::{AssetPath, AssetPathStatic},
+        report_processing_failure,
+    },
+    fetch::stream::AssetByteStream,
+    protocol::{AssetBytesDecoder, AssetProtocol, future::AssetAwaitsAsyncProcessing},
+};
+use anput::{
+    bundle::DynamicBundle, third_party::intuicio_data::managed::value::ManagedValue, world::World,
+};
+use moirai::{
+    job::{JobHandle, JobLocation, JobResult},
+    jobs::Jobs,
+};
+use std::{collections::HashMap, error::Error};
+
+fn asset_path(storage: &World, handle: AssetHandle) -> String {
+    storage
+        .component::<true, AssetPathStatic>(handle.entity())
+        .map(|path| path.to_string())
+        .unwrap_or_default()
+}
+
+/// Protocol wrapper that processes asset bytes on background jobs, keeping
+/// main thread responsive during heavy decoding.
+///
+/// Only protocols providing `AssetProtocol::bytes_decoder` can be deferred,
+/// others keep processing their assets in place. Assets awaiting their jobs
+/// are marked with `AssetAwaitsAsyncProcessing` and get decoded components
+/// inserted in one of later maintenances.
+pub struct DeferredAssetProtocol<Protocol: AssetProtocol> {
+    protocol: Protocol,
+    jobs: ManagedValue<Jobs>,
+    drive_jobs: bool,
+    job_handles: HashMap<AssetHandle, JobHandle<Result<DynamicBundle, String>>>,
+}
+
+impl<Protocol: AssetProtocol> DeferredAssetProtocol<Protocol> {
+    /// Creates a new `DeferredAssetProtocol` wrapping inner protocol.
+    ///
+    /// # Arguments
+    /// - `protocol`: The inner protocol that decodes asset bytes in the background.
+    ///
+    /// # Returns
+    /// - A new `DeferredAssetProtocol` instance.
+    pub fn new(protocol: Protocol) -> Self {
+        Self {
+            protocol,
+            jobs: ManagedValue::Owned(Default::default()),
+            drive_jobs: true,
+            job_handles: Default::default(),
+        }
+    }
+
+    /// Sets the jobs for the deferred asset protocol.
+    ///
+    /// Jobs runner shared with other deferred engines is not driven by this
+    /// protocol, unless it gets marked with `drive_jobs`.
+    ///
+    /// # Arguments
+    /// - `jobs`: The jobs runner to be managed by the deferred asset protocol.
+    ///
+    /// # Returns
+    /// - A new `DeferredAssetProtocol` instance with the updated jobs.
+    pub fn jobs(mut self, jobs: impl Into<ManagedValue<Jobs>>) -> Self {
+        self.jobs = jobs.into();
+        self.drive_jobs = matches!(self.jobs, ManagedValue::Owned(_));
+        self
+    }
+
+    /// Makes this protocol run local jobs of its jobs runner on maintenance,
+    /// even if jobs runner is shared.
+    ///
+    /// # Returns
+    /// - A new `DeferredAssetProtocol` instance driving its jobs runner.
+    pub fn drive_jobs(mut self) -> Self {
+        self.drive_jobs = true;
+        self
+    }
+
+    /// Returns the inner protocol.
+    pub fn into_inner(self) -> Protocol {
+        self.protocol
+    }
+}
+
+impl<Protocol: AssetProtocol> AssetProtocol for DeferredAssetProtocol<Protocol> {
+    fn name(&self) -> &str {
+        self.protocol.name()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.protocol.extensions()
+    }
+
+    fn sniff(&self, bytes: &[u8]) -> bool {
+        self.protocol.sniff(bytes)
+    }
+
+    fn bytes_decoder(&self) -> Option<AssetBytesDecoder> {
+        self.protocol.bytes_decoder()
+    }
+
+    fn extract_bundle_from_path(&self, path: &AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        self.protocol.extract_bundle_from_path(path)
+    }
+
+    fn rewrite_path(&self, path: AssetPathStatic) -> Result<AssetPathStatic, Box<dyn Error>> {
+        self.protocol.rewrite_path(path)
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(decoder) = self.protocol.bytes_decoder() else {
+            return self.protocol.process_bytes(handle, storage, bytes);
+        };
+        let path = asset_path(storage, handle);
+        let job = async move {
+            decoder(bytes).map_err(|error| {
+                format!("Failed deferred processing of asset: `{path}`. Error: {error}")
+            })
+        };
+        let jobs = self
+            .jobs
+            .read()
+            .ok_or("Failed to get read access to jobs runner in deferred processing.")?;
+        let job_handle = jobs.spawn(JobLocation::other_than_current_thread(), job);
+        self.job_handles.insert(handle, job_handle);
+        storage.insert(handle.entity(), (AssetAwaitsAsyncProcessing,))?;
+        Ok(())
+    }
+
+    fn process_stream(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        stream: AssetByteStream,
+    ) -> Result<(), Box<dyn Error>> {
+        self.protocol.process_stream(handle, storage, stream)
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        self.protocol.produce_bytes(handle, storage)
+    }
+
+    fn produce_asset_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<(), Box<dyn Error>> {
+        self.protocol.produce_asset_bytes(handle, storage)
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        if self.drive_jobs {
+            self.jobs
+                .read()
+                .ok_or(
+                    "Failed to get read access to jobs runner in deferred protocol maintainance.",
+                )?
+                .run_local();
+        }
+
+        self.protocol.maintain(storage)?;
+
+        // Assets unloaded or cancelled while still being processed do not need
+        // their jobs anymore.
+        self.job_handles.retain(|handle, job_handle| {
+            let awaits =
+                storage.has_entity_component::<AssetAwaitsAsyncProcessing>(handle.entity());
+            if !awaits {
+                job_handle.cancel();
+            }
+            awaits
+        });
+
+        let complete = self
+            .job_handles
+            .iter()
+            .filter(|(_, job_handle)| job_handle.is_done())
+            .map(|(handle, _)| *handle)
+            .collect::<Vec<_>>();
+        // Failed assets are reported the same way as synchronous processing
+        // does, and only first error gets returned after all jobs are handled.
+        let mut result = Ok(());
+        for handle in complete {
+            let job_handle = self.job_handles.remove(&handle).unwrap();
+            let error = match job_handle.take() {
+                JobResult::Completed(Ok(bundle)) => {
+                    storage.remove::<(AssetAwaitsAsyncProcessing,)>(handle.entity())?;
+                    storage.insert(handle.entity(), bundle)?;
+                    if storage.has_entity_component::<AssetLoadError>(handle.entity()) {
+                        storage.remove::<(AssetLoadError,)>(handle.entity())?;
+                    }
+                    continue;
+                }
+                JobResult::Completed(Err(error)) => error,
+                JobResult::Cancelled | JobResult::Consumed => format!(
+                    "Deferred processing of asset: `{}` failed with undefined error!",
+                    asset_path(storage, handle)
+                ),
+                JobResult::InProgress => {
+                    self.job_handles.insert(handle, job_handle);
+                    continue;
+                }
+            };
+            storage.remove::<(AssetAwaitsAsyncProcessing,)>(handle.entity())?;
+            let path = storage
+                .component::<true, AssetPathStatic>(handle.entity())?
+                .clone();
+            report_processing_failure(storage, handle, &path, error.clone())?;
+            if result.is_ok() {
+                result = Err(error.into());
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    database::{
+        handle::AssetHandle,
+        path::{AssetPath, AssetPathStatic},
+    },
+    protocol::AssetProtocol,
+};
+use anput::world::World;
+use std::error::Error;
+
+/// Component holding sorted paths of assets found in a directory.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DirectoryListing(pub Vec<AssetPathStatic>);
+
+/// Protocol implementation for directory listing assets, like `dir://levels`.
+///
+/// Works with fetch engines that produce directory listings as bytes, one
+/// file path per line, like `FileAssetFetch` does for directory paths.
+/// Listing gets decoded into `DirectoryListing` component, which allows
+/// discovering available assets at runtime. Listed paths have no protocol,
+/// unless entries protocol is set.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DirectoryListingProtocol {
+    entries_protocol: Option<String>,
+}
+
+impl DirectoryListingProtocol {
+    /// Sets protocol of listed asset paths.
+    ///
+    /// # Arguments
+    /// - `protocol`: The name of the protocol.
+    ///
+    /// # Returns
+    /// The updated `DirectoryListingProtocol` instance.
+    pub fn entries_protocol(mut self, protocol: impl ToString) -> Self {
+        self.entries_protocol = Some(protocol.to_string());
+        self
+    }
+}
+
+impl AssetProtocol for DirectoryListingProtocol {
+    fn name(&self) -> &str {
+        "dir"
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let text = std::str::from_utf8(&bytes)?;
+        let protocol = self.entries_protocol.as_deref().unwrap_or_default();
+        let mut entries = text
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| AssetPath::from_parts(protocol, line, ""))
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.path().cmp(b.path()));
+        storage.insert(handle.entity(), (DirectoryListing(entries),))?;
+        Ok(())
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let listing = storage.component::<true, DirectoryListing>(handle.entity())?;
+        Ok(listing
+            .0
+            .iter()
+            .map(|path| path.path())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .into_bytes())
+    }
+}
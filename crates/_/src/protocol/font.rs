@@ -0,0 +1,103 @@
+use crate::{
+    database::{handle::AssetHandle, path::AssetPathStatic},
+    protocol::AssetProtocol,
+};
+use anput::world::World;
+use std::error::Error;
+use ttf_parser::{Face, name_id};
+
+/// Font metadata asset component.
+///
+/// Raw font bytes are kept next to it as `Vec<u8>` component, so they can be
+/// passed to a rasterizer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FontFace {
+    /// Font family name. Empty if font does not report any.
+    pub family: String,
+    /// Number of font design units per em square.
+    pub units_per_em: u16,
+    /// Number of glyphs in the font.
+    pub glyph_count: u16,
+}
+
+/// Protocol implementation for TrueType/OpenType font assets.
+///
+/// Parses font bytes into `FontFace` component and keeps raw bytes as
+/// `Vec<u8>` component.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FontAssetProtocol {
+    index: u32,
+}
+
+impl FontAssetProtocol {
+    /// Sets index of the face to read from font collection files.
+    /// By default first face is read.
+    ///
+    /// # Arguments
+    /// - `index`: Face index in font collection.
+    ///
+    /// # Returns
+    /// The updated `FontAssetProtocol` instance.
+    pub fn with_face_index(mut self, index: u32) -> Self {
+        self.index = index;
+        self
+    }
+}
+
+impl AssetProtocol for FontAssetProtocol {
+    fn name(&self) -> &str {
+        "font"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ttf", "otf", "ttc", "otc"]
+    }
+
+    fn sniff(&self, bytes: &[u8]) -> bool {
+        [b"\x00\x01\x00\x00", b"OTTO", b"true", b"ttcf"]
+            .into_iter()
+            .any(|magic| bytes.starts_with(magic))
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let face = Face::parse(&bytes, self.index).map_err(|error| {
+            format!(
+                "Malformed font asset: `{}`. Error: {}",
+                storage
+                    .component::<true, AssetPathStatic>(handle.entity())
+                    .map(|path| path.content().to_owned())
+                    .unwrap_or_default(),
+                error
+            )
+        })?;
+        let family = face
+            .names()
+            .into_iter()
+            .filter(|name| name.name_id == name_id::FAMILY)
+            .find_map(|name| name.to_string())
+            .unwrap_or_default();
+        let font = FontFace {
+            family,
+            units_per_em: face.units_per_em(),
+            glyph_count: face.number_of_glyphs(),
+        };
+        storage.insert(handle.entity(), (font, bytes))?;
+        Ok(())
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let result = storage
+            .component::<true, Vec<u8>>(handle.entity())
+            .map(|bytes| bytes.to_owned())?;
+        Ok(result)
+    }
+}
@@ -15,11 +15,44 @@ use std::error::Error;
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct GroupAsset;
 
+/// Paths of members of group asset, in order they were listed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GroupMembers(pub Vec<AssetPathStatic>);
+
 /// Protocol implementation for handling "group" assets.
 ///
 /// A "group" asset is a collection of paths to other assets, usually defined in text form.
+///
+/// - Lines ending with `/**` (like `json://levels/**`) are replaced with nested
+///   group of all files beneath that directory: `group://levels?recursive&protocol=json`.
+///   This requires fetch that lists directories, like `FileAssetFetch`.
+/// - Lines without protocol get protocol from group `protocol` meta, or from
+///   their file extension otherwise.
 pub struct GroupAssetProtocol;
 
+impl GroupAssetProtocol {
+    fn member_path(group: &AssetPath, line: &str) -> AssetPathStatic {
+        let path = AssetPath::new(line);
+        if let Some(directory) = path.path().strip_suffix("/**") {
+            let meta = if path.protocol().is_empty() {
+                "recursive".to_owned()
+            } else {
+                format!("recursive&protocol={}", path.protocol())
+            };
+            return AssetPath::from_parts("group", directory, &meta);
+        }
+        if path.protocol().is_empty() {
+            let protocol = group
+                .meta_value("protocol")
+                .or_else(|| path.path_extension());
+            if let Some(protocol) = protocol {
+                return AssetPath::from_parts(protocol, path.path(), path.meta());
+            }
+        }
+        path.into_static()
+    }
+}
+
 impl AssetProtocol for GroupAssetProtocol {
     fn name(&self) -> &str {
         "group"
@@ -36,20 +69,25 @@ impl AssetProtocol for GroupAssetProtocol {
             std::mem::take(&mut bytes.0)
         };
         storage.remove::<(AssetBytesAreReadyToProcess,)>(handle.entity())?;
+        let group = storage
+            .component::<true, AssetPathStatic>(handle.entity())?
+            .clone();
+        let mut members = Vec::default();
         for line in std::str::from_utf8(&bytes)?
             .lines()
             .map(|line| line.trim())
             .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with(';'))
         {
-            let path = AssetPath::new(line.to_owned()).into_static();
+            let path = Self::member_path(&group, line);
             let entity = if let Some(entity) = storage.find_by::<true, _>(&path) {
                 entity
             } else {
                 storage.spawn((path.clone(), AssetAwaitsResolution))?
             };
             storage.relate::<true, _>(AssetDependency, handle.entity(), entity)?;
+            members.push(path);
         }
-        storage.insert(handle.entity(), (GroupAsset,))?;
+        storage.insert(handle.entity(), (GroupAsset, GroupMembers(members)))?;
         Ok(())
     }
 
@@ -0,0 +1,177 @@
+use crate::{
+    database::{handle::AssetHandle, path::AssetPathStatic},
+    protocol::{AssetBytesDecoder, AssetProtocol},
+};
+use anput::{bundle::DynamicBundle, world::World};
+use image::{
+    DynamicImage, ImageBuffer, ImageFormat, Luma, LumaA, Rgb, Rgba, guess_format,
+    load_from_memory_with_format,
+};
+use std::{error::Error, io::Cursor, sync::Arc};
+
+/// Pixel format of decoded image data.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodedImageFormat {
+    /// Single 8-bit luminance channel.
+    Luma8,
+    /// 8-bit luminance and alpha channels.
+    LumaAlpha8,
+    /// 8-bit red, green and blue channels.
+    Rgb8,
+    /// 8-bit red, green, blue and alpha channels.
+    #[default]
+    Rgba8,
+}
+
+impl DecodedImageFormat {
+    /// Returns number of bytes single pixel takes in this format.
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::Luma8 => 1,
+            Self::LumaAlpha8 => 2,
+            Self::Rgb8 => 3,
+            Self::Rgba8 => 4,
+        }
+    }
+}
+
+fn decode_image(bytes: &[u8], format: DecodedImageFormat) -> Result<DecodedImage, Box<dyn Error>> {
+    let image_format =
+        guess_format(bytes).map_err(|error| format!("Unsupported image format. Error: {error}"))?;
+    let image = load_from_memory_with_format(bytes, image_format)?;
+    let (width, height) = (image.width(), image.height());
+    let bytes = match format {
+        DecodedImageFormat::Luma8 => image.into_luma8().into_raw(),
+        DecodedImageFormat::LumaAlpha8 => image.into_luma_alpha8().into_raw(),
+        DecodedImageFormat::Rgb8 => image.into_rgb8().into_raw(),
+        DecodedImageFormat::Rgba8 => image.into_rgba8().into_raw(),
+    };
+    Ok(DecodedImage {
+        width,
+        height,
+        format,
+        bytes,
+    })
+}
+
+/// Decoded image asset component with raw pixel data.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DecodedImage {
+    /// Width of the image in pixels.
+    pub width: u32,
+    /// Height of the image in pixels.
+    pub height: u32,
+    /// Pixel format of `bytes`.
+    pub format: DecodedImageFormat,
+    /// Raw pixel data, row by row.
+    pub bytes: Vec<u8>,
+}
+
+/// Protocol implementation for decoding image assets (PNG, JPEG, etc.)
+/// into `DecodedImage` component with pixel data in requested format.
+///
+/// Stored images are encoded as PNG.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImageAssetProtocol {
+    format: DecodedImageFormat,
+}
+
+impl ImageAssetProtocol {
+    /// Sets pixel format decoded images are converted to.
+    ///
+    /// # Arguments
+    /// - `format`: Target pixel format.
+    ///
+    /// # Returns
+    /// The updated `ImageAssetProtocol` instance.
+    pub fn with_format(mut self, format: DecodedImageFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl AssetProtocol for ImageAssetProtocol {
+    fn name(&self) -> &str {
+        "image"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[
+            "png", "jpg", "jpeg", "gif", "bmp", "tga", "webp", "tif", "tiff", "ico",
+        ]
+    }
+
+    fn sniff(&self, bytes: &[u8]) -> bool {
+        guess_format(bytes).is_ok()
+    }
+
+    fn bytes_decoder(&self) -> Option<AssetBytesDecoder> {
+        let format = self.format;
+        Some(Arc::new(move |bytes| {
+            let image = decode_image(&bytes, format)?;
+            let mut bundle = DynamicBundle::default();
+            bundle
+                .add_component(image)
+                .map_err(|_| "Failed to add decoded image to bundle")?;
+            Ok(bundle)
+        }))
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let image = decode_image(&bytes, self.format).map_err(|error| {
+            format!(
+                "Failed to decode image asset: `{}`. Error: {}",
+                storage
+                    .component::<true, AssetPathStatic>(handle.entity())
+                    .map(|path| path.content().to_owned())
+                    .unwrap_or_default(),
+                error
+            )
+        })?;
+        storage.insert(handle.entity(), (image,))?;
+        Ok(())
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let decoded = storage.component::<true, DecodedImage>(handle.entity())?;
+        let DecodedImage {
+            width,
+            height,
+            format,
+            bytes,
+        } = &*decoded;
+        let (width, height) = (*width, *height);
+        let bytes = bytes.to_owned();
+        let invalid_size = || format!("Image pixel data does not match {width}x{height} size");
+        let image = match format {
+            DecodedImageFormat::Luma8 => DynamicImage::ImageLuma8(
+                ImageBuffer::<Luma<u8>, _>::from_raw(width, height, bytes)
+                    .ok_or_else(invalid_size)?,
+            ),
+            DecodedImageFormat::LumaAlpha8 => DynamicImage::ImageLumaA8(
+                ImageBuffer::<LumaA<u8>, _>::from_raw(width, height, bytes)
+                    .ok_or_else(invalid_size)?,
+            ),
+            DecodedImageFormat::Rgb8 => DynamicImage::ImageRgb8(
+                ImageBuffer::<Rgb<u8>, _>::from_raw(width, height, bytes)
+                    .ok_or_else(invalid_size)?,
+            ),
+            DecodedImageFormat::Rgba8 => DynamicImage::ImageRgba8(
+                ImageBuffer::<Rgba<u8>, _>::from_raw(width, height, bytes)
+                    .ok_or_else(invalid_size)?,
+            ),
+        };
+        let mut result = Vec::default();
+        image.write_to(&mut Cursor::new(&mut result), ImageFormat::Png)?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{database::handle::AssetHandle, protocol::AssetProtocol};
+use anput::world::World;
+use std::error::Error;
+
+/// Protocol implementation for line-oriented text assets.
+///
+/// Decodes UTF-8 text and splits it into `Vec<String>` component, one entry
+/// per line. Both LF and CRLF line endings are supported. By default trailing
+/// empty lines are dropped and no lines are treated as comments.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LinesAssetProtocol {
+    keep_trailing_empty_lines: bool,
+    comment_prefix: Option<String>,
+}
+
+impl LinesAssetProtocol {
+    /// Makes protocol keep empty lines at the end of the text.
+    ///
+    /// # Returns
+    /// The updated `LinesAssetProtocol` instance.
+    pub fn keep_trailing_empty_lines(mut self) -> Self {
+        self.keep_trailing_empty_lines = true;
+        self
+    }
+
+    /// Makes protocol skip lines starting with given prefix, ignoring
+    /// leading whitespaces.
+    ///
+    /// # Arguments
+    /// - `prefix`: Comment line prefix, for example `#`.
+    ///
+    /// # Returns
+    /// The updated `LinesAssetProtocol` instance.
+    pub fn comment_prefix(mut self, prefix: impl ToString) -> Self {
+        self.comment_prefix = Some(prefix.to_string());
+        self
+    }
+}
+
+impl AssetProtocol for LinesAssetProtocol {
+    fn name(&self) -> &str {
+        "lines"
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let text = std::str::from_utf8(&bytes)?;
+        let mut lines = text
+            .lines()
+            .filter(|line| {
+                self.comment_prefix
+                    .as_deref()
+                    .map(|prefix| !line.trim_start().starts_with(prefix))
+                    .unwrap_or(true)
+            })
+            .map(|line| line.to_owned())
+            .collect::<Vec<_>>();
+        if !self.keep_trailing_empty_lines {
+            while lines.last().map(|line| line.is_empty()).unwrap_or_default() {
+                lines.pop();
+            }
+        }
+        storage.insert(handle.entity(), (lines,))?;
+        Ok(())
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let lines = storage.component::<true, Vec<String>>(handle.entity())?;
+        let mut result = String::default();
+        for line in lines.iter() {
+            result.push_str(line);
+            result.push('\n');
+        }
+        Ok(result.into_bytes())
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{database::handle::AssetHandle, protocol::AssetProtocol};
+use anput::world::World;
+use pulldown_cmark::{Parser, html::push_html};
+use serde_json::Value;
+use std::error::Error;
+
+/// Markdown document asset component.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MarkdownDocument {
+    /// Parsed YAML front-matter. `Value::Null` if document has none.
+    pub frontmatter: Value,
+    /// Markdown content following front-matter.
+    pub body: String,
+}
+
+/// Markdown document body rendered to HTML asset component.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MarkdownHtml(pub String);
+
+/// Protocol implementation for Markdown assets with optional YAML
+/// front-matter.
+///
+/// Front-matter is a block at the very start of the document, delimited by
+/// `---` lines. It is parsed into `MarkdownDocument::frontmatter`, while the
+/// rest of the document goes into `MarkdownDocument::body`. Optionally body
+/// gets also rendered into `MarkdownHtml` component.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MarkdownAssetProtocol {
+    html: bool,
+}
+
+impl MarkdownAssetProtocol {
+    /// Makes protocol render document body into `MarkdownHtml` component.
+    ///
+    /// # Returns
+    /// The updated `MarkdownAssetProtocol` instance.
+    pub fn with_html(mut self) -> Self {
+        self.html = true;
+        self
+    }
+
+    /// Splits document text into optional front-matter and body.
+    ///
+    /// # Arguments
+    /// - `text`: Document text.
+    ///
+    /// # Returns
+    /// Tuple of front-matter content (without delimiters) and body.
+    pub fn split_frontmatter(text: &str) -> (Option<&str>, &str) {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let Some(rest) = text
+            .strip_prefix("---\r\n")
+            .or_else(|| text.strip_prefix("---\n"))
+        else {
+            return (None, text);
+        };
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim_end_matches(['\r', '\n']) == "---" {
+                return (Some(&rest[..offset]), &rest[(offset + line.len())..]);
+            }
+            offset += line.len();
+        }
+        (None, text)
+    }
+}
+
+impl AssetProtocol for MarkdownAssetProtocol {
+    fn name(&self) -> &str {
+        "markdown"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["md", "markdown"]
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let text = std::str::from_utf8(&bytes)?;
+        let (frontmatter, body) = Self::split_frontmatter(text);
+        let frontmatter = match frontmatter {
+            Some(frontmatter) if !frontmatter.trim().is_empty() => {
+                serde_yaml::from_str::<Value>(frontmatter)?
+            }
+            _ => Value::Null,
+        };
+        let document = MarkdownDocument {
+            frontmatter,
+            body: body.to_owned(),
+        };
+        if self.html {
+            let mut html = String::default();
+            push_html(&mut html, Parser::new(&document.body));
+            storage.insert(handle.entity(), (document, MarkdownHtml(html)))?;
+        } else {
+            storage.insert(handle.entity(), (document,))?;
+        }
+        Ok(())
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let document = storage.component::<true, MarkdownDocument>(handle.entity())?;
+        let mut result = String::default();
+        if !document.frontmatter.is_null() {
+            result.push_str("---\n");
+            result.push_str(&serde_yaml::to_string(&document.frontmatter)?);
+            result.push_str("---\n");
+        }
+        result.push_str(&document.body);
+        Ok(result.into_bytes())
+    }
+}
@@ -1,19 +1,48 @@
+#[cfg(feature = "bincode")]
+pub mod bincode;
 pub mod bundle;
 pub mod bytes;
+#[cfg(feature = "verify")]
+pub mod checksum;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod deferred;
+pub mod directory;
+#[cfg(feature = "font")]
+pub mod font;
 pub mod future;
 pub mod group;
+#[cfg(feature = "image")]
+pub mod image;
+pub mod lines;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+pub mod pipeline;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+#[cfg(feature = "ron")]
+pub mod ron;
 pub mod text;
+#[cfg(feature = "xml")]
+pub mod xml;
 
 use crate::{
     database::{
         handle::AssetHandle,
         path::{AssetPath, AssetPathStatic},
     },
-    fetch::AssetBytesAreReadyToProcess,
+    fetch::{AssetBytesAreReadyToProcess, stream::AssetByteStream},
     store::AssetBytesAreReadyToStore,
 };
 use anput::{bundle::DynamicBundle, world::World};
-use std::error::Error;
+use std::{error::Error, sync::Arc};
+
+/// Thread-safe decoder turning asset bytes into bundle of asset components,
+/// without access to storage.
+pub type AssetBytesDecoder =
+    Arc<dyn Fn(Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> + Send + Sync>;
 
 /// Trait defining the protocol for processing and handling assets.
 ///
@@ -25,6 +54,55 @@ pub trait AssetProtocol: Send + Sync {
     /// This name can be used for identification or debugging purposes.
     fn name(&self) -> &str;
 
+    /// Returns file extensions of assets this protocol handles.
+    ///
+    /// This function is optional to override. It is used for automatic
+    /// protocol selection, like `AssetDatabase::protocol_for_extension`.
+    ///
+    /// # Returns
+    /// - List of file extensions, without leading dot.
+    ///
+    /// # Default Implementation
+    /// Returns empty list.
+    fn extensions(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Tells if asset content looks like something this protocol handles.
+    ///
+    /// This function is optional to override. It is used for automatic
+    /// protocol selection, usually by checking magic bytes of the content,
+    /// like `AssetDatabase::protocol_for_bytes`.
+    ///
+    /// # Arguments
+    /// - `bytes`: Beginning of asset content.
+    ///
+    /// # Returns
+    /// - `true` if protocol recognizes content, otherwise `false`.
+    ///
+    /// # Default Implementation
+    /// Returns `false`.
+    #[allow(unused_variables)]
+    fn sniff(&self, bytes: &[u8]) -> bool {
+        false
+    }
+
+    /// Returns decoder able to process asset bytes off the main thread.
+    ///
+    /// This function is optional to override. Protocols whose processing
+    /// only turns bytes into components, without reading or writing other
+    /// entities, can provide it so `AssetDatabase::maintain_parallel` can
+    /// decode their assets on worker threads.
+    ///
+    /// # Returns
+    /// - `Some` decoder if protocol supports parallel processing.
+    ///
+    /// # Default Implementation
+    /// Returns `None`.
+    fn bytes_decoder(&self) -> Option<AssetBytesDecoder> {
+        None
+    }
+
     /// Extracts a dynamic bundle from the given asset path.
     ///
     /// This function is optional to override. It is called when an asset is
@@ -91,6 +169,33 @@ pub trait AssetProtocol: Send + Sync {
         Ok(())
     }
 
+    /// Processes streamed asset content.
+    ///
+    /// This function is optional to override. It is called instead of
+    /// `process_bytes` for assets fetched as `AssetByteStream`, allowing
+    /// implementers to process content chunk by chunk without buffering it.
+    ///
+    /// # Arguments
+    /// - `handle`: The handle of the asset being processed.
+    /// - `storage`: The world storage containing all asset-related data.
+    /// - `stream`: The stream of asset content bytes.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - An error wrapped in `Box<dyn Error>` if processing fails.
+    ///
+    /// # Default Implementation
+    /// Reads whole stream into bytes and passes them to `process_bytes`.
+    fn process_stream(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        stream: AssetByteStream,
+    ) -> Result<(), Box<dyn Error>> {
+        let bytes = stream.read_all()?;
+        self.process_bytes(handle, storage, bytes)
+    }
+
     /// Processes an asset by first retrieving its raw byte data and then
     /// delegating to `process_bytes`.
     ///
@@ -99,7 +204,11 @@ pub trait AssetProtocol: Send + Sync {
     ///    with the asset's entity.
     /// 2. Extracts the raw byte data from the component.
     /// 3. Removes the `AssetBytesAreReadyToProcess` component from the entity.
-    /// 4. Passes the byte data to `process_bytes` for further processing.
+    /// 4. Passes the byte data to `process_bytes` for further processing, or
+    ///    `AssetByteStream` component (if present) to `process_stream`.
+    ///
+    /// Processing failures of zero-length assets are reported as empty asset
+    /// errors.
     ///
     /// # Arguments
     /// - `handle`: The handle of the asset being processed.
@@ -119,6 +228,25 @@ pub trait AssetProtocol: Send + Sync {
             std::mem::take(&mut bytes.0)
         };
         storage.remove::<(AssetBytesAreReadyToProcess,)>(handle.entity())?;
+        if storage.has_entity_component::<AssetByteStream>(handle.entity()) {
+            let stream = {
+                let mut stream = storage.component_mut::<true, AssetByteStream>(handle.entity())?;
+                std::mem::take(&mut *stream)
+            };
+            storage.remove::<(AssetByteStream,)>(handle.entity())?;
+            return self.process_stream(handle, storage, stream);
+        }
+        if bytes.is_empty() {
+            // Many decoders fail on empty input with cryptic errors, so make
+            // it clear that the asset itself is empty.
+            return self.process_bytes(handle, storage, bytes).map_err(|error| {
+                let path = storage
+                    .component::<true, AssetPathStatic>(handle.entity())
+                    .map(|path| path.to_string())
+                    .unwrap_or_default();
+                format!("Failed to process empty asset: `{path}`. Error: {error}").into()
+            });
+        }
         self.process_bytes(handle, storage, bytes)
     }
 
@@ -0,0 +1,99 @@
+use crate::{
+    database::{inspector::AssetInspector, path::AssetPathStatic},
+    protocol::bundle::{
+        BundleAssetProtocol, BundleWithDependencies, BundleWithDependenciesProcessor,
+        StoreWithDependencies,
+    },
+};
+use anput::component::Component;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{error::Error, marker::PhantomData};
+
+/// Bundle protocol that decodes MessagePack assets into `T` component.
+pub type MessagePackAssetProtocol<T> = BundleAssetProtocol<MessagePackAssetProcessor<T>>;
+
+/// Processor for MessagePack binary assets.
+///
+/// Decodes asset bytes into `T` component and encodes it back when asset gets
+/// stored. Meant to be used with `BundleAssetProtocol`:
+/// `BundleAssetProtocol::new("msgpack", MessagePackAssetProcessor::<T>::default())`.
+pub struct MessagePackAssetProcessor<T: Component + Serialize + DeserializeOwned> {
+    struct_map: bool,
+    #[allow(clippy::type_complexity)]
+    dependencies: Option<Box<dyn Fn(&T) -> Vec<AssetPathStatic> + Send + Sync>>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Component + Serialize + DeserializeOwned> Default for MessagePackAssetProcessor<T> {
+    fn default() -> Self {
+        Self {
+            struct_map: false,
+            dependencies: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Component + Serialize + DeserializeOwned> MessagePackAssetProcessor<T> {
+    /// Makes stored assets encode structs as maps with field names.
+    /// By default structs are encoded as compact arrays of field values.
+    ///
+    /// # Returns
+    /// The updated `MessagePackAssetProcessor` instance.
+    pub fn struct_map(mut self) -> Self {
+        self.struct_map = true;
+        self
+    }
+
+    /// Sets function that reports asset dependencies of decoded asset.
+    ///
+    /// # Arguments
+    /// - `dependencies`: Function returning asset paths decoded asset depends on.
+    ///
+    /// # Returns
+    /// The updated `MessagePackAssetProcessor` instance.
+    pub fn dependencies(
+        mut self,
+        dependencies: impl Fn(&T) -> Vec<AssetPathStatic> + Send + Sync + 'static,
+    ) -> Self {
+        self.dependencies = Some(Box::new(dependencies));
+        self
+    }
+}
+
+impl<T: Component + Serialize + DeserializeOwned> BundleWithDependenciesProcessor
+    for MessagePackAssetProcessor<T>
+{
+    type Bundle = (T,);
+
+    fn process_bytes(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<BundleWithDependencies<Self::Bundle>, Box<dyn Error>> {
+        let asset = rmp_serde::from_slice::<T>(&bytes)?;
+        let dependencies = self
+            .dependencies
+            .as_ref()
+            .map(|dependencies| dependencies(&asset))
+            .unwrap_or_default();
+        Ok(BundleWithDependencies::new((asset,)).dependencies(dependencies))
+    }
+
+    fn produce_bytes(
+        &mut self,
+        inspector: AssetInspector,
+    ) -> Result<StoreWithDependencies, Box<dyn Error>> {
+        let asset = inspector.access_checked::<&T>().ok_or_else(|| {
+            format!(
+                "Could not get {} asset component",
+                std::any::type_name::<T>(),
+            )
+        })?;
+        let bytes = if self.struct_map {
+            rmp_serde::to_vec_named(asset)?
+        } else {
+            rmp_serde::to_vec(asset)?
+        };
+        Ok(StoreWithDependencies::new(bytes))
+    }
+}
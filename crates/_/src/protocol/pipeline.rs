@@ -0,0 +1,121 @@
+use crate::{
+    database::{
+        handle::AssetHandle,
+        path::{AssetPath, AssetPathStatic},
+    },
+    protocol::{
+        AssetProtocol,
+        bundle::{BundleAssetProtocol, BundleWithDependenciesProcessor},
+    },
+};
+use anput::{bundle::DynamicBundle, world::World};
+use std::error::Error;
+
+/// Single byte transformation stage of pipeline protocol.
+pub type PipelineStage = Box<dyn FnMut(Vec<u8>) -> Result<Vec<u8>, Box<dyn Error>> + Send + Sync>;
+
+/// Protocol passing asset bytes through ordered list of transformation
+/// stages (like decompression or decryption), before terminal processor
+/// turns them into asset bundle.
+///
+/// Useful when transformation is specific to protocol, for example loading
+/// `.json.gz` assets as "decompress then parse JSON". Produced bytes are
+/// not transformed back by stages.
+pub struct PipelineProtocol<Processor: BundleWithDependenciesProcessor> {
+    stages: Vec<PipelineStage>,
+    protocol: BundleAssetProtocol<Processor>,
+}
+
+impl<Processor: BundleWithDependenciesProcessor> PipelineProtocol<Processor> {
+    /// Creates a new pipeline protocol with no stages.
+    ///
+    /// # Arguments
+    /// - `name`: Name of protocol.
+    /// - `processor`: Terminal processor turning transformed bytes into bundle.
+    ///
+    /// # Returns
+    /// A new `PipelineProtocol` instance.
+    pub fn new(name: impl ToString, processor: Processor) -> Self {
+        Self {
+            stages: Default::default(),
+            protocol: BundleAssetProtocol::new(name, processor),
+        }
+    }
+
+    /// Adds transformation stage, invoked after previously added stages.
+    ///
+    /// # Arguments
+    /// - `stage`: Function transforming bytes.
+    ///
+    /// # Returns
+    /// The updated `PipelineProtocol` instance.
+    pub fn with_stage(
+        mut self,
+        stage: impl FnMut(Vec<u8>) -> Result<Vec<u8>, Box<dyn Error>> + Send + Sync + 'static,
+    ) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+
+    /// Sets file extensions of assets this protocol handles.
+    ///
+    /// # Arguments
+    /// - `extensions`: File extensions, without leading dot.
+    ///
+    /// # Returns
+    /// The updated `PipelineProtocol` instance.
+    pub fn with_extensions(mut self, extensions: impl IntoIterator<Item = &'static str>) -> Self {
+        self.protocol = self.protocol.with_extensions(extensions);
+        self
+    }
+}
+
+impl<Processor: BundleWithDependenciesProcessor> AssetProtocol for PipelineProtocol<Processor> {
+    fn name(&self) -> &str {
+        self.protocol.name()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.protocol.extensions()
+    }
+
+    fn extract_bundle_from_path(&self, path: &AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
+        self.protocol.extract_bundle_from_path(path)
+    }
+
+    fn rewrite_path(&self, path: AssetPathStatic) -> Result<AssetPathStatic, Box<dyn Error>> {
+        self.protocol.rewrite_path(path)
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut bytes = bytes;
+        for (index, stage) in self.stages.iter_mut().enumerate() {
+            bytes = stage(bytes).map_err(|error| {
+                format!(
+                    "Pipeline protocol: `{}` failed at stage: {}. Error: {}",
+                    self.protocol.name(),
+                    index,
+                    error
+                )
+            })?;
+        }
+        self.protocol.process_bytes(handle, storage, bytes)
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        self.protocol.produce_bytes(handle, storage)
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        self.protocol.maintain(storage)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    database::{inspector::AssetInspector, path::AssetPathStatic},
+    protocol::bundle::{
+        BundleAssetProtocol, BundleWithDependencies, BundleWithDependenciesProcessor,
+        StoreWithDependencies,
+    },
+};
+use anput::component::Component;
+use prost::Message;
+use std::{error::Error, marker::PhantomData};
+
+/// Bundle protocol that decodes protobuf assets into `M` message component.
+pub type ProtobufAssetProtocol<M> = BundleAssetProtocol<ProtobufAssetProcessor<M>>;
+
+/// Processor for protobuf binary assets.
+///
+/// Decodes asset bytes into `M` message component and encodes it back when
+/// asset gets stored. Meant to be used with `BundleAssetProtocol`:
+/// `BundleAssetProtocol::new("protobuf", ProtobufAssetProcessor::<M>::default())`.
+pub struct ProtobufAssetProcessor<M: Component + Message + Default> {
+    #[allow(clippy::type_complexity)]
+    dependencies: Option<Box<dyn Fn(&M) -> Vec<AssetPathStatic> + Send + Sync>>,
+    _phantom: PhantomData<fn() -> M>,
+}
+
+impl<M: Component + Message + Default> Default for ProtobufAssetProcessor<M> {
+    fn default() -> Self {
+        Self {
+            dependencies: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<M: Component + Message + Default> ProtobufAssetProcessor<M> {
+    /// Sets function that reports asset dependencies of decoded message,
+    /// for example assets referenced by their IDs.
+    ///
+    /// # Arguments
+    /// - `dependencies`: Function returning asset paths decoded message depends on.
+    ///
+    /// # Returns
+    /// The updated `ProtobufAssetProcessor` instance.
+    pub fn dependencies(
+        mut self,
+        dependencies: impl Fn(&M) -> Vec<AssetPathStatic> + Send + Sync + 'static,
+    ) -> Self {
+        self.dependencies = Some(Box::new(dependencies));
+        self
+    }
+}
+
+impl<M: Component + Message + Default> BundleWithDependenciesProcessor
+    for ProtobufAssetProcessor<M>
+{
+    type Bundle = (M,);
+
+    fn process_bytes(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<BundleWithDependencies<Self::Bundle>, Box<dyn Error>> {
+        let asset = M::decode(bytes.as_slice()).map_err(|error| {
+            format!(
+                "Failed to decode {} protobuf asset. Error: {}",
+                std::any::type_name::<M>(),
+                error
+            )
+        })?;
+        let dependencies = self
+            .dependencies
+            .as_ref()
+            .map(|dependencies| dependencies(&asset))
+            .unwrap_or_default();
+        Ok(BundleWithDependencies::new((asset,)).dependencies(dependencies))
+    }
+
+    fn produce_bytes(
+        &mut self,
+        inspector: AssetInspector,
+    ) -> Result<StoreWithDependencies, Box<dyn Error>> {
+        let asset = inspector.access_checked::<&M>().ok_or_else(|| {
+            format!(
+                "Could not get {} asset component",
+                std::any::type_name::<M>(),
+            )
+        })?;
+        Ok(StoreWithDependencies::new(asset.encode_to_vec()))
+    }
+}
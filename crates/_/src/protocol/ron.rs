@@ -0,0 +1,103 @@
+use crate::{
+    database::{inspector::AssetInspector, path::AssetPathStatic},
+    protocol::bundle::{
+        BundleAssetProtocol, BundleWithDependencies, BundleWithDependenciesProcessor,
+        StoreWithDependencies,
+    },
+};
+use anput::component::Component;
+use ron::ser::PrettyConfig;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{error::Error, marker::PhantomData};
+
+/// Bundle protocol that decodes RON assets into `T` component.
+pub type RonAssetProtocol<T> = BundleAssetProtocol<RonAssetProcessor<T>>;
+
+/// Processor for RON (Rusty Object Notation) assets.
+///
+/// Deserializes asset bytes into `T` component and serializes it back when
+/// asset gets stored. Meant to be used with `BundleAssetProtocol`:
+/// `BundleAssetProtocol::new("ron", RonAssetProcessor::<T>::default())`.
+pub struct RonAssetProcessor<T: Component + Serialize + DeserializeOwned> {
+    pretty: Option<PrettyConfig>,
+    #[allow(clippy::type_complexity)]
+    dependencies: Option<Box<dyn Fn(&T) -> Vec<AssetPathStatic> + Send + Sync>>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Component + Serialize + DeserializeOwned> Default for RonAssetProcessor<T> {
+    fn default() -> Self {
+        Self {
+            pretty: None,
+            dependencies: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Component + Serialize + DeserializeOwned> RonAssetProcessor<T> {
+    /// Makes stored assets use pretty output with given configuration.
+    /// By default stored assets use compact output.
+    ///
+    /// # Arguments
+    /// - `config`: RON pretty printing configuration.
+    ///
+    /// # Returns
+    /// The updated `RonAssetProcessor` instance.
+    pub fn pretty(mut self, config: PrettyConfig) -> Self {
+        self.pretty = Some(config);
+        self
+    }
+
+    /// Sets function that reports asset dependencies of decoded asset.
+    ///
+    /// # Arguments
+    /// - `dependencies`: Function returning asset paths decoded asset depends on.
+    ///
+    /// # Returns
+    /// The updated `RonAssetProcessor` instance.
+    pub fn dependencies(
+        mut self,
+        dependencies: impl Fn(&T) -> Vec<AssetPathStatic> + Send + Sync + 'static,
+    ) -> Self {
+        self.dependencies = Some(Box::new(dependencies));
+        self
+    }
+}
+
+impl<T: Component + Serialize + DeserializeOwned> BundleWithDependenciesProcessor
+    for RonAssetProcessor<T>
+{
+    type Bundle = (T,);
+
+    fn process_bytes(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<BundleWithDependencies<Self::Bundle>, Box<dyn Error>> {
+        let asset = ron::de::from_bytes::<T>(&bytes)?;
+        let dependencies = self
+            .dependencies
+            .as_ref()
+            .map(|dependencies| dependencies(&asset))
+            .unwrap_or_default();
+        Ok(BundleWithDependencies::new((asset,)).dependencies(dependencies))
+    }
+
+    fn produce_bytes(
+        &mut self,
+        inspector: AssetInspector,
+    ) -> Result<StoreWithDependencies, Box<dyn Error>> {
+        let asset = inspector.access_checked::<&T>().ok_or_else(|| {
+            format!(
+                "Could not get {} asset component",
+                std::any::type_name::<T>(),
+            )
+        })?;
+        let content = if let Some(config) = self.pretty.as_ref() {
+            ron::ser::to_string_pretty(asset, config.clone())?
+        } else {
+            ron::ser::to_string(asset)?
+        };
+        Ok(StoreWithDependencies::new(content.into_bytes()))
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{database::handle::AssetHandle, protocol::AssetProtocol};
-use anput::world::World;
-use std::error::Error;
+use crate::{
+    database::handle::AssetHandle,
+    fetch::pool::release_asset_bytes,
+    protocol::{AssetBytesDecoder, AssetProtocol},
+};
+use anput::{bundle::DynamicBundle, world::World};
+use std::{error::Error, sync::Arc};
 
 /// Protocol implementation for handling text-based assets.
 ///
@@ -13,6 +17,21 @@ impl AssetProtocol for TextAssetProtocol {
         "text"
     }
 
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+
+    fn bytes_decoder(&self) -> Option<AssetBytesDecoder> {
+        Some(Arc::new(|bytes| {
+            let text = String::from_utf8(bytes)?;
+            let mut bundle = DynamicBundle::default();
+            bundle
+                .add_component(text)
+                .map_err(|_| "Failed to add text to bundle")?;
+            Ok(bundle)
+        }))
+    }
+
     fn process_bytes(
         &mut self,
         handle: AssetHandle,
@@ -20,6 +39,7 @@ impl AssetProtocol for TextAssetProtocol {
         bytes: Vec<u8>,
     ) -> Result<(), Box<dyn Error>> {
         let text = std::str::from_utf8(&bytes)?.to_owned();
+        release_asset_bytes(storage, handle.entity(), bytes);
         storage.insert(handle.entity(), (text,))?;
         Ok(())
     }
@@ -35,3 +55,143 @@ impl AssetProtocol for TextAssetProtocol {
         Ok(result)
     }
 }
+
+#[cfg(feature = "encoding")]
+impl TextAssetProtocol {
+    /// Creates text protocol that transcodes bytes from given encoding.
+    ///
+    /// # Arguments
+    /// - `encoding`: Encoding of asset bytes, for example `encoding_rs::WINDOWS_1252`.
+    ///
+    /// # Returns
+    /// New `EncodedTextAssetProtocol` instance.
+    pub fn with_encoding(encoding: &'static encoding_rs::Encoding) -> EncodedTextAssetProtocol {
+        EncodedTextAssetProtocol::default().with_encoding(encoding)
+    }
+}
+
+/// Protocol implementation for text assets in encodings other than UTF-8.
+///
+/// Bytes are transcoded into `String` component. By default byte order mark
+/// found at the start of the bytes overrides configured encoding, and invalid
+/// sequences produce an error.
+#[cfg(feature = "encoding")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodedTextAssetProtocol {
+    encoding: &'static encoding_rs::Encoding,
+    detect_bom: bool,
+    lossy: bool,
+}
+
+#[cfg(feature = "encoding")]
+impl Default for EncodedTextAssetProtocol {
+    fn default() -> Self {
+        Self::new(encoding_rs::UTF_8)
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl EncodedTextAssetProtocol {
+    /// Creates a new instance of `EncodedTextAssetProtocol`.
+    ///
+    /// # Arguments
+    /// - `encoding`: Encoding of asset bytes.
+    ///
+    /// # Returns
+    /// New `EncodedTextAssetProtocol` instance.
+    pub fn new(encoding: &'static encoding_rs::Encoding) -> Self {
+        Self {
+            encoding,
+            detect_bom: true,
+            lossy: false,
+        }
+    }
+
+    /// Sets encoding of asset bytes.
+    ///
+    /// # Arguments
+    /// - `encoding`: Encoding of asset bytes.
+    ///
+    /// # Returns
+    /// The updated `EncodedTextAssetProtocol` instance.
+    pub fn with_encoding(mut self, encoding: &'static encoding_rs::Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Sets whether byte order mark should override configured encoding.
+    ///
+    /// # Arguments
+    /// - `detect_bom`: True to detect encoding from byte order mark.
+    ///
+    /// # Returns
+    /// The updated `EncodedTextAssetProtocol` instance.
+    pub fn detect_bom(mut self, detect_bom: bool) -> Self {
+        self.detect_bom = detect_bom;
+        self
+    }
+
+    /// Makes invalid sequences get replaced with replacement characters
+    /// instead of producing an error.
+    ///
+    /// # Returns
+    /// The updated `EncodedTextAssetProtocol` instance.
+    pub fn lossy(mut self) -> Self {
+        self.lossy = true;
+        self
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl AssetProtocol for EncodedTextAssetProtocol {
+    fn name(&self) -> &str {
+        "text"
+    }
+
+    fn process_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+        bytes: Vec<u8>,
+    ) -> Result<(), Box<dyn Error>> {
+        let (text, encoding, had_errors) = if self.detect_bom {
+            self.encoding.decode(&bytes)
+        } else {
+            let (text, had_errors) = self.encoding.decode_without_bom_handling(&bytes);
+            (text, self.encoding, had_errors)
+        };
+        if had_errors && !self.lossy {
+            return Err(
+                format!("Text asset contains invalid {} sequences", encoding.name()).into(),
+            );
+        }
+        let text = text.into_owned();
+        release_asset_bytes(storage, handle.entity(), bytes);
+        storage.insert(handle.entity(), (text,))?;
+        Ok(())
+    }
+
+    fn produce_bytes(
+        &mut self,
+        handle: AssetHandle,
+        storage: &mut World,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let text = storage.component::<true, String>(handle.entity())?;
+        // encoding_rs does not encode into UTF-16, so it is done by hand.
+        if self.encoding == encoding_rs::UTF_16LE {
+            return Ok(text.encode_utf16().flat_map(u16::to_le_bytes).collect());
+        }
+        if self.encoding == encoding_rs::UTF_16BE {
+            return Ok(text.encode_utf16().flat_map(u16::to_be_bytes).collect());
+        }
+        let (bytes, _, had_errors) = self.encoding.encode(&text);
+        if had_errors && !self.lossy {
+            return Err(format!(
+                "Text asset contains characters unmappable to {}",
+                self.encoding.name()
+            )
+            .into());
+        }
+        Ok(bytes.into_owned())
+    }
+}
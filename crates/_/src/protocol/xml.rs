@@ -0,0 +1,142 @@
+use crate::{
+    database::{inspector::AssetInspector, path::AssetPathStatic},
+    protocol::bundle::{
+        BundleAssetProtocol, BundleWithDependencies, BundleWithDependenciesProcessor,
+        StoreWithDependencies,
+    },
+};
+use anput::component::Component;
+use quick_xml::se::Serializer;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{error::Error, marker::PhantomData};
+
+/// Bundle protocol that decodes XML assets into `T` component.
+pub type XmlAssetProtocol<T> = BundleAssetProtocol<XmlAssetProcessor<T>>;
+
+/// Processor for XML assets.
+///
+/// Deserializes asset bytes into `T` component and serializes it back when
+/// asset gets stored. Meant to be used with `BundleAssetProtocol`:
+/// `BundleAssetProtocol::new("xml", XmlAssetProcessor::<T>::default())`.
+///
+/// Mapping follows `quick-xml` serde conventions: fields renamed with `@`
+/// prefix (`#[serde(rename = "@id")]`) map to attributes, `$text` maps to
+/// element text, and other fields map to child elements. Namespaced names
+/// are matched with their prefix, like `#[serde(rename = "svg:rect")]`,
+/// while namespace declarations are regular `@xmlns:*` attributes.
+pub struct XmlAssetProcessor<T: Component + Serialize + DeserializeOwned> {
+    root: Option<String>,
+    indent: Option<(char, usize)>,
+    expand_empty_elements: bool,
+    #[allow(clippy::type_complexity)]
+    dependencies: Option<Box<dyn Fn(&T) -> Vec<AssetPathStatic> + Send + Sync>>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Component + Serialize + DeserializeOwned> Default for XmlAssetProcessor<T> {
+    fn default() -> Self {
+        Self {
+            root: None,
+            indent: None,
+            expand_empty_elements: false,
+            dependencies: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Component + Serialize + DeserializeOwned> XmlAssetProcessor<T> {
+    /// Sets name of root element of stored assets.
+    /// By default root element is named after `T` type.
+    ///
+    /// # Arguments
+    /// - `name`: Name of root element, optionally with namespace prefix.
+    ///
+    /// # Returns
+    /// The updated `XmlAssetProcessor` instance.
+    pub fn root(mut self, name: impl ToString) -> Self {
+        self.root = Some(name.to_string());
+        self
+    }
+
+    /// Makes stored assets use indented output.
+    /// By default stored assets use compact output.
+    ///
+    /// # Arguments
+    /// - `character`: Indentation character.
+    /// - `size`: Number of indentation characters per nesting level.
+    ///
+    /// # Returns
+    /// The updated `XmlAssetProcessor` instance.
+    pub fn indent(mut self, character: char, size: usize) -> Self {
+        self.indent = Some((character, size));
+        self
+    }
+
+    /// Makes stored assets write empty elements with closing tags
+    /// (`<tag></tag>`) instead of self-closing ones (`<tag/>`).
+    ///
+    /// # Returns
+    /// The updated `XmlAssetProcessor` instance.
+    pub fn expand_empty_elements(mut self) -> Self {
+        self.expand_empty_elements = true;
+        self
+    }
+
+    /// Sets function that reports asset dependencies of decoded asset.
+    ///
+    /// # Arguments
+    /// - `dependencies`: Function returning asset paths decoded asset depends on.
+    ///
+    /// # Returns
+    /// The updated `XmlAssetProcessor` instance.
+    pub fn dependencies(
+        mut self,
+        dependencies: impl Fn(&T) -> Vec<AssetPathStatic> + Send + Sync + 'static,
+    ) -> Self {
+        self.dependencies = Some(Box::new(dependencies));
+        self
+    }
+}
+
+impl<T: Component + Serialize + DeserializeOwned> BundleWithDependenciesProcessor
+    for XmlAssetProcessor<T>
+{
+    type Bundle = (T,);
+
+    fn process_bytes(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<BundleWithDependencies<Self::Bundle>, Box<dyn Error>> {
+        let asset = quick_xml::de::from_str::<T>(std::str::from_utf8(&bytes)?)?;
+        let dependencies = self
+            .dependencies
+            .as_ref()
+            .map(|dependencies| dependencies(&asset))
+            .unwrap_or_default();
+        Ok(BundleWithDependencies::new((asset,)).dependencies(dependencies))
+    }
+
+    fn produce_bytes(
+        &mut self,
+        inspector: AssetInspector,
+    ) -> Result<StoreWithDependencies, Box<dyn Error>> {
+        let asset = inspector.access_checked::<&T>().ok_or_else(|| {
+            format!(
+                "Could not get {} asset component",
+                std::any::type_name::<T>(),
+            )
+        })?;
+        let mut buffer = String::default();
+        let mut serializer = match self.root.as_deref() {
+            Some(root) => Serializer::with_root(&mut buffer, Some(root))?,
+            None => Serializer::new(&mut buffer),
+        };
+        if let Some((character, size)) = self.indent {
+            serializer.indent(character, size);
+        }
+        serializer.expand_empty_elements(self.expand_empty_elements);
+        asset.serialize(serializer)?;
+        Ok(StoreWithDependencies::new(buffer.into_bytes()))
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{
+    database::{
+        events::{AssetEvent, AssetEventBindings, AssetEventKind},
+        handle::AssetHandle,
+        path::{AssetPath, AssetPathStatic},
+    },
+    store::{AssetAwaitsAsyncStore, AssetStore},
+};
+use anput::{
+    bundle::DynamicBundle, third_party::intuicio_data::managed::value::ManagedValue, world::World,
+};
+use moirai::{
+    job::{JobHandle, JobLocation, JobResult},
+    jobs::Jobs,
+};
+use std::{
+    collections::HashMap,
+    error::Error,
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+/// A deferred asset store that queues tasks for saving asset bytes asynchronously
+/// on separate jobs, so storing does not block the main thread.
+///
+/// The `DeferredAssetStore` struct allows asset storing to occur in the background
+/// on jobs, with assets marked as awaiting async store until the task has finished.
+///
+/// Jobs of assets that stopped awaiting async store (for example when asset
+/// database got cancelled) are cancelled, and their results are discarded.
+///
+/// Saving an asset again while its previous save is still in flight cancels
+/// the previous job. Saves of the same path never write at the same time, and
+/// save that finds newer one queued skips writing, so the latest bytes always
+/// end up stored.
+pub struct DeferredAssetStore<Store: AssetStore> {
+    store: Arc<RwLock<Store>>,
+    jobs: ManagedValue<Jobs>,
+    drive_jobs: bool,
+    #[allow(clippy::type_complexity)]
+    job_handles: RwLock<HashMap<AssetPathStatic, JobHandle<Result<DynamicBundle, String>>>>,
+    #[allow(clippy::type_complexity)]
+    generations: RwLock<HashMap<AssetPathStatic, Arc<(AtomicUsize, Mutex<()>)>>>,
+}
+
+impl<Store: AssetStore> DeferredAssetStore<Store> {
+    /// Creates a new `DeferredAssetStore` with a specified asset store.
+    ///
+    /// # Arguments
+    /// - `store`: The asset store to handle saving the bytes of an asset in the background.
+    ///
+    /// # Returns
+    /// - A new `DeferredAssetStore` instance.
+    pub fn new(store: Store) -> Self {
+        Self {
+            store: Arc::new(RwLock::new(store)),
+            jobs: ManagedValue::Owned(Default::default()),
+            drive_jobs: true,
+            job_handles: Default::default(),
+            generations: Default::default(),
+        }
+    }
+
+    /// Sets the jobs for the deferred asset store.
+    ///
+    /// Owned jobs runner gets its local jobs run on every maintenance of this
+    /// store. Jobs runner shared between multiple deferred engines (passed as
+    /// `Managed::lazy()` for example) is not driven by any of them, unless one
+    /// gets marked with `drive_jobs`, so local jobs run only once per tick.
+    ///
+    /// # Arguments
+    /// - `jobs`: The jobs runner to be managed by the deferred asset store.
+    ///
+    /// # Returns
+    /// - A new `DeferredAssetStore` instance with the updated jobs.
+    pub fn jobs(mut self, jobs: impl Into<ManagedValue<Jobs>>) -> Self {
+        self.jobs = jobs.into();
+        self.drive_jobs = matches!(self.jobs, ManagedValue::Owned(_));
+        self
+    }
+
+    /// Makes this store run local jobs of its jobs runner on maintenance,
+    /// even if jobs runner is shared. Only one of engines sharing jobs runner
+    /// should drive it.
+    ///
+    /// # Returns
+    /// - A new `DeferredAssetStore` instance driving its jobs runner.
+    pub fn drive_jobs(mut self) -> Self {
+        self.drive_jobs = true;
+        self
+    }
+
+    /// Returns number of stores currently running in background jobs.
+    ///
+    /// # Returns
+    /// - Number of in-flight jobs of this engine.
+    pub fn in_flight(&self) -> usize {
+        self.job_handles
+            .read()
+            .map(|job_handles| job_handles.len())
+            .unwrap_or_default()
+    }
+}
+
+impl<Store: AssetStore> AssetStore for DeferredAssetStore<Store> {
+    fn save_bytes(&self, path: AssetPath, bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+        let path = path.into_static();
+        let path2 = path.clone();
+        let store = self.store.clone();
+        let latest = self
+            .generations
+            .write()
+            .map_err(|error| format!("{error}"))?
+            .entry(path.clone())
+            .or_default()
+            .clone();
+        let generation = latest.0.fetch_add(1, Ordering::SeqCst) + 1;
+        let job = async move {
+            // Lock is held while saving, so saves of the same path do not
+            // overlap, and outdated ones leave writing to the latest.
+            let _lock = latest.1.lock().map_err(|error| {
+                format!("Failed to lock async store for asset: `{path}`. Error: {error}")
+            })?;
+            if latest.0.load(Ordering::SeqCst) != generation {
+                return Ok(DynamicBundle::default());
+            }
+            store.read().map_err(|error| {
+                format!(
+                    "Failed to get read access to inner store engine in async store for asset: `{path}`. Error: {error}"
+                )
+            })?.save_bytes(path.clone(), bytes).map_err(|error| {
+                format!(
+                    "Failed async store for asset: `{path}`. Error: {error}"
+                )
+            })
+        };
+        let jobs = self.jobs.read().ok_or_else(|| {
+            format!("Failed to get read access to jobs runner in async store for asset: `{path2}`")
+        })?;
+        let handle = jobs.spawn(JobLocation::other_than_current_thread(), job);
+        if let Some(previous) = self
+            .job_handles
+            .write()
+            .map_err(|error| format!("{error}"))?
+            .insert(path2, handle)
+        {
+            previous.cancel();
+        }
+        let mut bundle = DynamicBundle::default();
+        let _ = bundle.add_component(AssetAwaitsAsyncStore);
+        Ok(bundle)
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        if self.drive_jobs {
+            self.jobs
+                .read()
+                .ok_or("Failed to get read access to jobs runner in deferred store maintainance.")?
+                .run_local();
+        }
+
+        self.store
+            .write()
+            .map_err(|error| format!("Failed deferred store engine maintainance. Error: {error}"))?
+            .maintain(storage)?;
+
+        // Assets cancelled or unloaded while still being stored do not need
+        // their jobs anymore, same as assets that stopped awaiting them.
+        let abandoned = self
+            .job_handles
+            .read()
+            .map_err(|error| format!("{error}"))?
+            .keys()
+            .filter(|path| {
+                storage
+                    .find_by::<true, _>(*path)
+                    .map(|entity| !storage.has_entity_component::<AssetAwaitsAsyncStore>(entity))
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for path in abandoned {
+            if let Some(handle) = self
+                .job_handles
+                .write()
+                .map_err(|error| format!("{error}"))?
+                .remove(&path)
+            {
+                handle.cancel();
+            }
+        }
+
+        let complete = self
+            .job_handles
+            .read()
+            .map_err(|error| format!("{error}"))?
+            .iter()
+            .filter(|(_, handle)| handle.is_done())
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        for path in complete.into_iter().rev() {
+            let handle = self
+                .job_handles
+                .write()
+                .map_err(|error| format!("{error}"))?
+                .remove(&path)
+                .unwrap();
+            match handle.take() {
+                JobResult::Completed(result) => {
+                    // Latest save is done, so no outdated one writes anymore.
+                    self.generations
+                        .write()
+                        .map_err(|error| format!("{error}"))?
+                        .remove(&path);
+                    let entity = storage.find_by::<true, _>(&path);
+                    if let Some(entity) = entity {
+                        storage.remove::<(AssetAwaitsAsyncStore,)>(entity)?;
+                    }
+                    match result {
+                        Ok(bundle) => {
+                            if let Some(entity) = entity {
+                                storage.insert(entity, bundle)?;
+                            }
+                        }
+                        Err(error) => {
+                            if let Some(entity) = entity
+                                && let Ok(mut bindings) =
+                                    storage.component_mut::<true, AssetEventBindings>(entity)
+                            {
+                                bindings.dispatch(AssetEvent {
+                                    handle: AssetHandle::new(entity),
+                                    kind: AssetEventKind::BytesStoringFailed,
+                                    path: path.clone(),
+                                })?;
+                            }
+                            return Err(error.into());
+                        }
+                    }
+                }
+                JobResult::Cancelled | JobResult::Consumed => {
+                    if let Some(entity) = storage.find_by::<true, _>(&path) {
+                        storage.remove::<(AssetAwaitsAsyncStore,)>(entity)?;
+                    }
+                    return Err(format!(
+                        "Async store execution of `{path}` asset failed with undefined error!"
+                    )
+                    .into());
+                }
+                JobResult::InProgress => {
+                    self.job_handles
+                        .write()
+                        .map_err(|error| format!("{error}"))?
+                        .insert(path.clone(), handle);
+                }
+            };
+        }
+        Ok(())
+    }
+}
@@ -1,10 +1,50 @@
 use crate::{database::path::AssetPath, store::AssetStore};
 use anput::bundle::DynamicBundle;
-use std::{error::Error, path::PathBuf};
+use std::{
+    error::Error,
+    fs::File,
+    io::Write,
+    path::{Component, Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
+// Makes temporary file names unique between concurrent saves of same path.
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Writes bytes to temporary file next to destination, flushes it to disk and
+/// then renames it into place, so destination file is never left partially
+/// written, even if process crashes in the middle of saving.
 fn save_file_bytes(file_path: PathBuf, bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
-    std::fs::create_dir_all(file_path.parent().unwrap())?;
-    std::fs::write(&file_path, bytes)?;
+    let file_name = file_path
+        .file_name()
+        .ok_or_else(|| format!("Invalid file path to store: {file_path:?}"))?
+        .to_string_lossy()
+        .into_owned();
+    if let Some(parent) = file_path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let temp_path = file_path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let write = || {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(&bytes)?;
+        file.sync_all()
+    };
+    if let Err(error) = write() {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(error.into());
+    }
+    if let Err(error) = std::fs::rename(&temp_path, &file_path) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(error.into());
+    }
     Ok(DynamicBundle::default())
 }
 
@@ -21,6 +61,8 @@ impl AssetStore for AbsoluteFileAssetStore {
 
 /// Implementation of the `AssetStore` trait that saves assets to the file
 /// system using a specified root directory.
+///
+/// Asset paths are not allowed to point outside of root directory.
 #[derive(Debug, Default, Clone)]
 pub struct FileAssetStore {
     pub root: PathBuf,
@@ -42,7 +84,15 @@ impl FileAssetStore {
 
 impl AssetStore for FileAssetStore {
     fn save_bytes(&self, path: AssetPath, bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
-        let path = self.root.join(path.path());
-        save_file_bytes(path, bytes)
+        let relative = Path::new(path.path());
+        if relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(
+                format!("Asset path escapes store root directory: `{}`", path.path()).into(),
+            );
+        }
+        save_file_bytes(self.root.join(relative), bytes)
     }
 }
@@ -0,0 +1,102 @@
+use crate::{
+    database::path::AssetPath,
+    store::{AssetAwaitsAsyncStore, AssetStore},
+};
+use anput::{bundle::DynamicBundle, world::World};
+use std::{error::Error, fmt::Write};
+
+/// A mirror asset store that saves the same asset bytes to multiple stores.
+///
+/// Unlike router store, which picks one store per asset, mirror store writes
+/// every asset to all of its stores, for example local cache and remote server.
+/// Storing succeeds only if all stores succeed, or at least quorum of them if
+/// one is set, and errors of failed stores get reported together.
+///
+/// Bundle produced by the first successful store gets attached to an asset.
+/// Stores completing asynchronously (like `DeferredAssetStore`) are not
+/// supported, because their success is not known when mirror store counts
+/// successful stores - these get counted as failed, and their jobs get
+/// abandoned.
+#[derive(Default)]
+pub struct MirrorAssetStore {
+    stores: Vec<Box<dyn AssetStore>>,
+    quorum: Option<usize>,
+}
+
+impl MirrorAssetStore {
+    /// Adds a store to mirror assets to.
+    ///
+    /// # Arguments
+    /// - `store`: The asset store to save assets with.
+    ///
+    /// # Returns
+    /// - The `MirrorAssetStore` instance with the store added.
+    pub fn with(mut self, store: impl AssetStore + 'static) -> Self {
+        self.add(store);
+        self
+    }
+
+    /// Sets number of stores that have to succeed for storing to succeed.
+    ///
+    /// # Arguments
+    /// - `quorum`: Minimal number of successful stores.
+    ///
+    /// # Returns
+    /// - The `MirrorAssetStore` instance with the quorum set.
+    pub fn quorum(mut self, quorum: usize) -> Self {
+        self.quorum = Some(quorum);
+        self
+    }
+
+    /// Adds a store to mirror assets to.
+    ///
+    /// # Arguments
+    /// - `store`: The asset store to save assets with.
+    pub fn add(&mut self, store: impl AssetStore + 'static) {
+        self.stores.push(Box::new(store));
+    }
+}
+
+impl AssetStore for MirrorAssetStore {
+    fn save_bytes(&self, path: AssetPath, bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+        let required = self.quorum.unwrap_or(self.stores.len());
+        let mut result = None;
+        let mut succeeded = 0;
+        let mut errors = String::new();
+        for store in &self.stores {
+            match store.save_bytes(path.clone(), bytes.clone()) {
+                Ok(mut bundle) => {
+                    if bundle.remove_component::<AssetAwaitsAsyncStore>().is_some() {
+                        let _ = write!(
+                            &mut errors,
+                            "\n- {}: Asynchronous stores are not supported in mirror store",
+                            store.name()
+                        );
+                        continue;
+                    }
+                    succeeded += 1;
+                    result.get_or_insert(bundle);
+                }
+                Err(error) => {
+                    let _ = write!(&mut errors, "\n- {}: {error}", store.name());
+                }
+            }
+        }
+        if succeeded >= required {
+            Ok(result.unwrap_or_default())
+        } else {
+            Err(format!(
+                "Mirror store saved asset: `{path}` to {succeeded} of {} stores, while {required} were required. Errors:{errors}",
+                self.stores.len()
+            )
+            .into())
+        }
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        for store in &mut self.stores {
+            store.maintain(storage)?;
+        }
+        Ok(())
+    }
+}
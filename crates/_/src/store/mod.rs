@@ -1,5 +1,10 @@
+pub mod deferred;
 pub mod file;
 pub mod future;
+pub mod mirror;
+pub mod router;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 use crate::database::{
     events::{AssetEvent, AssetEventBindings, AssetEventKind},
@@ -7,7 +12,10 @@ use crate::database::{
     path::AssetPath,
 };
 use anput::{bundle::DynamicBundle, world::World};
-use std::error::Error;
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
 
 /// Marker type for assets that are awaiting storing.
 pub struct AssetAwaitsStoring;
@@ -20,8 +28,53 @@ pub struct AssetBytesAreReadyToStore(pub Vec<u8>);
 /// asynchronous and it's pending completion.
 pub struct AssetAwaitsAsyncStore;
 
+/// Timing of the most recent asset storing.
+///
+/// Added to an asset when its storing starts and completed when all store
+/// stages are finished, so it can be used for "saving..." indicators and
+/// storing metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetStoreTiming {
+    /// Moment storing of an asset has started.
+    pub started: Instant,
+    /// Moment bytes of an asset were stored, if storing has finished.
+    pub stored: Option<Instant>,
+}
+
+impl Default for AssetStoreTiming {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            stored: None,
+        }
+    }
+}
+
+impl AssetStoreTiming {
+    /// Tells if storing has finished.
+    pub fn is_stored(&self) -> bool {
+        self.stored.is_some()
+    }
+
+    /// Returns time it took to store an asset, if storing has finished.
+    pub fn duration(&self) -> Option<Duration> {
+        self.stored
+            .map(|stored| stored.saturating_duration_since(self.started))
+    }
+}
+
 /// Defines the interface for storing asset data to an external source.
 pub trait AssetStore: Send + Sync + 'static {
+    /// Returns the name of the store engine.
+    ///
+    /// Used for diagnostics, like logs and editor panels.
+    ///
+    /// # Returns
+    /// - Name of the store engine, by default its type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
     /// Saves the raw bytes of an asset given its path.
     ///
     /// # Arguments
@@ -62,6 +115,10 @@ impl AssetStoreEngine {
         self.store
     }
 
+    pub fn name(&self) -> &str {
+        self.store.name()
+    }
+
     pub fn save_bytes(
         &self,
         handle: AssetHandle,
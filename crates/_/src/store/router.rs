@@ -0,0 +1,118 @@
+use crate::{database::path::AssetPath, store::AssetStore};
+use anput::{bundle::DynamicBundle, world::World};
+use std::{error::Error, sync::RwLock};
+
+/// A router-based asset store that allows routing assets to different stores based on patterns.
+#[derive(Default)]
+pub struct RouterAssetStore {
+    #[allow(clippy::type_complexity)]
+    table: RwLock<
+        Vec<(
+            // Route rule validator.
+            Box<dyn Fn(&AssetPath) -> bool + Send + Sync>,
+            // Asset store.
+            Box<dyn AssetStore>,
+            // Priority.
+            usize,
+        )>,
+    >,
+}
+
+impl RouterAssetStore {
+    /// Adds a route rule with priority and asset store.
+    ///
+    /// # Arguments
+    /// - `rule`: The route validator.
+    /// - `store`: The asset store to handle matched paths.
+    /// - `priority`: The priority of this rule.
+    ///
+    /// # Returns
+    /// - The `RouterAssetStore` instance with the new routing entry.
+    pub fn route(
+        mut self,
+        rule: impl Fn(&AssetPath) -> bool + Send + Sync + 'static,
+        store: impl AssetStore + 'static,
+        priority: usize,
+    ) -> Self {
+        self.add(rule, store, priority);
+        self
+    }
+
+    /// Adds a route for assets of given protocol.
+    ///
+    /// # Arguments
+    /// - `protocol`: The protocol of asset paths to match.
+    /// - `store`: The asset store to handle matched paths.
+    ///
+    /// # Returns
+    /// - The `RouterAssetStore` instance with the new routing entry.
+    pub fn route_protocol(
+        mut self,
+        protocol: impl ToString,
+        store: impl AssetStore + 'static,
+    ) -> Self {
+        self.add_protocol(protocol, store);
+        self
+    }
+
+    /// Adds a route rule with priority and asset store.
+    ///
+    /// # Arguments
+    /// - `rule`: The route validator.
+    /// - `store`: The asset store to handle matched paths.
+    /// - `priority`: The priority of this rule.
+    pub fn add(
+        &mut self,
+        rule: impl Fn(&AssetPath) -> bool + Send + Sync + 'static,
+        store: impl AssetStore + 'static,
+        priority: usize,
+    ) {
+        if let Ok(mut table) = self.table.write() {
+            table.push((Box::new(rule), Box::new(store), priority));
+            table.sort_by(|(_, _, a), (_, _, b)| a.cmp(b).reverse());
+        }
+    }
+
+    /// Adds a route for assets of given protocol.
+    ///
+    /// # Arguments
+    /// - `protocol`: The protocol of asset paths to match.
+    /// - `store`: The asset store to handle matched paths.
+    pub fn add_protocol(&mut self, protocol: impl ToString, store: impl AssetStore + 'static) {
+        let protocol = protocol.to_string();
+        self.add(move |path| path.protocol() == protocol, store, 0);
+    }
+}
+
+impl AssetStore for RouterAssetStore {
+    fn save_bytes(&self, path: AssetPath, bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+        for (rule, store, _) in self
+            .table
+            .read()
+            .map_err(|error| format!("{error}"))?
+            .iter()
+        {
+            if rule(&path) {
+                return store.save_bytes(path, bytes);
+            }
+        }
+        Err(format!(
+            "Could not find store route for asset: `{}` with protocol: `{}`",
+            path,
+            path.protocol()
+        )
+        .into())
+    }
+
+    fn maintain(&mut self, storage: &mut World) -> Result<(), Box<dyn Error>> {
+        for (_, store, _) in self
+            .table
+            .write()
+            .map_err(|error| format!("{error}"))?
+            .iter_mut()
+        {
+            store.maintain(storage)?;
+        }
+        Ok(())
+    }
+}
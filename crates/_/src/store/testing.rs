@@ -0,0 +1,45 @@
+use crate::{database::path::AssetPath, store::AssetStore};
+use anput::bundle::DynamicBundle;
+use std::{
+    collections::HashMap,
+    error::Error,
+    sync::{Arc, Mutex},
+};
+
+/// Asset store meant for tests, recording saved bytes in memory.
+///
+/// Saved assets are keyed by path part of the asset path. Store is cheap to
+/// clone and clones share recorded assets, so one can be put into database
+/// and the other kept for assertions.
+#[derive(Debug, Default, Clone)]
+pub struct NullAssetStore {
+    saved: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl NullAssetStore {
+    /// Returns shared map of saved assets bytes.
+    pub fn saved(&self) -> Arc<Mutex<HashMap<String, Vec<u8>>>> {
+        self.saved.clone()
+    }
+
+    /// Gets copy of bytes saved under given path.
+    ///
+    /// # Arguments
+    /// - `path`: Path part of the asset path.
+    ///
+    /// # Returns
+    /// Saved bytes if asset was stored.
+    pub fn get(&self, path: &str) -> Option<Vec<u8>> {
+        self.saved.lock().ok()?.get(path).cloned()
+    }
+}
+
+impl AssetStore for NullAssetStore {
+    fn save_bytes(&self, path: AssetPath, bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+        self.saved
+            .lock()
+            .map_err(|error| format!("Failed to save asset: `{path}`. Error: {error}"))?
+            .insert(path.path().to_owned(), bytes);
+        Ok(DynamicBundle::default())
+    }
+}
@@ -0,0 +1,44 @@
+use keket::{
+    database::AssetDatabase, fetch::deferred::DeferredAssetFetch, protocol::text::TextAssetProtocol,
+};
+use keket_client::{ClientAssetFetch, third_party::reqwest};
+use keket_server::AssetServer;
+use std::{error::Error, net::SocketAddr};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Server requires clients to provide matching bearer token.
+    let server = AssetServer::builder()
+        .root(concat!(env!("CARGO_MANIFEST_DIR"), "/../../resources"))
+        .bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .watch(None)
+        .token(Some("secret".to_owned()))
+        .spawn()?;
+    let address = server.address().to_string();
+
+    // Unauthenticated requests get rejected.
+    assert!(ClientAssetFetch::new(&address).is_err());
+    let response = reqwest::blocking::get(format!("http://{address}/assets/lorem.txt"))?;
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let response = reqwest::blocking::Client::new()
+        .get(format!("http://{address}/assets/lorem.txt"))
+        .bearer_auth("wrong")
+        .send()?;
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    // Authenticated client fetches assets as usual.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(DeferredAssetFetch::new(ClientAssetFetch::new_authorized(
+            &address, "secret",
+        )?));
+    let lorem = database.ensure("text://lorem.txt")?;
+    database.maintain_until_idle()?;
+    println!("Lorem Ipsum: {}", lorem.access::<&String>(&database));
+
+    drop(database);
+    server.shutdown();
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
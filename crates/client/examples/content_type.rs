@@ -0,0 +1,31 @@
+use keket_client::third_party::reqwest::{self, header::CONTENT_TYPE};
+use keket_server::AssetServer;
+use std::{error::Error, net::SocketAddr};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let server = AssetServer::builder()
+        .root(concat!(env!("CARGO_MANIFEST_DIR"), "/../../resources"))
+        .bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .watch(None)
+        .spawn()?;
+    let address = server.address();
+
+    // Server infers content type from file extension.
+    let response = reqwest::blocking::get(format!("http://{address}/assets/assets.manifest.json"))?;
+    println!("JSON content type: {:?}", response.headers()[CONTENT_TYPE]);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+
+    // Unknown extensions fall back to raw bytes.
+    let response = reqwest::blocking::get(format!("http://{address}/assets/image.fs"))?;
+    println!(
+        "Unknown content type: {:?}",
+        response.headers()[CONTENT_TYPE]
+    );
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/octet-stream");
+
+    server.shutdown();
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
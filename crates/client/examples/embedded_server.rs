@@ -0,0 +1,51 @@
+use keket::{
+    database::AssetDatabase,
+    fetch::deferred::DeferredAssetFetch,
+    protocol::{bytes::BytesAssetProtocol, text::TextAssetProtocol},
+};
+use keket_client::ClientAssetFetch;
+use keket_server::AssetServer;
+use std::{error::Error, net::SocketAddr};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Spawn asset server in-process, at port picked by the system.
+    let server = AssetServer::builder()
+        .root(concat!(env!("CARGO_MANIFEST_DIR"), "/../../resources"))
+        .bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .watch(None)
+        .spawn()?;
+
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_protocol(BytesAssetProtocol)
+        .with_fetch(DeferredAssetFetch::new(ClientAssetFetch::new(
+            &server.address().to_string(),
+        )?));
+
+    let lorem = database.ensure("text://lorem.txt")?;
+    let trash = database.ensure("bytes://trash.bin")?;
+    database.maintain_until_idle()?;
+
+    let expected = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../resources/lorem.txt"
+    ))?;
+    assert_eq!(lorem.access::<&String>(&database), &expected);
+    let expected = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../resources/trash.bin"
+    ))?;
+    assert_eq!(trash.access::<&Vec<u8>>(&database), &expected);
+
+    // Drop client connection first, then stop the server.
+    let address = server.address().to_string();
+    drop(database);
+    server.shutdown();
+
+    // Stopped server does not accept connections anymore.
+    assert!(ClientAssetFetch::new(&address).is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use keket_client::third_party::reqwest::{self, StatusCode};
+use keket_server::AssetServer;
+use std::{
+    error::Error,
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let server = AssetServer::builder()
+        .root(concat!(env!("CARGO_MANIFEST_DIR"), "/../../resources"))
+        .bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .watch(None)
+        .spawn()?;
+    let address = server.address();
+
+    // Reading directory as a file fails, but server reports it as error
+    // response instead of crashing.
+    let response = reqwest::blocking::get(format!("http://{address}/assets/levels"))?;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    println!("Error response: {}", response.text()?);
+
+    // Missing files are reported as not found.
+    let response = reqwest::blocking::get(format!("http://{address}/assets/missing.txt"))?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Server keeps serving files after errors.
+    let response = reqwest::blocking::get(format!("http://{address}/assets/lorem.txt"))?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Paths escaping root directory get rejected. Regular clients normalize
+    // `..` segments away, so request is sent as it is.
+    let mut stream = TcpStream::connect(address)?;
+    write!(
+        stream,
+        "GET /assets/.. HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\n\r\n"
+    )?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    assert!(response.starts_with("HTTP/1.1 400"));
+
+    // Terminate command shuts down only the server, not the process it runs in.
+    let response = reqwest::blocking::Client::new()
+        .post(format!("http://{address}/run/lorem.txt"))
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body("\"Terminate\"")
+        .send()?;
+    assert_eq!(response.status(), StatusCode::OK);
+    server.wait();
+    assert!(reqwest::blocking::get(format!("http://{address}/assets/lorem.txt")).is_err());
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use keket::{
     database::path::AssetPath,
-    fetch::{AssetAwaitsResolution, AssetBytesAreReadyToProcess, AssetFetch},
+    fetch::{AssetAwaitsResolution, AssetBytesAreReadyToProcess, AssetFetch, AssetSourceChanged},
     third_party::anput::{
         bundle::DynamicBundle, entity::Entity, query::Update,
         third_party::intuicio_data::type_hash::TypeHash, world::World,
@@ -11,7 +11,9 @@ use std::{
     error::Error,
     net::{SocketAddr, TcpStream},
 };
-use tungstenite::{WebSocket, connect, stream::MaybeTlsStream};
+use tungstenite::{
+    WebSocket, client::IntoClientRequest, connect, http::HeaderValue, stream::MaybeTlsStream,
+};
 
 pub mod third_party {
     pub use reqwest;
@@ -25,6 +27,7 @@ pub struct AssetFromClient;
 pub struct ClientAssetFetch {
     root: Url,
     socket: WebSocket<MaybeTlsStream<TcpStream>>,
+    token: Option<String>,
 }
 
 impl ClientAssetFetch {
@@ -37,34 +40,73 @@ impl ClientAssetFetch {
     /// - `Ok(ClientAssetFetch)` if the initialization is successful.
     /// - `Err(Box<dyn Error>)` if any parsing errors occur.
     pub fn new(address: &str) -> Result<Self, Box<dyn Error>> {
+        Self::connect(address, None)
+    }
+
+    /// Creates a new instance of `ClientAssetFetch` authorized with bearer token.
+    ///
+    /// # Arguments
+    /// - `address`: A string slice representing the server IP address.
+    /// - `token`: Bearer token matching the one asset server was started with.
+    ///
+    /// # Returns
+    /// - `Ok(ClientAssetFetch)` if the initialization is successful.
+    /// - `Err(Box<dyn Error>)` if any parsing or connection errors occur.
+    pub fn new_authorized(address: &str, token: impl ToString) -> Result<Self, Box<dyn Error>> {
+        Self::connect(address, Some(token.to_string()))
+    }
+
+    fn connect(address: &str, token: Option<String>) -> Result<Self, Box<dyn Error>> {
         address.parse::<SocketAddr>()?;
         let root = format!("http://{address}/assets/").parse::<Url>()?;
-        let (socket, _) = connect(format!("ws://{address}/changes"))?;
+        let mut request = format!("ws://{address}/changes").into_client_request()?;
+        if let Some(token) = token.as_ref() {
+            request.headers_mut().insert(
+                "Authorization",
+                HeaderValue::from_str(&format!("Bearer {token}"))?,
+            );
+        }
+        let (socket, _) = connect(request)?;
         if let MaybeTlsStream::Plain(tcp) = socket.get_ref() {
             tcp.set_nonblocking(true)?;
         }
-        Ok(Self { root, socket })
+        Ok(Self {
+            root,
+            socket,
+            token,
+        })
     }
 }
 
 impl AssetFetch for ClientAssetFetch {
     fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
-        let url = self.root.join(path.path()).map_err(|error| {
-            format!(
-                "Failed to join root URL: `{}` with path: `{}`. Error: {}",
-                self.root,
-                path.path_with_meta(),
-                error
-            )
-        })?;
-        let mut response = reqwest::blocking::get(url.clone())
+        let url = self
+            .root
+            .join(path.percent_encoded().path())
+            .map_err(|error| {
+                format!(
+                    "Failed to join root URL: `{}` with path: `{}`. Error: {}",
+                    self.root,
+                    path.path_with_meta(),
+                    error
+                )
+            })?;
+        let mut request = reqwest::blocking::Client::new().get(url.clone());
+        if let Some(token) = self.token.as_ref() {
+            request = request.bearer_auth(token);
+        }
+        let mut response = request
+            .send()
+            .and_then(|response| response.error_for_status())
             .map_err(|error| format!("Failed to get HTTP content from: `{url}`. Error: {error}"))?;
         let mut bytes = vec![];
         response.copy_to(&mut bytes).map_err(|error| {
             format!("Failed to read bytes response from: `{url}`. Error: {error}")
         })?;
         let mut bundle = DynamicBundle::default();
-        let _ = bundle.add_component(AssetBytesAreReadyToProcess(bytes));
+        bundle
+            .add_component(AssetBytesAreReadyToProcess(bytes))
+            .map_err(|_| format!("Failed to add bytes to bundle for asset: `{url}`"))?;
         let _ = bundle.add_component(AssetFromClient);
         let _ = bundle.add_component(url);
         Ok(bundle)
@@ -91,7 +133,7 @@ impl AssetFetch for ClientAssetFetch {
                         .cloned()
                         .collect::<Vec<_>>();
                     storage.remove_raw(entity, columns)?;
-                    storage.insert(entity, (AssetAwaitsResolution,))?;
+                    storage.insert(entity, (AssetAwaitsResolution, AssetSourceChanged))?;
                 }
             }
         }
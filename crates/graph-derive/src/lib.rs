@@ -1,23 +1,88 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{Attribute, ItemStruct, parse_macro_input, parse_quote};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Fields, Index, parse_macro_input, parse_quote, spanned::Spanned,
+};
 
 fn has_asset_deps_attr(attrs: &[Attribute]) -> bool {
     attrs.iter().any(|attr| attr.path.is_ident("asset_deps"))
 }
 
-/// Derives the `AssetTree` trait for a struct.
-/// This macro will automatically implement the `AssetTree` trait for the struct,
+fn struct_dependencies(fields: &Fields) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| has_asset_deps_attr(&field.attrs))
+        .map(|(index, field)| {
+            if let Some(ident) = field.ident.as_ref() {
+                quote!(self.#ident)
+            } else {
+                let index = Index::from(index);
+                quote!(self.#index)
+            }
+        })
+        .collect()
+}
+
+fn variant_pattern(fields: &Fields) -> (TokenStream2, Vec<TokenStream2>) {
+    match fields {
+        Fields::Named(named) => {
+            let idents = named
+                .named
+                .iter()
+                .filter(|field| has_asset_deps_attr(&field.attrs))
+                .filter_map(|field| field.ident.as_ref())
+                .collect::<Vec<_>>();
+            (
+                quote!({ #( #idents, )* .. }),
+                idents.iter().map(|ident| quote!(#ident)).collect(),
+            )
+        }
+        Fields::Unnamed(unnamed) => {
+            let bindings = unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    if has_asset_deps_attr(&field.attrs) {
+                        Some(format_ident!("field{}", index))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+            let patterns = bindings.iter().map(|binding| match binding {
+                Some(binding) => quote!(#binding),
+                None => quote!(_),
+            });
+            (
+                quote!(( #( #patterns, )* )),
+                bindings
+                    .iter()
+                    .flatten()
+                    .map(|binding| quote!(#binding))
+                    .collect(),
+            )
+        }
+        Fields::Unit => (quote!(), vec![]),
+    }
+}
+
+/// Derives the `AssetTree` trait for a struct (with named or unnamed fields)
+/// or an enum.
+/// This macro will automatically implement the `AssetTree` trait for the type,
 /// allowing it to return its asset dependencies based on the fields that have
-/// the `#[asset_deps]` attribute.
+/// the `#[asset_deps]` attribute. For enums only fields of the active variant
+/// are collected, and unit variants contribute no dependencies.
 #[proc_macro_derive(AssetTree, attributes(asset_deps))]
 pub fn asset_tree_struct(input: TokenStream) -> TokenStream {
-    let ItemStruct {
+    let DeriveInput {
         ident,
-        fields,
+        data,
         mut generics,
         ..
-    } = parse_macro_input!(input as ItemStruct);
+    } = parse_macro_input!(input as DeriveInput);
     let generics_params = generics.params.clone();
     let where_clause = generics.make_where_clause();
     for param in &generics_params {
@@ -26,18 +91,56 @@ pub fn asset_tree_struct(input: TokenStream) -> TokenStream {
         }
     }
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-    let fields = fields
-        .iter()
-        .filter(|field| has_asset_deps_attr(&field.attrs))
-        .filter_map(|field| field.ident.as_ref())
-        .collect::<Vec<_>>();
+    // Empty enums cannot be constructed, so collecting code is unreachable.
+    let allow_unreachable = matches!(&data, Data::Enum(data) if data.variants.is_empty())
+        .then(|| quote!(#[allow(unreachable_code)]));
+    let body = match data {
+        Data::Struct(data) => {
+            let fields = struct_dependencies(&data.fields);
+            quote! {
+                #(
+                    result.extend(#fields.asset_dependencies());
+                )*
+            }
+        }
+        Data::Enum(data) if data.variants.is_empty() => quote! {
+            match *self {}
+        },
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let name = &variant.ident;
+                let (pattern, bindings) = variant_pattern(&variant.fields);
+                quote! {
+                    Self::#name #pattern => {
+                        #(
+                            result.extend(#bindings.asset_dependencies());
+                        )*
+                    }
+                }
+            });
+            quote! {
+                #[allow(unused_variables)]
+                match self {
+                    #( #arms )*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return syn::Error::new(
+                data.union_token.span(),
+                "AssetTree cannot be derived for unions",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
     quote! {
         impl #impl_generics AssetTree for #ident #ty_generics #where_clause {
+            #allow_unreachable
             fn asset_dependencies(&self) -> impl IntoIterator<Item = AssetPathStatic> {
-                let mut result = vec![];
-                #(
-                    result.extend(self.#fields.asset_dependencies());
-                )*
+                #[allow(unused_mut)]
+                let mut result: Vec<AssetPathStatic> = vec![];
+                #body
                 result
             }
         }
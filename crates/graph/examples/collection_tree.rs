@@ -0,0 +1,71 @@
+use keket::database::reference::AssetRef;
+use keket_graph::protocol::AssetTree;
+use keket_graph_derive::AssetTree;
+use std::collections::HashMap;
+
+// Collections of asset references report dependencies of all their elements.
+#[derive(Debug, AssetTree)]
+struct Scene {
+    #[asset_deps]
+    props: Vec<AssetRef>,
+    #[asset_deps]
+    skybox: [AssetRef; 2],
+    #[asset_deps]
+    sounds: HashMap<String, AssetRef>,
+}
+
+fn paths(tree: &impl AssetTree) -> Vec<String> {
+    tree.asset_dependencies()
+        .into_iter()
+        .map(|path| path.to_string())
+        .collect()
+}
+
+fn main() {
+    let scene = Scene {
+        props: vec![
+            AssetRef::new("mesh://tree.obj"),
+            AssetRef::new("mesh://rock.obj"),
+            AssetRef::new("mesh://bush.obj"),
+        ],
+        skybox: [
+            AssetRef::new("image://day.png"),
+            AssetRef::new("image://night.png"),
+        ],
+        sounds: HashMap::from([
+            ("wind".to_owned(), AssetRef::new("audio://wind.ogg")),
+            ("birds".to_owned(), AssetRef::new("audio://birds.ogg")),
+        ]),
+    };
+
+    println!("{scene:?} depends on: {:?}", paths(&scene));
+    assert_eq!(
+        paths(&scene.props),
+        vec!["mesh://tree.obj", "mesh://rock.obj", "mesh://bush.obj"]
+    );
+    assert_eq!(
+        paths(&scene.skybox),
+        vec!["image://day.png", "image://night.png"]
+    );
+
+    // Hash maps have no order, so only set of their values matters.
+    let mut sounds = paths(&scene.sounds);
+    sounds.sort();
+    assert_eq!(sounds, vec!["audio://birds.ogg", "audio://wind.ogg"]);
+
+    let mut all = paths(&scene);
+    assert_eq!(all.len(), 7);
+    all.sort();
+    assert_eq!(
+        all,
+        vec![
+            "audio://birds.ogg",
+            "audio://wind.ogg",
+            "image://day.png",
+            "image://night.png",
+            "mesh://bush.obj",
+            "mesh://rock.obj",
+            "mesh://tree.obj",
+        ]
+    );
+}
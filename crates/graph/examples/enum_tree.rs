@@ -0,0 +1,89 @@
+use keket::database::{path::AssetPathStatic, reference::AssetRef};
+use keket_graph::protocol::AssetTree;
+use keket_graph_derive::AssetTree;
+
+// Enums can derive `AssetTree` too - only fields of the active variant
+// marked with `#[asset_deps]` are reported as dependencies.
+#[derive(Debug, AssetTree)]
+enum Material {
+    // Unit variants have no dependencies.
+    Untextured,
+    // Tuple-like variants report marked fields by position.
+    Solid(#[asset_deps] AssetRef, f32),
+    // Struct-like variants report marked fields by name.
+    Textured {
+        #[asset_deps]
+        diffuse: AssetRef,
+        #[asset_deps]
+        normal: Option<AssetRef>,
+        tiling: f32,
+    },
+}
+
+// Enums without variants cannot be constructed, so they have no dependencies.
+#[derive(Debug, AssetTree)]
+#[allow(dead_code)]
+enum Never {}
+
+// Tuple structs report marked fields by position.
+#[derive(Debug, AssetTree)]
+struct Level(#[asset_deps] AssetRef, #[asset_deps] AssetRef);
+
+// Collections of asset trees report dependencies of all their elements.
+#[derive(Debug, AssetTree)]
+struct Palette {
+    #[asset_deps]
+    materials: Vec<Material>,
+}
+
+fn paths(tree: &impl AssetTree) -> Vec<String> {
+    tree.asset_dependencies()
+        .into_iter()
+        .map(|path| path.to_string())
+        .collect()
+}
+
+fn main() {
+    let materials = [
+        Material::Untextured,
+        Material::Solid(AssetRef::new("image://solid.png"), 1.0),
+        Material::Textured {
+            diffuse: AssetRef::new("image://diffuse.png"),
+            normal: Some(AssetRef::new("image://normal.png")),
+            tiling: 2.0,
+        },
+    ];
+
+    for material in &materials {
+        println!("{material:?} depends on: {:?}", paths(material));
+    }
+    assert!(paths(&materials[0]).is_empty());
+    assert_eq!(paths(&materials[1]), vec!["image://solid.png"]);
+    assert_eq!(
+        paths(&materials[2]),
+        vec!["image://diffuse.png", "image://normal.png"]
+    );
+
+    let level = Level(
+        AssetRef::new("scene://forest.json"),
+        AssetRef::new("audio://forest.ogg"),
+    );
+    println!("{level:?} depends on: {:?}", paths(&level));
+    assert_eq!(
+        paths(&level),
+        vec!["scene://forest.json", "audio://forest.ogg"]
+    );
+
+    let palette = Palette {
+        materials: materials.into(),
+    };
+    println!("Palette depends on: {:?}", paths(&palette));
+    assert_eq!(
+        paths(&palette),
+        vec![
+            "image://solid.png",
+            "image://diffuse.png",
+            "image://normal.png"
+        ]
+    );
+}
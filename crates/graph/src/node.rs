@@ -125,6 +125,28 @@ impl<T: AssetTree> AssetNode<T> {
                 _phantom: PhantomData,
             })
     }
+
+    /// Reloads the asset node, invalidating its previously resolved handle.
+    ///
+    /// # Arguments
+    /// - `database`: A mutable reference to the `AssetDatabase` where the asset is stored.
+    ///
+    /// # Returns
+    /// A `Result` containing an `AssetNodeResolved` instance pointing to the
+    /// reloaded asset if successful, or an error if reloading fails. Reloaded
+    /// asset has to be maintained by the database before its component can
+    /// be accessed.
+    pub fn reload<'a>(
+        &'a self,
+        database: &'a mut AssetDatabase,
+    ) -> Result<AssetNodeResolved<'a, T>, Box<dyn Error>> {
+        self.inner
+            .reload(database)
+            .map(|resolved| AssetNodeResolved {
+                inner: resolved,
+                _phantom: PhantomData,
+            })
+    }
 }
 
 impl<T: AssetTree> Clone for AssetNode<T> {
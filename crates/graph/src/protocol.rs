@@ -10,6 +10,7 @@ use keket::{
     third_party::anput::component::Component,
 };
 use std::{
+    collections::HashMap,
     error::Error,
     ops::{Deref, DerefMut},
 };
@@ -37,6 +38,27 @@ impl<T: AssetTree> AssetTree for Option<T> {
     }
 }
 
+impl<T: AssetTree> AssetTree for Vec<T> {
+    fn asset_dependencies(&self) -> impl IntoIterator<Item = AssetPathStatic> {
+        self.iter().flat_map(|asset| asset.asset_dependencies())
+    }
+}
+
+impl<T: AssetTree, const N: usize> AssetTree for [T; N] {
+    fn asset_dependencies(&self) -> impl IntoIterator<Item = AssetPathStatic> {
+        self.iter().flat_map(|asset| asset.asset_dependencies())
+    }
+}
+
+impl<K, V: AssetTree, S> AssetTree for HashMap<K, V, S>
+where
+    Self: Component,
+{
+    fn asset_dependencies(&self) -> impl IntoIterator<Item = AssetPathStatic> {
+        self.values().flat_map(|asset| asset.asset_dependencies())
+    }
+}
+
 impl AssetTree for AssetPathStatic {
     fn asset_dependencies(&self) -> impl IntoIterator<Item = AssetPathStatic> {
         std::iter::once(self.clone().into_static())
@@ -24,6 +24,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     let lorem = database.ensure("text://lorem.txt")?;
     let json = database.ensure("json://person.json")?;
     let trash = database.ensure("bytes://trash.bin")?;
+    // Byte ranges are fetched with HTTP `Range` header.
+    let header = database.ensure("bytes://lorem.txt?range=0-10")?;
 
     // Wait while database is busy.
     while database.is_busy() {
@@ -40,6 +42,10 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("Lorem Ipsum: {}", lorem.access::<&String>(&database));
     println!("JSON: {:#}", json.access::<&Value>(&database));
     println!("Bytes: {:?}", trash.access::<&Vec<u8>>(&database));
+    println!(
+        "Lorem Ipsum header: {:?}",
+        String::from_utf8_lossy(header.access::<&Vec<u8>>(&database))
+    );
 
     // List all assets from HTTP.
     for (asset_path, url) in database.storage.query::<true, (&AssetPath, &Url)>() {
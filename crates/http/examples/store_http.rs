@@ -0,0 +1,67 @@
+use keket::{
+    database::{AssetDatabase, path::AssetPath},
+    fetch::file::FileAssetFetch,
+    protocol::text::TextAssetProtocol,
+    store::AssetStore,
+};
+use keket_http::{AssetStoredToHttp, HttpAssetStore, third_party::reqwest::Url};
+use keket_server::AssetServer;
+use std::{error::Error, net::SocketAddr};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    // Asset server (see server crate) accepting assets put into its root.
+    let root = std::env::temp_dir().join(format!("keket_store_http_{}", std::process::id()));
+    std::fs::create_dir_all(&root)?;
+    let server = AssetServer::builder()
+        .root(&root)
+        .bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .watch(None)
+        .token(Some("secret".to_owned()))
+        .spawn()?;
+    let assets = format!("http://{}/assets/", server.address());
+
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(
+            FileAssetFetch::default()
+                .with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/../../resources")),
+        )
+        // HTTP asset store that puts assets to asset server.
+        .with_store(HttpAssetStore::new(&assets)?.with_bearer_auth("secret")?);
+
+    // Load local asset and transform it.
+    let lorem = database.ensure("text://lorem.txt")?;
+    let content = lorem.access::<&String>(&database).to_uppercase();
+
+    // Spawn transformed asset and store it to the server.
+    let shout = database.spawn("text://shout.txt", (content.clone(),))?;
+    shout.store(&mut database)?;
+
+    while database.is_busy() {
+        database.maintain()?;
+    }
+
+    // Server received exactly the stored bytes.
+    assert!(shout.has::<AssetStoredToHttp>(&database));
+    let url = shout.access::<&Url>(&database);
+    println!("Stored at: `{url}`");
+    assert_eq!(url.as_str(), format!("{assets}shout.txt"));
+    assert_eq!(std::fs::read_to_string(root.join("shout.txt"))?, content);
+
+    // Non-2xx responses (here missing authorization) are reported as errors.
+    let unauthorized = HttpAssetStore::new(&assets)?;
+    let error = unauthorized
+        .save_bytes(AssetPath::new("text://denied.txt"), b"Nope".to_vec())
+        .unwrap_err();
+    println!("Unauthorized: {error}");
+    assert!(error.to_string().contains("401"));
+    assert!(!root.join("denied.txt").exists());
+
+    drop(database);
+    server.shutdown();
+    std::fs::remove_dir_all(&root)?;
+    /* ANCHOR_END: main */
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 use keket::{
     database::path::AssetPath,
-    fetch::{AssetBytesAreReadyToProcess, AssetFetch},
+    fetch::{AssetByteRange, AssetBytesAreReadyToProcess, AssetFetch, FetchError},
+    store::AssetStore,
     third_party::anput::bundle::DynamicBundle,
 };
-use reqwest::Url;
+use reqwest::{
+    StatusCode, Url,
+    blocking::Client,
+    header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue, RANGE},
+};
 use std::error::Error;
 
 pub mod third_party {
@@ -14,10 +19,53 @@ pub mod third_party {
 /// It does not contain asset data but is used to mark assets fetched from an HTTP URL.
 pub struct AssetFromHttp;
 
+/// A marker component indicating that server did not honor requested byte
+/// range and whole content was downloaded, then trimmed to the range.
+pub struct AssetByteRangeNotSupported;
+
+/// A marker component indicating that an asset was stored with an HTTP request.
+pub struct AssetStoredToHttp;
+
+fn join_url(root: &Url, path: &AssetPath) -> Result<Url, Box<dyn Error>> {
+    Ok(root.join(path.percent_encoded().path()).map_err(|error| {
+        format!(
+            "Failed to join root URL: `{}` with path: `{}`. Error: {}",
+            root,
+            path.path_with_meta(),
+            error
+        )
+    })?)
+}
+
+fn fetch_error(message: String, error: &reqwest::Error) -> FetchError {
+    match error.status() {
+        Some(StatusCode::NOT_FOUND | StatusCode::GONE) => FetchError::NotFound(message),
+        Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => {
+            FetchError::PermissionDenied(message)
+        }
+        _ => FetchError::Network(message),
+    }
+}
+
+fn insert_header(headers: &mut HeaderMap, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
+    let name = HeaderName::try_from(name)
+        .map_err(|error| format!("Invalid HTTP header name: `{name}`. Error: {error}"))?;
+    let value = HeaderValue::try_from(value)
+        .map_err(|error| format!("Invalid HTTP header value for: `{name}`. Error: {error}"))?;
+    headers.insert(name, value);
+    Ok(())
+}
+
 /// `HttpAssetFetch` is a struct that enables fetching assets from an HTTP endpoint.
 /// The root URL represents the base URL to join with paths to form full asset URLs.
+///
+/// Byte ranges requested with `range` asset path meta are sent as `Range`
+/// header. Servers not supporting ranges make whole content downloaded and
+/// trimmed to the range, marking asset with `AssetByteRangeNotSupported`.
 pub struct HttpAssetFetch {
     root: Url,
+    client: Client,
+    headers: HeaderMap,
 }
 
 impl HttpAssetFetch {
@@ -33,30 +81,151 @@ impl HttpAssetFetch {
     pub fn new(root: &str) -> Result<Self, Box<dyn Error>> {
         Ok(Self {
             root: root.parse()?,
+            client: Client::new(),
+            headers: Default::default(),
         })
     }
+
+    /// Adds header sent with every request.
+    ///
+    /// # Arguments
+    /// - `name`: Header name.
+    /// - `value`: Header value.
+    ///
+    /// # Returns
+    /// - `Ok(HttpAssetFetch)`: The updated `HttpAssetFetch` instance.
+    /// - `Err(Box<dyn Error>)`: If header name or value is invalid.
+    pub fn with_header(mut self, name: &str, value: &str) -> Result<Self, Box<dyn Error>> {
+        insert_header(&mut self.headers, name, value)?;
+        Ok(self)
+    }
+
+    /// Adds bearer token authorization header sent with every request.
+    ///
+    /// # Arguments
+    /// - `token`: Bearer token.
+    ///
+    /// # Returns
+    /// - `Ok(HttpAssetFetch)`: The updated `HttpAssetFetch` instance.
+    /// - `Err(Box<dyn Error>)`: If token is not a valid header value.
+    pub fn with_bearer_auth(self, token: &str) -> Result<Self, Box<dyn Error>> {
+        self.with_header(AUTHORIZATION.as_str(), &format!("Bearer {token}"))
+    }
 }
 
 impl AssetFetch for HttpAssetFetch {
     fn load_bytes(&self, path: AssetPath) -> Result<DynamicBundle, Box<dyn Error>> {
-        let url = self.root.join(path.path()).map_err(|error| {
-            format!(
-                "Failed to join root URL: `{}` with path: `{}`. Error: {}",
-                self.root,
-                path.path_with_meta(),
-                error
-            )
-        })?;
-        let mut response = reqwest::blocking::get(url.clone())
-            .map_err(|error| format!("Failed to get HTTP content from: `{url}`. Error: {error}"))?;
+        let url = join_url(&self.root, &path)?;
+        let range = AssetByteRange::from_path(&path)?;
+        let mut headers = self.headers.clone();
+        if let Some(range) = range.as_ref() {
+            insert_header(&mut headers, RANGE.as_str(), &range.http_header())?;
+        }
+        let mut response = self
+            .client
+            .get(url.clone())
+            .headers(headers)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(|error| {
+                fetch_error(
+                    format!("Failed to get HTTP content from: `{url}`. Error: {error}"),
+                    &error,
+                )
+            })?;
         let mut bytes = vec![];
+        let partial = response.status() == StatusCode::PARTIAL_CONTENT;
         response.copy_to(&mut bytes).map_err(|error| {
-            format!("Failed to read bytes response from: `{url}`. Error: {error}")
+            FetchError::Network(format!(
+                "Failed to read bytes response from: `{url}`. Error: {error}"
+            ))
         })?;
         let mut bundle = DynamicBundle::default();
-        let _ = bundle.add_component(AssetBytesAreReadyToProcess(bytes));
+        if let Some(range) = range.as_ref()
+            && !partial
+        {
+            bytes = range.slice(&bytes).to_vec();
+            let _ = bundle.add_component(AssetByteRangeNotSupported);
+        }
+        bundle
+            .add_component(AssetBytesAreReadyToProcess(bytes))
+            .map_err(|_| format!("Failed to add bytes to bundle for asset: `{url}`"))?;
         let _ = bundle.add_component(AssetFromHttp);
         let _ = bundle.add_component(url);
+        if let Some(range) = range {
+            let _ = bundle.add_component(range);
+        }
+        Ok(bundle)
+    }
+}
+
+/// `HttpAssetStore` is a struct that enables storing assets to an HTTP endpoint
+/// with `PUT` requests.
+/// The root URL represents the base URL to join with paths to form full asset URLs.
+pub struct HttpAssetStore {
+    root: Url,
+    client: Client,
+    headers: HeaderMap,
+}
+
+impl HttpAssetStore {
+    #[allow(rustdoc::bare_urls)]
+    /// Creates a new `HttpAssetStore` with a provided root URL.
+    ///
+    /// # Arguments
+    /// - `root`: A string representing the root URL for the HTTP request (e.g., "https://example.com").
+    ///
+    /// # Returns
+    /// - `Ok(HttpAssetStore)`: If the root URL is valid and successfully parsed.
+    /// - `Err(Box<dyn Error>)`: If the URL is invalid or any error occurs while parsing.
+    pub fn new(root: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            root: root.parse()?,
+            client: Client::new(),
+            headers: Default::default(),
+        })
+    }
+
+    /// Adds header sent with every request.
+    ///
+    /// # Arguments
+    /// - `name`: Header name.
+    /// - `value`: Header value.
+    ///
+    /// # Returns
+    /// - `Ok(HttpAssetStore)`: The updated `HttpAssetStore` instance.
+    /// - `Err(Box<dyn Error>)`: If header name or value is invalid.
+    pub fn with_header(mut self, name: &str, value: &str) -> Result<Self, Box<dyn Error>> {
+        insert_header(&mut self.headers, name, value)?;
+        Ok(self)
+    }
+
+    /// Adds bearer token authorization header sent with every request.
+    ///
+    /// # Arguments
+    /// - `token`: Bearer token.
+    ///
+    /// # Returns
+    /// - `Ok(HttpAssetStore)`: The updated `HttpAssetStore` instance.
+    /// - `Err(Box<dyn Error>)`: If token is not a valid header value.
+    pub fn with_bearer_auth(self, token: &str) -> Result<Self, Box<dyn Error>> {
+        self.with_header(AUTHORIZATION.as_str(), &format!("Bearer {token}"))
+    }
+}
+
+impl AssetStore for HttpAssetStore {
+    fn save_bytes(&self, path: AssetPath, bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+        let url = join_url(&self.root, &path)?;
+        self.client
+            .put(url.clone())
+            .headers(self.headers.clone())
+            .body(bytes)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(|error| format!("Failed to put HTTP content to: `{url}`. Error: {error}"))?;
+        let mut bundle = DynamicBundle::default();
+        let _ = bundle.add_component(AssetStoredToHttp);
+        let _ = bundle.add_component(url);
         Ok(bundle)
     }
 }
@@ -0,0 +1,66 @@
+use keket::{
+    database::AssetDatabase, fetch::container::ContainerAssetFetch,
+    protocol::text::TextAssetProtocol,
+};
+use keket_redb::{
+    RedbContainerPartialFetch, RedbContainerStore,
+    third_party::redb::{Database, ReadableDatabase, TableDefinition},
+};
+use std::{error::Error, sync::Arc};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    /* ANCHOR: main */
+    let path = std::env::temp_dir().join(format!("keket-redb-store-{}.redb", std::process::id()));
+    // Fetch and store share the same database.
+    let redb = Arc::new(Database::create(&path)?);
+
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(ContainerAssetFetch::new(RedbContainerPartialFetch::new(
+            redb.clone(),
+            "assets",
+        )))
+        .with_store(RedbContainerStore::new(redb.clone(), "assets"));
+
+    // Store runtime generated asset into default table...
+    let hello = database.spawn("text://hello.txt", (String::from("Hello World!"),))?;
+    hello.store(&mut database)?;
+    // ...and another one into table selected by path meta.
+    let drafts = database.spawn("text://draft.txt?drafts", (String::from("Draft"),))?;
+    drafts.store(&mut database)?;
+    database.maintain_until_idle()?;
+
+    // Stored bytes land in redb tables unchanged.
+    {
+        let transaction = redb.begin_read()?;
+        for (table_name, key, expected) in [
+            ("assets", "hello.txt", "Hello World!"),
+            ("drafts", "draft.txt", "Draft"),
+        ] {
+            let table =
+                transaction.open_table(TableDefinition::<String, Vec<u8>>::new(table_name))?;
+            let bytes = table.get(key.to_owned())?.map(|access| access.value());
+            assert_eq!(bytes.as_deref(), Some(expected.as_bytes()));
+        }
+    }
+
+    // Fetch stored assets back from redb.
+    let mut database = AssetDatabase::default()
+        .with_protocol(TextAssetProtocol)
+        .with_fetch(ContainerAssetFetch::new(RedbContainerPartialFetch::new(
+            redb, "assets",
+        )));
+    let hello = database.ensure("text://hello.txt")?;
+    println!("Hello: {}", hello.access::<&String>(&database));
+    assert_eq!(hello.access::<&String>(&database), "Hello World!");
+    let drafts = database.ensure("text://draft.txt?drafts")?;
+    assert_eq!(drafts.access::<&String>(&database), "Draft");
+
+    // Missing keys are reported as not found errors, not as empty assets.
+    assert!(database.ensure("text://missing.txt").is_err());
+    /* ANCHOR_END: main */
+
+    drop(database);
+    let _ = std::fs::remove_file(path);
+    Ok(())
+}
@@ -1,6 +1,11 @@
-use keket::{database::path::AssetPath, fetch::container::ContainerPartialFetch};
+use keket::{
+    database::path::AssetPath,
+    fetch::{FetchError, container::ContainerPartialFetch},
+    store::AssetStore,
+    third_party::anput::bundle::DynamicBundle,
+};
 use redb::{Database, ReadableDatabase, TableDefinition};
-use std::error::Error;
+use std::{error::Error, sync::Arc};
 
 pub mod third_party {
     pub use redb;
@@ -11,7 +16,7 @@ pub mod third_party {
 /// The fetcher uses the asset's `AssetPath` to find the corresponding asset in the database,
 /// reading the data from a specified table in the Redb database.
 pub struct RedbContainerPartialFetch {
-    database: Database,
+    database: Arc<Database>,
     default_table_name: String,
 }
 
@@ -19,14 +24,15 @@ impl RedbContainerPartialFetch {
     /// Creates a new `RedbContainerPartialFetch` instance using the provided database and default table name.
     ///
     /// # Arguments
-    /// - `database`: An instance of the Redb `Database` to use for querying.
+    /// - `database`: An instance of the Redb `Database` to use for querying,
+    ///   can be shared with `RedbContainerStore`.
     /// - `default_table_name`: A string representing the default table name to use for querying.
     ///
     /// # Returns
     /// - `Self`: A new `RedbContainerPartialFetch` initialized with the given database and table name.
-    pub fn new(database: Database, default_table_name: impl ToString) -> Self {
+    pub fn new(database: impl Into<Arc<Database>>, default_table_name: impl ToString) -> Self {
         Self {
-            database,
+            database: database.into(),
             default_table_name: default_table_name.to_string(),
         }
     }
@@ -39,7 +45,54 @@ impl ContainerPartialFetch for RedbContainerPartialFetch {
         let table_definition = TableDefinition::<String, Vec<u8>>::new(table_name);
         let table = transaction.open_table(table_definition)?;
         let access = table.get(path.path().to_owned())?;
-        let bytes = access.map(|access| access.value()).unwrap_or_default();
+        let bytes = access.map(|access| access.value()).ok_or_else(|| {
+            FetchError::NotFound(format!(
+                "Asset: `{}` not found in table: `{}`",
+                path.path(),
+                table_name
+            ))
+        })?;
         Ok(bytes)
     }
 }
+
+/// `RedbContainerStore` represents an asset store that writes asset data
+/// into a Redb database.
+/// The store uses the asset's `AssetPath` as a key, writing the data into
+/// table selected by asset path meta, or into default table otherwise.
+pub struct RedbContainerStore {
+    database: Arc<Database>,
+    default_table_name: String,
+}
+
+impl RedbContainerStore {
+    /// Creates a new `RedbContainerStore` instance using the provided database and default table name.
+    ///
+    /// # Arguments
+    /// - `database`: An instance of the Redb `Database` to write into,
+    ///   can be shared with `RedbContainerPartialFetch`.
+    /// - `default_table_name`: A string representing the default table name to write into.
+    ///
+    /// # Returns
+    /// - `Self`: A new `RedbContainerStore` initialized with the given database and table name.
+    pub fn new(database: impl Into<Arc<Database>>, default_table_name: impl ToString) -> Self {
+        Self {
+            database: database.into(),
+            default_table_name: default_table_name.to_string(),
+        }
+    }
+}
+
+impl AssetStore for RedbContainerStore {
+    fn save_bytes(&self, path: AssetPath, bytes: Vec<u8>) -> Result<DynamicBundle, Box<dyn Error>> {
+        let transaction = self.database.begin_write()?;
+        let table_name = path.try_meta().unwrap_or(self.default_table_name.as_str());
+        let table_definition = TableDefinition::<String, Vec<u8>>::new(table_name);
+        {
+            let mut table = transaction.open_table(table_definition)?;
+            table.insert(path.path().to_owned(), bytes)?;
+        }
+        transaction.commit()?;
+        Ok(DynamicBundle::default())
+    }
+}
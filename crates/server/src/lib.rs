@@ -0,0 +1,700 @@
+use bytes::Bytes;
+use futures_util::{SinkExt, StreamExt};
+use notify::{Config, Event, PollWatcher, RecursiveMode, Result as NotifyResult, Watcher};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Debug, Display},
+    io::{stderr, stdout},
+    net::SocketAddr,
+    path::{Component, Path, PathBuf},
+    process::Command,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{Receiver, RecvTimeoutError, Sender, channel},
+    },
+    thread::{JoinHandle, spawn},
+    time::{Duration, Instant},
+};
+use tokio::{
+    fs::{self, OpenOptions},
+    io::AsyncWriteExt,
+    net::TcpListener,
+    runtime::Runtime,
+};
+use tokio_util::sync::CancellationToken;
+use warp::{
+    Filter, Rejection, Reply,
+    filters::ws::{Message, WebSocket},
+    http::Response,
+    reject::Reject,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum PipelineCommand {
+    Terminate,
+    Execute {
+        program: String,
+        arguments: Vec<String>,
+    },
+}
+
+impl PipelineCommand {
+    fn run(&self, path: String, shutdown: &CancellationToken) {
+        match self {
+            // Server might be embedded in other process, so only server gets
+            // shut down instead of whole process.
+            Self::Terminate => {
+                shutdown.cancel();
+            }
+            Self::Execute { program, arguments } => {
+                let _ = Command::new(program)
+                    .arg(path)
+                    .args(arguments)
+                    .stdout(stdout())
+                    .stderr(stderr())
+                    .status();
+            }
+        }
+    }
+}
+
+/// Callback receiving log messages of asset server.
+pub type AssetServerLogger = Arc<dyn Fn(&str) + Send + Sync>;
+
+#[derive(Default, Clone)]
+struct Logger(Option<AssetServerLogger>);
+
+impl Logger {
+    fn log(&self, message: impl Display) {
+        if let Some(logger) = self.0.as_ref() {
+            logger(&message.to_string());
+        }
+    }
+}
+
+impl Debug for Logger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Logger").field(&self.0.is_some()).finish()
+    }
+}
+
+#[derive(Debug)]
+struct MessageError(pub String);
+
+impl Reject for MessageError {}
+
+fn message_error(logger: &Logger, message: String) -> Rejection {
+    logger.log(format!("Error: {message}"));
+    warp::reject::custom(MessageError(message))
+}
+
+#[derive(Debug)]
+struct Unauthorized;
+
+impl Reject for Unauthorized {}
+
+#[derive(Debug)]
+struct InvalidPath(String);
+
+impl Reject for InvalidPath {}
+
+fn resolve_path(root: &Path, path: &str, logger: &Logger) -> Result<PathBuf, Rejection> {
+    let relative = Path::new(path);
+    if relative
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        logger.log(format!("Rejected path escaping root directory: {path:?}"));
+        return Err(warp::reject::custom(InvalidPath(path.to_owned())));
+    }
+    Ok(root.join(relative))
+}
+
+fn authorize(token: Option<String>) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::header::optional::<String>("authorization")
+        .and_then(move |header: Option<String>| {
+            let authorized = match token.as_ref() {
+                Some(token) => header.as_deref() == Some(format!("Bearer {token}").as_str()),
+                None => true,
+            };
+            async move {
+                if authorized {
+                    Ok(())
+                } else {
+                    Err(warp::reject::custom(Unauthorized))
+                }
+            }
+        })
+        .untuple_one()
+}
+
+async fn handle_rejection(rejection: Rejection, logger: Logger) -> Result<impl Reply, Rejection> {
+    if rejection.find::<Unauthorized>().is_some() {
+        logger.log("Rejected unauthorized request");
+        Ok(warp::reply::with_status(
+            "Unauthorized".to_owned(),
+            warp::http::StatusCode::UNAUTHORIZED,
+        ))
+    } else if let Some(InvalidPath(path)) = rejection.find::<InvalidPath>() {
+        Ok(warp::reply::with_status(
+            format!("Asset path escapes root directory: {path}"),
+            warp::http::StatusCode::BAD_REQUEST,
+        ))
+    } else if let Some(MessageError(message)) = rejection.find::<MessageError>() {
+        Ok(warp::reply::with_status(
+            message.to_owned(),
+            warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+        ))
+    } else {
+        Err(rejection)
+    }
+}
+
+#[derive(Default, Clone)]
+struct ChangeBindings {
+    senders: Arc<Mutex<Vec<Sender<String>>>>,
+    pending: Arc<Mutex<HashMap<String, Instant>>>,
+    debounce: Duration,
+    logger: Logger,
+}
+
+impl ChangeBindings {
+    fn new(debounce: Duration, logger: Logger) -> Self {
+        Self {
+            debounce,
+            logger,
+            ..Default::default()
+        }
+    }
+
+    fn receiver(&self) -> Receiver<String> {
+        let (sender, receiver) = channel();
+        self.senders.lock().unwrap().push(sender);
+        receiver
+    }
+
+    fn send(&self, path: &str) {
+        // Rapid changes of the same file postpone its broadcast, so clients
+        // get single change message once file settles.
+        self.pending
+            .lock()
+            .unwrap()
+            .insert(path.to_owned(), Instant::now());
+    }
+
+    fn flush(&self, now: Instant) {
+        let settled = {
+            let mut pending = self.pending.lock().unwrap();
+            let settled = pending
+                .iter()
+                .filter(|(_, time)| now.saturating_duration_since(**time) >= self.debounce)
+                .map(|(path, _)| path.to_owned())
+                .collect::<Vec<_>>();
+            for path in &settled {
+                pending.remove(path);
+            }
+            settled
+        };
+        if settled.is_empty() {
+            return;
+        }
+        let mut senders = self.senders.lock().unwrap();
+        for path in settled {
+            self.logger.log(format!("Broadcast changed file: {path:?}"));
+            senders.retain(|sender| sender.send(path.clone()).is_ok());
+        }
+    }
+}
+
+fn content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "txt" | "md" => "text/plain; charset=utf-8",
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "csv" => "text/csv; charset=utf-8",
+        "js" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "toml" => "application/toml",
+        "yaml" | "yml" => "application/yaml",
+        "xml" => "application/xml",
+        "wasm" => "application/wasm",
+        "zip" => "application/zip",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "wav" => "audio/wav",
+        "mp3" => "audio/mpeg",
+        "ogg" => "audio/ogg",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        _ => "application/octet-stream",
+    }
+}
+
+async fn get_file_handler(
+    path: String,
+    root: PathBuf,
+    logger: Logger,
+) -> Result<impl Reply, Rejection> {
+    let file_path = resolve_path(&root, &path, &logger)?;
+
+    if !file_path.exists() {
+        return Err(warp::reject::not_found());
+    }
+
+    let file_bytes = fs::read(&file_path).await.map_err(|err| {
+        message_error(
+            &logger,
+            format!("Failed to read file: {file_path:?}. Error: {err}"),
+        )
+    })?;
+
+    logger.log(format!("Requested file: {file_path:?}"));
+    Response::builder()
+        .header("Content-Type", content_type(&file_path))
+        .body(file_bytes)
+        .map_err(|err| {
+            message_error(
+                &logger,
+                format!("Failed to build response for file: {file_path:?}. Error: {err}"),
+            )
+        })
+}
+
+async fn put_file_handler(
+    path: String,
+    root: PathBuf,
+    body: Bytes,
+    logger: Logger,
+) -> Result<impl Reply, Rejection> {
+    let file_path = resolve_path(&root, &path, &logger)?;
+
+    if let Some(parent) = file_path.parent() {
+        fs::create_dir_all(parent).await.map_err(|err| {
+            message_error(
+                &logger,
+                format!("Failed to create directory: {parent:?}. Error: {err}"),
+            )
+        })?;
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(&file_path)
+        .await
+        .map_err(|err| {
+            message_error(
+                &logger,
+                format!("Failed to open file: {file_path:?}. Error: {err}"),
+            )
+        })?;
+
+    file.write_all(&body).await.map_err(|err| {
+        message_error(
+            &logger,
+            format!("Failed to write file: {file_path:?}. Error: {err}"),
+        )
+    })?;
+
+    logger.log(format!("Created file: {file_path:?}"));
+    Ok(warp::reply::with_status(
+        "File saved",
+        warp::http::StatusCode::OK,
+    ))
+}
+
+async fn delete_file_handler(
+    path: String,
+    root: PathBuf,
+    logger: Logger,
+) -> Result<impl Reply, Rejection> {
+    let file_path = resolve_path(&root, &path, &logger)?;
+
+    if !file_path.exists() {
+        return Err(warp::reject::not_found());
+    }
+
+    if let Err(err) = fs::remove_file(&file_path).await {
+        return Err(message_error(
+            &logger,
+            format!("Failed to delete file: {file_path:?}. Error: {err}"),
+        ));
+    }
+
+    logger.log(format!("Deleted file: {file_path:?}"));
+    Ok(warp::reply::with_status(
+        "File deleted",
+        warp::http::StatusCode::OK,
+    ))
+}
+
+async fn run_command_handler(
+    path: String,
+    body: PipelineCommand,
+    logger: Logger,
+    shutdown: CancellationToken,
+) -> Result<impl Reply, Rejection> {
+    body.run(path, &shutdown);
+    logger.log(format!("Executed command: {body:?}"));
+    Ok(warp::reply::with_status(
+        "Executed command",
+        warp::http::StatusCode::OK,
+    ))
+}
+
+async fn client_connected(ws: WebSocket, id: usize, receiver: Receiver<String>, logger: Logger) {
+    logger.log(format!("WebSocket client connected: {id}"));
+    let (mut client_tx, _) = ws.split();
+    loop {
+        let mut disconnected = false;
+        while let Ok(path) = receiver.try_recv() {
+            logger.log(format!("WebSocket sent changed path: {path:?} to: {id}"));
+            if client_tx.send(Message::text(path)).await.is_err() {
+                disconnected = true;
+            }
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        if client_tx
+            .send(Message::ping(id.to_be_bytes().to_vec()))
+            .await
+            .is_err()
+        {
+            disconnected = true;
+        }
+        if disconnected {
+            let _ = client_tx.close().await;
+            logger.log(format!("WebSocket client disconnected: {id}"));
+            return;
+        }
+    }
+}
+
+fn watch_changes(
+    root: &Path,
+    interval: Duration,
+    bindings: ChangeBindings,
+) -> Result<PollWatcher, Box<dyn Error>> {
+    bindings
+        .logger
+        .log(format!("Start file system watcher at path: {root:?}"));
+    let (watcher_tx, watcher_rx) = channel::<NotifyResult<Event>>();
+    let mut watcher = PollWatcher::new(watcher_tx, Config::default().with_poll_interval(interval))?;
+    watcher.watch(root, RecursiveMode::Recursive)?;
+
+    let root = format!("{}{}", root.to_string_lossy(), std::path::MAIN_SEPARATOR);
+    // Thread finishes once watcher gets dropped together with its sender.
+    spawn(move || {
+        let timeout = bindings.debounce.max(Duration::from_millis(10));
+        loop {
+            match watcher_rx.recv_timeout(timeout) {
+                Ok(Ok(event)) => {
+                    if event.kind.is_modify() {
+                        for path in event.paths {
+                            bindings.logger.log(format!("File changed: {path:?}"));
+                            let path = path.to_string_lossy();
+                            let path = path.as_ref();
+                            bindings.send(path.strip_prefix(&root).unwrap_or(path));
+                        }
+                    }
+                }
+                Ok(Err(_)) | Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            bindings.flush(Instant::now());
+        }
+    });
+    Ok(watcher)
+}
+
+async fn serve(
+    root: PathBuf,
+    token: Option<String>,
+    listener: TcpListener,
+    bindings: ChangeBindings,
+    logger: Logger,
+    shutdown: CancellationToken,
+) {
+    let id_generator = Arc::new(AtomicUsize::default());
+    let root = warp::any().map(move || root.clone());
+    let terminate = {
+        let shutdown = shutdown.clone();
+        warp::any().map(move || shutdown.clone())
+    };
+    let log = {
+        let logger = logger.clone();
+        warp::any().map(move || logger.clone())
+    };
+    warp::serve(
+        authorize(token)
+            .and(
+                warp::path!("assets" / String)
+                    .and(warp::get())
+                    .and(root.clone())
+                    .and(log.clone())
+                    .and_then(get_file_handler)
+                    .or(warp::path!("assets" / String)
+                        .and(warp::put())
+                        .and(root.clone())
+                        .and(warp::body::bytes())
+                        .and(log.clone())
+                        .and_then(put_file_handler))
+                    .or(warp::path!("assets" / String)
+                        .and(warp::delete())
+                        .and(root)
+                        .and(log.clone())
+                        .and_then(delete_file_handler))
+                    .or(warp::path!("run" / String)
+                        .and(warp::post())
+                        .and(warp::body::json())
+                        .and(log.clone())
+                        .and(terminate)
+                        .and_then(run_command_handler))
+                    .or(warp::path("changes")
+                        .and(warp::ws())
+                        .and(warp::any().map(move || id_generator.fetch_add(1, Ordering::Relaxed)))
+                        .and(warp::any().map(move || bindings.clone()))
+                        .and(log)
+                        .map(
+                            move |ws: warp::ws::Ws,
+                                  id: usize,
+                                  bindings: ChangeBindings,
+                                  logger: Logger| {
+                                logger.log(format!("WebSocket new client connection: {id}"));
+                                ws.on_upgrade(move |ws| {
+                                    client_connected(ws, id, bindings.receiver(), logger)
+                                })
+                            },
+                        )),
+            )
+            .recover(move |rejection| handle_rejection(rejection, logger.clone())),
+    )
+    .incoming(listener)
+    .graceful(async move {
+        shutdown.cancelled().await;
+    })
+    .run()
+    .await;
+}
+
+/// Asset server configuration, used to spawn in-process asset server.
+///
+/// Server serves files from its root directory and notifies connected
+/// clients about changed files, so it can be used both as standalone
+/// asset server and in integration tests of client fetch engines.
+#[derive(Debug, Clone)]
+pub struct AssetServer {
+    root: PathBuf,
+    address: SocketAddr,
+    watch_interval: Option<Duration>,
+    debounce: Duration,
+    token: Option<String>,
+    logger: Logger,
+}
+
+impl Default for AssetServer {
+    fn default() -> Self {
+        Self {
+            root: PathBuf::from("."),
+            address: SocketAddr::from(([127, 0, 0, 1], 8080)),
+            watch_interval: Some(Duration::from_secs(10)),
+            debounce: Duration::from_millis(250),
+            token: None,
+            logger: Logger::default(),
+        }
+    }
+}
+
+impl AssetServer {
+    /// Creates a new asset server configuration with default settings.
+    ///
+    /// # Returns
+    /// - A new `AssetServer` serving current directory at `127.0.0.1:8080`.
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Sets root directory of served files.
+    ///
+    /// # Arguments
+    /// - `path`: Path to directory with served files.
+    ///
+    /// # Returns
+    /// - The modified `AssetServer` instance.
+    pub fn root(mut self, path: impl Into<PathBuf>) -> Self {
+        self.root = path.into();
+        self
+    }
+
+    /// Sets address server listens at.
+    ///
+    /// Port `0` makes system pick free port - actual address can be read
+    /// from `ServerHandle::address`.
+    ///
+    /// # Arguments
+    /// - `address`: Socket address to bind.
+    ///
+    /// # Returns
+    /// - The modified `AssetServer` instance.
+    pub fn bind(mut self, address: impl Into<SocketAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Sets interval of file system polling for changed files.
+    ///
+    /// # Arguments
+    /// - `interval`: Polling interval, or `None` to disable watching.
+    ///
+    /// # Returns
+    /// - The modified `AssetServer` instance.
+    pub fn watch(mut self, interval: Option<Duration>) -> Self {
+        self.watch_interval = interval;
+        self
+    }
+
+    /// Sets time file has to stay unchanged before its change gets broadcast.
+    ///
+    /// Multiple changes of the same file within that time are coalesced
+    /// into single change message.
+    ///
+    /// # Arguments
+    /// - `duration`: Debounce duration.
+    ///
+    /// # Returns
+    /// - The modified `AssetServer` instance.
+    pub fn debounce(mut self, duration: Duration) -> Self {
+        self.debounce = duration;
+        self
+    }
+
+    /// Sets bearer token required from clients in `Authorization` header.
+    ///
+    /// Requests without matching token get rejected with `401 Unauthorized`.
+    ///
+    /// # Arguments
+    /// - `token`: Bearer token, or `None` to accept all requests.
+    ///
+    /// # Returns
+    /// - The modified `AssetServer` instance.
+    pub fn token(mut self, token: Option<String>) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Sets callback receiving log messages of served requests, file changes
+    /// and server lifetime. Server does not log anything by default.
+    ///
+    /// # Arguments
+    /// - `logger`: Callback receiving log messages.
+    ///
+    /// # Returns
+    /// - The modified `AssetServer` instance.
+    pub fn logger(mut self, logger: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.logger = Logger(Some(Arc::new(logger)));
+        self
+    }
+
+    /// Spawns asset server on background thread with its own runtime.
+    ///
+    /// Returns once server listens for connections, so clients can connect
+    /// right away.
+    ///
+    /// # Returns
+    /// - `Ok(ServerHandle)` controlling spawned server.
+    /// - `Err(Box<dyn Error>)` if runtime, watcher or listener fail to start.
+    pub fn spawn(self) -> Result<ServerHandle, Box<dyn Error>> {
+        let root = if self.root.is_absolute() {
+            self.root
+        } else {
+            std::env::current_dir()?.join(self.root)
+        };
+        let bindings = ChangeBindings::new(self.debounce, self.logger.clone());
+        let watcher = self
+            .watch_interval
+            .map(|interval| watch_changes(&root, interval, bindings.clone()))
+            .transpose()?;
+        let runtime = Runtime::new()?;
+        let listener = runtime.block_on(TcpListener::bind(self.address))?;
+        let address = listener.local_addr()?;
+        let shutdown = CancellationToken::new();
+
+        self.logger
+            .log(format!("Start asset server at address: {address:?}"));
+        let logger = self.logger;
+        let server_shutdown = shutdown.clone();
+        let thread = spawn(move || {
+            runtime.block_on(serve(
+                root,
+                self.token,
+                listener,
+                bindings,
+                logger.clone(),
+                server_shutdown,
+            ));
+            drop(watcher);
+            logger.log(format!("Stopped asset server at address: {address:?}"));
+        });
+        Ok(ServerHandle {
+            address,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+}
+
+/// Handle to asset server running in background.
+///
+/// Dropping handle shuts server down. Server also shuts down on its own when
+/// client runs `Terminate` pipeline command.
+pub struct ServerHandle {
+    address: SocketAddr,
+    shutdown: CancellationToken,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ServerHandle {
+    /// Returns address server listens at.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Gracefully shuts server down and waits for it to finish.
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    /// Blocks current thread until server finishes.
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+
+    fn stop(&mut self) {
+        self.shutdown.cancel();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ServerHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}